@@ -12,7 +12,6 @@ import { SignerWalletAdapter } from "@solana/wallet-adapter-base";
 import { createDefaultAuctionFactory } from "../utils/auctionFactory";
 import {
     AuctionFactoryClient,
-    TokenAccount,
     Auction,
     AuctionFactory,
     BN_ZERO,
@@ -236,25 +235,8 @@ export const AuctionFactoryProvider: FC<AuctionFactoryProviderProps> = ({
         );
         const userPublickey: PublicKey = getValidatedWalletConnection(wallet);
 
-        let bidderTokenAccountData: TokenAccount | undefined = undefined;
-        if (auction.bidder) {
-            const [bidderTokenAccount, bidderTokenAccountBump] =
-                await _client.getAssociatedTokenAccountAddress(
-                    auction.bidder,
-                    auction.resource
-                );
-            bidderTokenAccountData = {
-                address: bidderTokenAccount,
-                bump: bidderTokenAccountBump,
-            } as TokenAccount;
-        }
-
-        console.log('bidderTokenAccountData pubkey: ', bidderTokenAccountData?.address.toString());
-        console.log('bidderTokenAccountData bump: ', bidderTokenAccountData?.bump);
-
         await _client.settleAuction(
             auctionFactory.sequence,
-            bidderTokenAccountData,
             auction.resource,
             userPublickey
         );
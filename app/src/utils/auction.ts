@@ -13,12 +13,12 @@ export const isAuctionLive = (auction: Auction | undefined) => {
 
 export const computeMinimumNextBid = (
     amount: number,
-    requiredPercentIncrease: number
+    requiredBasisPointIncrease: number
 ) => {
     // unable to bid 0, min bid is 1 lamport
     if (amount === 0) return (1 / LAMPORTS_PER_SOL);
 
-    const minBidAmount = Math.round((1 + (requiredPercentIncrease / 100)) * amount);
+    const minBidAmount = Math.round((1 + (requiredBasisPointIncrease / 10000)) * amount);
 
     // a single lamport over current bid amount
     if (minBidAmount === amount) {
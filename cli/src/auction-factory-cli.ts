@@ -4,6 +4,7 @@ import { BN, Wallet } from "@project-serum/anchor";
 import {
     AuctionFactoryData,
     AuctionFactoryClient,
+    getDefaultAuctionFactoryData,
 } from "@auction-factory/sdk";
 
 import { Cluster, clusterApiUrl, Connection, Keypair, PublicKey } from "@solana/web3.js";
@@ -175,7 +176,7 @@ programCommand("init_auction_factory")
     )
     .option(
         "-mpi, --minBidPercentageIncrease <number>",
-        "Minimum bid percentage increase for each auction"
+        "Minimum bid increase for each auction, in basis points"
     )
     .option(
         "-mrp, --minReservePrice <number>",
@@ -207,6 +208,7 @@ programCommand("init_auction_factory")
             bump,
             seed,
             {
+                ...getDefaultAuctionFactoryData(),
                 duration: new BN(duration),
                 timeBuffer: new BN(timeBuffer),
                 minBidPercentageIncrease: new BN(minBidPercentageIncrease),
@@ -240,7 +242,7 @@ programCommand("modify_auction_factory")
     )
     .option(
         "-mpi, --minBidPercentageIncrease <number>",
-        "Minimum bid percentage increase for each auction. Optional."
+        "Minimum bid increase for each auction, in basis points. Optional."
     )
     .option(
         "-mrp, --minReservePrice <number>",
@@ -265,6 +267,7 @@ programCommand("modify_auction_factory")
         const af = await client.fetchAuctionFactory(addr);
         await client.modify(
             {
+                ...(af.data as AuctionFactoryData),
                 duration: duration ? new BN(duration) : af.data.duration,
                 timeBuffer: timeBuffer
                     ? new BN(timeBuffer)
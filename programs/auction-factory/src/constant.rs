@@ -7,12 +7,22 @@ pub const SPL_ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID: &str =
 pub const AUX_FACTORY_SEED: &str = "aux_fax";
 pub const AUX_SEED: &str = "aux";
 pub const URI_CONFIG_SEED: &str = "config";
+pub const BID_REFUND_SEED: &str = "refund";
+pub const BID_RECEIPT_SEED: &str = "receipt";
+pub const AUCTION_SUMMARY_SEED: &str = "summary";
 
 // auction factory
 pub const AUCTION_FACTORY_SEED_LEN: usize = 5;
+pub const MAX_BID_INCREMENT_TIERS: usize = 4;
+pub const MAX_REVENUE_RECIPIENTS: usize = 5;
+pub const BASIS_POINTS_DENOMINATOR: u64 = 10_000;
 
 // auction
 pub const MAX_BIDS_TO_RECORD: usize = 10;
+pub const MAX_WINNERS_PER_AUCTION: usize = 10;
+pub const MAX_CANDLE_CHECKPOINTS: usize = 10;
+// number of slots between requesting a candle auction's close and the slot whose hash closes it
+pub const CANDLE_CLOSE_SLOT_DELAY: u64 = 10;
 
 // config
 pub const CONFIG_SEED_LEN: usize = 5;
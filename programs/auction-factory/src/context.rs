@@ -1,18 +1,28 @@
 use {
     crate::{
-        constant::{AUX_FACTORY_SEED, AUX_SEED, CONFIG_SEED_LEN, MAX_URI_LENGTH, URI_CONFIG_SEED},
+        constant::{
+            AUCTION_SUMMARY_SEED, AUX_FACTORY_SEED, AUX_SEED, BID_REFUND_SEED, CONFIG_SEED_LEN,
+            MAX_URI_LENGTH, URI_CONFIG_SEED,
+        },
         instructions::{
             create_master_edition::CreateMasterEdition, create_metadata::CreateMetadata,
-            transfer::TransferLamports, update_metadata::UpdateMetadata, sign_metadata::SignMetadata
+            create_token_account::CreateAssociatedTokenAccount, mint_edition::MintEdition,
+            sign_metadata::SignMetadata, transfer::TransferLamports,
+            update_metadata::UpdateMetadata,
         },
         structs::{
             auction::{Auction, AUCTION_ACCOUNT_SPACE},
             auction_factory::{AuctionFactory, AuctionFactoryData, AUCTION_FACTORY_ACCOUNT_SPACE},
+            auction_summary::{AuctionSummary, AUCTION_SUMMARY_ACCOUNT_SPACE},
+            bid_receipt::BidReceipt,
+            bid_refund::{BidRefund, BID_REFUND_ACCOUNT_SPACE},
             config::Config,
         },
     },
     anchor_lang::prelude::*,
-    anchor_spl::token::{Burn, CloseAccount, Mint, MintTo, Token, TokenAccount},
+    anchor_spl::token::{
+        Burn, CloseAccount, Mint, MintTo, SetAuthority, Token, TokenAccount, Transfer,
+    },
 };
 
 /// =========================================
@@ -211,16 +221,641 @@ pub struct SupplyResource<'info> {
     auction_factory_bump: u8,
     seed: String,
     auction_bump: u8,
-    sequence: u64,
-    amount: u64
+    sequence: u64,
+    amount: u64,
+    bid_refund_bump: u8
+)]
+pub struct PlaceBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    // token accounts are not used for auctions with bids denominated in SOL, do account validation on-chain.
+    #[account(mut)]
+    pub bidder_token_account: AccountInfo<'info>,
+    // the auction's associated token account for the bid mint, which escrows SPL token bids.
+    // not used for auctions with bids denominated in SOL, do account validation on-chain.
+    #[account(mut)]
+    pub auction_bid_token_account: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes(),
+        ],
+        bump = auction_factory_bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            sequence.to_string().as_bytes()
+        ],
+        bump = auction_bump,
+        constraint = auction.to_account_info().owner == program_id,
+    )]
+    pub auction: Account<'info, Auction>,
+    // bidder's escrow ledger for this auction, created on the bidder's first bid
+    #[account(
+        init_if_needed,
+        seeds = [
+            BID_REFUND_SEED.as_bytes(),
+            auction.key().as_ref(),
+            bidder.key().as_ref()
+        ],
+        bump = bid_refund_bump,
+        payer = bidder,
+        space = BID_REFUND_ACCOUNT_SPACE,
+    )]
+    pub bid_refund: Account<'info, BidRefund>,
+    // only read when the leading bid is backed by a max bid, do account validation on-chain.
+    pub leading_bid_refund: AccountInfo<'info>,
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    auction_factory_bump: u8,
+    seed: String,
+    auction_bump: u8,
+    sequence: u64,
+    max_price: u64
+)]
+pub struct Buy<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    // not used for auctions with bids denominated in SOL, do account validation on-chain.
+    #[account(mut)]
+    pub buyer_payment_token_account: AccountInfo<'info>,
+    #[account(
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes(),
+        ],
+        bump = auction_factory_bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            sequence.to_string().as_bytes()
+        ],
+        bump = auction_bump,
+        constraint = auction.to_account_info().owner == program_id,
+    )]
+    pub auction: Account<'info, Auction>,
+    #[account(
+        mut,
+        constraint = auction_factory.treasury.key() == treasury.key()
+    )]
+    pub treasury: AccountInfo<'info>,
+    #[account(constraint = mint.key() == auction_token_account.mint)]
+    pub mint: Account<'info, Mint>,
+    // buyer's associated token account for the resource, do account validation on-chain.
+    // created by the buyer if missing.
+    #[account(mut)]
+    pub buyer_token_account: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = auction_token_account.amount == 1,
+        constraint = auction_token_account.owner == auction.key()
+    )]
+    pub auction_token_account: Account<'info, TokenAccount>,
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+    #[account(address = spl_associated_token_account::id())]
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    auction_factory_bump: u8,
+    seed: String,
+    auction_bump: u8,
+    sequence: u64,
+    bid_refund_bump: u8
+)]
+pub struct ClaimRefund<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+    // not used for auctions with bids denominated in SOL, do account validation on-chain.
+    #[account(mut)]
+    pub bidder_token_account: AccountInfo<'info>,
+    #[account(
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes(),
+        ],
+        bump = auction_factory_bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            sequence.to_string().as_bytes()
+        ],
+        bump = auction_bump,
+        constraint = auction.to_account_info().owner == program_id,
+    )]
+    pub auction: Account<'info, Auction>,
+    // not used for auctions with bids denominated in SOL, do account validation on-chain.
+    #[account(mut)]
+    pub auction_bid_token_account: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [
+            BID_REFUND_SEED.as_bytes(),
+            auction.key().as_ref(),
+            bidder.key().as_ref()
+        ],
+        bump = bid_refund_bump,
+        constraint = bid_refund.bidder == bidder.key(),
+        constraint = bid_refund.auction == auction.key(),
+    )]
+    pub bid_refund: Account<'info, BidRefund>,
+    // receives penalties taken from unrevealed sealed bid deposits
+    #[account(
+        mut,
+        constraint = auction_factory.treasury.key() == treasury.key()
+    )]
+    pub treasury: AccountInfo<'info>,
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    auction_factory_bump: u8,
+    seed: String,
+    auction_bump: u8,
+    sequence: u64,
+    bid_refund_bump: u8
+)]
+pub struct RevealSealedBid<'info> {
+    pub bidder: Signer<'info>,
+    #[account(
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes(),
+        ],
+        bump = auction_factory_bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            sequence.to_string().as_bytes()
+        ],
+        bump = auction_bump,
+        constraint = auction.to_account_info().owner == program_id,
+    )]
+    pub auction: Account<'info, Auction>,
+    #[account(
+        mut,
+        seeds = [
+            BID_REFUND_SEED.as_bytes(),
+            auction.key().as_ref(),
+            bidder.key().as_ref()
+        ],
+        bump = bid_refund_bump,
+        constraint = bid_refund.bidder == bidder.key(),
+        constraint = bid_refund.auction == auction.key(),
+    )]
+    pub bid_refund: Account<'info, BidRefund>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    auction_factory_bump: u8,
+    seed: String,
+    auction_bump: u8,
+    sequence: u64
+)]
+pub struct ReadAuction<'info> {
+    #[account(
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes(),
+        ],
+        bump = auction_factory_bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+    #[account(
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            sequence.to_string().as_bytes()
+        ],
+        bump = auction_bump,
+        constraint = auction.to_account_info().owner == program_id,
+    )]
+    pub auction: Account<'info, Auction>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    auction_factory_bump: u8,
+    seed: String,
+    auction_bump: u8,
+    sequence: u64
+)]
+pub struct RequestCandleClose<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes(),
+        ],
+        bump = auction_factory_bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            sequence.to_string().as_bytes()
+        ],
+        bump = auction_bump,
+        constraint = auction.to_account_info().owner == program_id,
+    )]
+    pub auction: Account<'info, Auction>,
+    // SlotHashes sysvar, do account validation on-chain.
+    pub slot_hashes: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    auction_factory_bump: u8,
+    seed: String,
+    auction_bump: u8,
+    sequence: u64
+)]
+pub struct CloseBidReceipt<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes(),
+        ],
+        bump = auction_factory_bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+    // may have already been closed via close_auction, do account validation on-chain.
+    #[account(
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            sequence.to_string().as_bytes()
+        ],
+        bump = auction_bump,
+    )]
+    pub auction: AccountInfo<'info>,
+    #[account(
+        mut,
+        close = receipt_payer,
+        constraint = bid_receipt.auction == auction.key(),
+        constraint = bid_receipt.payer == receipt_payer.key(),
+    )]
+    pub bid_receipt: Account<'info, BidReceipt>,
+    // account that paid rent for the bid receipt
+    #[account(mut)]
+    pub receipt_payer: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    auction_factory_bump: u8,
+    seed: String,
+    auction_bump: u8,
+    sequence: u64
+)]
+pub struct CloseBidRefund<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes(),
+        ],
+        bump = auction_factory_bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            sequence.to_string().as_bytes()
+        ],
+        bump = auction_bump,
+        constraint = auction.to_account_info().owner == program_id,
+    )]
+    pub auction: Account<'info, Auction>,
+    #[account(
+        mut,
+        close = bidder,
+        constraint = bid_refund.auction == auction.key(),
+        constraint = bid_refund.bidder == bidder.key(),
+    )]
+    pub bid_refund: Account<'info, BidRefund>,
+    // bidder that paid rent for the bid refund
+    #[account(mut)]
+    pub bidder: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    auction_factory_bump: u8,
+    seed: String,
+    auction_bump: u8,
+    sequence: u64
+)]
+pub struct SettleAuction<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes(),
+        ],
+        bump = auction_factory_bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            sequence.to_string().as_bytes()
+        ],
+        bump = auction_bump,
+        constraint = auction.to_account_info().owner == program_id,
+    )]
+    pub auction: Account<'info, Auction>,
+    #[account(
+        mut,
+        constraint = mint.decimals == 0,
+        constraint = mint.supply == 1,
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = auction_factory.treasury.key() == treasury.key()
+    )]
+    pub treasury: AccountInfo<'info>,
+    #[account(mut)]
+    pub metadata: AccountInfo<'info>,
+    // winning bidder's wallet, or the unsold resource recipient for auctions without any bids.
+    // only used to create their token account. do account validation on-chain.
+    pub bidder: AccountInfo<'info>,
+    // for auctions without any bids, only used under the transfer unsold resource policy.
+    // do account validation on-chain. created by the settler if missing.
+    #[account(mut)]
+    pub bidder_token_account: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = auction_token_account.amount == 1,
+        constraint = auction_token_account.owner == auction.key()
+    )]
+    pub auction_token_account: Account<'info, TokenAccount>,
+    // not used for auctions with bids denominated in SOL, do account validation on-chain.
+    #[account(mut)]
+    pub auction_bid_token_account: AccountInfo<'info>,
+    // receives the crank reward share of the proceeds for auctions with bids denominated in an
+    // SPL token, do account validation on-chain.
+    #[account(mut)]
+    pub payer_bid_token_account: AccountInfo<'info>,
+    // SlotHashes sysvar. only used to close candle auctions, do account validation on-chain.
+    pub slot_hashes: AccountInfo<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+    #[account(address = spl_associated_token_account::id())]
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    auction_factory_bump: u8,
+    seed: String,
+    current_auction_bump: u8,
+    next_auction_bump: u8,
+    config_bump: u8,
+    config_seed: String,
+    current_seq: u64,
+    next_seq: u64
+)]
+pub struct SettleAndAdvance<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            URI_CONFIG_SEED.as_bytes(),
+            config_seed.as_bytes()
+        ],
+        bump = config_bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes(),
+        ],
+        bump = auction_factory_bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            current_seq.to_string().as_bytes()
+        ],
+        bump = current_auction_bump,
+        constraint = current_auction.to_account_info().owner == program_id,
+    )]
+    pub current_auction: Account<'info, Auction>,
+    #[account(
+        mut,
+        constraint = current_mint.decimals == 0,
+        constraint = current_mint.supply == 1,
+    )]
+    pub current_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = auction_factory.treasury.key() == treasury.key()
+    )]
+    pub treasury: AccountInfo<'info>,
+    #[account(mut)]
+    pub current_metadata: AccountInfo<'info>,
+    // winning bidder's wallet, or the unsold resource recipient for auctions without any bids.
+    // only used to create their token account. do account validation on-chain.
+    pub bidder: AccountInfo<'info>,
+    // for auctions without any bids, only used under the transfer unsold resource policy.
+    // do account validation on-chain. created by the settler if missing.
+    #[account(mut)]
+    pub bidder_token_account: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = current_auction_token_account.amount == 1,
+        constraint = current_auction_token_account.owner == current_auction.key()
+    )]
+    pub current_auction_token_account: Account<'info, TokenAccount>,
+    // not used for auctions with bids denominated in SOL, do account validation on-chain.
+    #[account(mut)]
+    pub auction_bid_token_account: AccountInfo<'info>,
+    // not used for auctions with bids denominated in SOL, do account validation on-chain.
+    #[account(mut)]
+    pub payer_bid_token_account: AccountInfo<'info>,
+    // SlotHashes sysvar. only used to close candle auctions, do account validation on-chain.
+    pub slot_hashes: AccountInfo<'info>,
+    #[account(
+        init,
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            next_seq.to_string().as_bytes()
+        ],
+        bump = next_auction_bump,
+        payer = payer,
+        space = AUCTION_ACCOUNT_SPACE,
+        constraint = next_auction.to_account_info().owner == program_id,
+    )]
+    pub next_auction: Account<'info, Auction>,
+    // created ahead of time, with the next auction's address as mint & freeze authority
+    #[account(
+        mut,
+        constraint = next_mint.decimals == 0,
+        constraint = next_mint.supply == 0,
+        constraint = next_mint.freeze_authority.unwrap() == next_auction.key(),
+        constraint = next_mint.mint_authority.unwrap() == next_auction.key(),
+    )]
+    pub next_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = next_auction_token_account.amount == 0,
+        constraint = next_auction_token_account.owner == next_auction.key(),
+        constraint = next_auction_token_account.mint == next_mint.key()
+    )]
+    pub next_auction_token_account: Account<'info, TokenAccount>,
+    // metadata accounts are verified via cpi in the metadata program
+    #[account(mut)]
+    pub next_metadata: AccountInfo<'info>,
+    #[account(mut)]
+    pub next_master_edition: AccountInfo<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+    #[account(address = spl_associated_token_account::id())]
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    auction_factory_bump: u8,
+    seed: String,
+    auction_bump: u8,
+    sequence: u64
+)]
+pub struct ClaimPrintEdition<'info> {
+    // pays for the printed edition and signs as its mint authority
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes(),
+        ],
+        bump = auction_factory_bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            sequence.to_string().as_bytes()
+        ],
+        bump = auction_bump,
+        constraint = auction.to_account_info().owner == program_id,
+    )]
+    pub auction: Account<'info, Auction>,
+    #[account(constraint = auction.resource == Some(master_mint.key()))]
+    pub master_mint: Account<'info, Mint>,
+    // metadata accounts are verified via cpi in the metadata program
+    pub master_metadata: AccountInfo<'info>,
+    #[account(mut)]
+    pub master_edition: AccountInfo<'info>,
+    #[account(
+        constraint = auction_token_account.amount == 1,
+        constraint = auction_token_account.owner == auction.key(),
+        constraint = auction_token_account.mint == master_mint.key()
+    )]
+    pub auction_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = new_mint.decimals == 0,
+        constraint = new_mint.supply == 1,
+    )]
+    pub new_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub new_metadata: AccountInfo<'info>,
+    #[account(mut)]
+    pub new_edition: AccountInfo<'info>,
+    #[account(mut)]
+    pub edition_mark_pda: AccountInfo<'info>,
+    // holds the single token of the new mint, must belong to the winner. do account validation on-chain.
+    pub winner_token_account: AccountInfo<'info>,
+    #[account(address = mpl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    auction_factory_bump: u8,
+    seed: String,
+    auction_bump: u8,
+    sequence: u64
 )]
-pub struct PlaceBid<'info> {
-    #[account(mut)]
-    pub bidder: Signer<'info>,
+pub struct ClaimMasterEdition<'info> {
     #[account(mut)]
-    pub leading_bidder: AccountInfo<'info>,
+    pub payer: Signer<'info>,
     #[account(
-        mut,
         seeds = [
             AUX_FACTORY_SEED.as_bytes(),
             seed.as_bytes(),
@@ -240,19 +875,36 @@ pub struct PlaceBid<'info> {
         constraint = auction.to_account_info().owner == program_id,
     )]
     pub auction: Account<'info, Auction>,
+    // top bidder's wallet, only used to create their token account. do account validation on-chain.
+    pub bidder: AccountInfo<'info>,
+    #[account(constraint = mint.key() == auction_token_account.mint)]
+    pub mint: Account<'info, Mint>,
+    // top bidder's associated token account for the resource, do account validation on-chain.
+    // created by the payer if missing.
+    #[account(mut)]
+    pub bidder_token_account: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = auction_token_account.amount == 1,
+        constraint = auction_token_account.owner == auction.key()
+    )]
+    pub auction_token_account: Account<'info, TokenAccount>,
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
+    #[account(address = spl_associated_token_account::id())]
+    pub associated_token_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
 #[instruction(
-    bidder_account_bump: u8,
     auction_factory_bump: u8,
     seed: String,
     auction_bump: u8,
     sequence: u64
 )]
-pub struct SettleAuction<'info> {
-    #[account(mut)]
+pub struct CloseAuctionTokenAccount<'info> {
     pub payer: Signer<'info>,
     #[account(
         mut,
@@ -275,30 +927,17 @@ pub struct SettleAuction<'info> {
         constraint = auction.to_account_info().owner == program_id,
     )]
     pub auction: Account<'info, Auction>,
-    #[account(
-        mut,
-        constraint = mint.decimals == 0,
-        constraint = mint.supply == 1,
-    )]
-    pub mint: Account<'info, Mint>,
     #[account(
         mut,
         constraint = auction_factory.treasury.key() == treasury.key()
     )]
     pub treasury: AccountInfo<'info>,
-    #[account(mut)]
-    pub metadata: AccountInfo<'info>,
-    // not used in the case of auctions without any bids, do account validation on-chain.
-    #[account(mut)]
-    pub bidder_token_account: AccountInfo<'info>,
     #[account(
         mut,
-        constraint = auction_token_account.amount == 1,
+        constraint = auction_token_account.amount == 0,
         constraint = auction_token_account.owner == auction.key()
     )]
     pub auction_token_account: Account<'info, TokenAccount>,
-    #[account(address = mpl_token_metadata::id())]
-    pub token_metadata_program: UncheckedAccount<'info>,
     #[account(address = spl_token::id())]
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
@@ -309,12 +948,13 @@ pub struct SettleAuction<'info> {
     auction_factory_bump: u8,
     seed: String,
     auction_bump: u8,
-    sequence: u64
+    sequence: u64,
+    auction_summary_bump: u8
 )]
-pub struct CloseAuctionTokenAccount<'info> {
+pub struct CloseAuction<'info> {
+    #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
-        mut,
         seeds = [
             AUX_FACTORY_SEED.as_bytes(),
             seed.as_bytes(),
@@ -325,6 +965,7 @@ pub struct CloseAuctionTokenAccount<'info> {
     pub auction_factory: Account<'info, AuctionFactory>,
     #[account(
         mut,
+        close = treasury,
         seeds = [
             AUX_SEED.as_bytes(),
             auction_factory.key().as_ref(),
@@ -335,16 +976,26 @@ pub struct CloseAuctionTokenAccount<'info> {
     )]
     pub auction: Account<'info, Auction>,
     #[account(
-        mut,
-        constraint = auction_factory.treasury.key() == treasury.key()
+        init,
+        seeds = [
+            AUCTION_SUMMARY_SEED.as_bytes(),
+            auction.key().as_ref()
+        ],
+        bump = auction_summary_bump,
+        payer = payer,
+        space = AUCTION_SUMMARY_ACCOUNT_SPACE,
     )]
-    pub treasury: AccountInfo<'info>,
+    pub auction_summary: Account<'info, AuctionSummary>,
+    // receives any surplus funds left in the auction, and the auction's rent
     #[account(
         mut,
-        constraint = auction_token_account.amount == 0,
-        constraint = auction_token_account.owner == auction.key()
+        constraint = auction_factory.treasury.key() == treasury.key()
     )]
-    pub auction_token_account: Account<'info, TokenAccount>,
+    pub treasury: AccountInfo<'info>,
+    // not used for auctions with bids denominated in SOL, do account validation on-chain.
+    // closed once emptied.
+    #[account(mut)]
+    pub auction_bid_token_account: AccountInfo<'info>,
     #[account(address = spl_token::id())]
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
@@ -360,6 +1011,7 @@ pub struct CloseAuctionTokenAccount<'info> {
     seed: String,
     config_bump: u8,
     config_seed: String,
+    bid_mint: Option<Pubkey>,
     data: AuctionFactoryData
 )]
 pub struct InitializeAuctionFactory<'info> {
@@ -403,10 +1055,51 @@ pub struct ModifyAuctionFactory<'info> {
             seed.as_bytes()
         ],
         bump = bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    auction_factory_bump: u8,
+    seed: String,
+    auction_bump: u8,
+    sequence: u64
+)]
+pub struct CancelAuction<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes(),
+        ],
+        bump = auction_factory_bump,
         constraint = auction_factory.authority.key() == payer.key(),
         constraint = auction_factory.to_account_info().owner == program_id,
     )]
     pub auction_factory: Account<'info, AuctionFactory>,
+    #[account(
+        mut,
+        seeds = [
+            AUX_SEED.as_bytes(),
+            auction_factory.key().as_ref(),
+            sequence.to_string().as_bytes()
+        ],
+        bump = auction_bump,
+        constraint = auction.to_account_info().owner == program_id,
+    )]
+    pub auction: Account<'info, Auction>,
+    // not used if the auction has no resource, do account validation on-chain.
+    #[account(mut)]
+    pub mint: AccountInfo<'info>,
+    #[account(mut)]
+    pub auction_token_account: AccountInfo<'info>,
+    // only used when returning the resource to the authority, do account validation on-chain.
+    #[account(mut)]
+    pub authority_token_account: AccountInfo<'info>,
+    #[account(address = spl_token::id())]
+    pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
@@ -419,7 +1112,6 @@ pub struct TransferAuctionFactoryLamportsToTreasury<'info> {
             seed.as_bytes()
         ],
         bump = bump,
-        constraint = auction_factory.authority.key() == payer.key(),
         constraint = auction_factory.to_account_info().owner == program_id,
     )]
     pub auction_factory: Account<'info, AuctionFactory>,
@@ -449,6 +1141,37 @@ pub struct UpdateAuctionFactoryAuthority<'info> {
     pub auction_factory: Account<'info, AuctionFactory>,
 }
 
+#[derive(Accounts)]
+#[instruction(bump: u8, seed: String)]
+pub struct ExecutePendingChange<'info> {
+    pub payer: Signer<'info>,
+    #[account(mut,
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes()
+        ],
+        bump = bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8, seed: String)]
+pub struct AcceptAuctionFactoryAuthority<'info> {
+    // pending authority, do account validation on-chain.
+    pub payer: Signer<'info>,
+    #[account(mut,
+        seeds = [
+            AUX_FACTORY_SEED.as_bytes(),
+            seed.as_bytes()
+        ],
+        bump = bump,
+        constraint = auction_factory.to_account_info().owner == program_id,
+    )]
+    pub auction_factory: Account<'info, AuctionFactory>,
+}
+
 #[derive(Accounts)]
 #[instruction(auction_factory_bump: u8, seed: String)]
 pub struct UpdateAuctionFactoryTreasury<'info> {
@@ -459,7 +1182,6 @@ pub struct UpdateAuctionFactoryTreasury<'info> {
             seed.as_bytes()
         ],
         bump = auction_factory_bump,
-        constraint = auction_factory.authority.key() == payer.key(),
         constraint = auction_factory.to_account_info().owner == program_id,
     )]
     pub auction_factory: Account<'info, AuctionFactory>,
@@ -473,7 +1195,10 @@ pub struct UpdateAuctionFactoryTreasury<'info> {
 }
 
 // ================ IMPL FOR CPI CONTEXT ================
+// cpi helpers follow anchor's `into_*` naming, but borrow the accounts so that several helpers
+// can be used within one instruction.
 
+#[allow(clippy::wrong_self_convention)]
 impl<'info> CreateTokenMint<'info> {
     pub fn into_mint_token_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
         let cpi_program = self.token_program.to_account_info();
@@ -488,6 +1213,7 @@ impl<'info> CreateTokenMint<'info> {
     }
 }
 
+#[allow(clippy::wrong_self_convention)]
 impl<'info> PlaceBid<'info> {
     pub fn into_receive_bid_context(
         &self,
@@ -502,9 +1228,226 @@ impl<'info> PlaceBid<'info> {
 
         CpiContext::new(cpi_program, cpi_accounts)
     }
+
+    pub fn into_receive_token_bid_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.bidder_token_account.to_account_info(),
+            to: self.auction_bid_token_account.to_account_info(),
+            authority: self.bidder.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+#[allow(clippy::wrong_self_convention)]
+impl<'info> Buy<'info> {
+    pub fn into_payment_context(
+        &self,
+        destination: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, TransferLamports<'info>> {
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = TransferLamports {
+            from: self.buyer.to_account_info(),
+            to: destination,
+            system_program: self.system_program.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn into_token_payment_context(
+        &self,
+        destination: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.buyer_payment_token_account.to_account_info(),
+            to: destination,
+            authority: self.buyer.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn into_create_buyer_token_account_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, CreateAssociatedTokenAccount<'info>> {
+        let cpi_program = self.associated_token_program.to_account_info();
+
+        let cpi_accounts = CreateAssociatedTokenAccount {
+            payer: self.buyer.to_account_info(),
+            associated_token_account: self.buyer_token_account.to_account_info(),
+            wallet: self.buyer.to_account_info(),
+            mint: self.mint.to_account_info(),
+            associated_token_program: self.associated_token_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
 }
 
+#[allow(clippy::wrong_self_convention)]
 impl<'info> SettleAuction<'info> {
+    pub fn into_create_bidder_token_account_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, CreateAssociatedTokenAccount<'info>> {
+        let cpi_program = self.associated_token_program.to_account_info();
+
+        let cpi_accounts = CreateAssociatedTokenAccount {
+            payer: self.payer.to_account_info(),
+            associated_token_account: self.bidder_token_account.to_account_info(),
+            wallet: self.bidder.to_account_info(),
+            mint: self.mint.to_account_info(),
+            associated_token_program: self.associated_token_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn into_burn_token_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = Burn {
+            mint: self.mint.to_account_info(),
+            to: self.auction_token_account.to_account_info(),
+            authority: self.auction.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn into_set_token_account_owner_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, SetAuthority<'info>> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = SetAuthority {
+            current_authority: self.auction.to_account_info(),
+            account_or_mint: self.auction_token_account.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+// settle_and_advance runs the settle_auction & supply_resource_to_auction handlers over its own
+// accounts. accounts are validated by SettleAndAdvance itself.
+#[allow(clippy::wrong_self_convention)]
+impl<'info> SettleAndAdvance<'info> {
+    pub fn into_settle_auction_accounts(&self) -> SettleAuction<'info> {
+        SettleAuction {
+            payer: self.payer.clone(),
+            auction_factory: self.auction_factory.clone(),
+            auction: self.current_auction.clone(),
+            mint: self.current_mint.clone(),
+            treasury: self.treasury.clone(),
+            metadata: self.current_metadata.clone(),
+            bidder: self.bidder.clone(),
+            bidder_token_account: self.bidder_token_account.clone(),
+            auction_token_account: self.current_auction_token_account.clone(),
+            auction_bid_token_account: self.auction_bid_token_account.clone(),
+            payer_bid_token_account: self.payer_bid_token_account.clone(),
+            slot_hashes: self.slot_hashes.clone(),
+            token_metadata_program: self.token_metadata_program.clone(),
+            token_program: self.token_program.clone(),
+            associated_token_program: self.associated_token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        }
+    }
+
+    pub fn into_supply_resource_accounts(&self) -> SupplyResource<'info> {
+        SupplyResource {
+            payer: self.payer.clone(),
+            config: self.config.clone(),
+            auction_factory: self.auction_factory.clone(),
+            auction: self.next_auction.clone(),
+            mint: self.next_mint.clone(),
+            metadata: self.next_metadata.clone(),
+            master_edition: self.next_master_edition.clone(),
+            token_metadata_program: self.token_metadata_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        }
+    }
+
+    pub fn into_mint_token_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = MintTo {
+            mint: self.next_mint.to_account_info(),
+            to: self.next_auction_token_account.to_account_info(),
+            authority: self.next_auction.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+#[allow(clippy::wrong_self_convention)]
+impl<'info> ClaimPrintEdition<'info> {
+    pub fn into_mint_edition_context(&self) -> CpiContext<'_, '_, '_, 'info, MintEdition<'info>> {
+        let cpi_program = self.token_metadata_program.to_account_info();
+
+        let cpi_accounts = MintEdition {
+            payer: self.payer.to_account_info(),
+            new_metadata: self.new_metadata.to_account_info(),
+            new_edition: self.new_edition.to_account_info(),
+            master_edition: self.master_edition.to_account_info(),
+            new_mint: self.new_mint.to_account_info(),
+            new_mint_authority: self.payer.to_account_info(),
+            edition_mark_pda: self.edition_mark_pda.to_account_info(),
+            token_account_owner: self.auction.to_account_info(),
+            token_account: self.auction_token_account.to_account_info(),
+            new_metadata_update_authority: self.auction.to_account_info(),
+            metadata: self.master_metadata.to_account_info(),
+            metadata_mint: self.master_mint.to_account_info(),
+            token_metadata_program: self.token_metadata_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+#[allow(clippy::wrong_self_convention)]
+impl<'info> ClaimMasterEdition<'info> {
+    pub fn into_create_bidder_token_account_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, CreateAssociatedTokenAccount<'info>> {
+        let cpi_program = self.associated_token_program.to_account_info();
+
+        let cpi_accounts = CreateAssociatedTokenAccount {
+            payer: self.payer.to_account_info(),
+            associated_token_account: self.bidder_token_account.to_account_info(),
+            wallet: self.bidder.to_account_info(),
+            mint: self.mint.to_account_info(),
+            associated_token_program: self.associated_token_program.clone(),
+            token_program: self.token_program.clone(),
+            system_program: self.system_program.clone(),
+            rent: self.rent.clone(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+#[allow(clippy::wrong_self_convention)]
+impl<'info> CancelAuction<'info> {
     pub fn into_burn_token_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
         let cpi_program = self.token_program.to_account_info();
 
@@ -518,6 +1461,7 @@ impl<'info> SettleAuction<'info> {
     }
 }
 
+#[allow(clippy::wrong_self_convention)]
 impl<'info> CloseAuctionTokenAccount<'info> {
     pub fn into_close_token_account_context(
         &self,
@@ -535,6 +1479,25 @@ impl<'info> CloseAuctionTokenAccount<'info> {
     }
 }
 
+#[allow(clippy::wrong_self_convention)]
+impl<'info> CloseAuction<'info> {
+    pub fn into_close_bid_token_account_context(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = CloseAccount {
+            account: self.auction_bid_token_account.to_account_info(),
+            // send rent lamports to treasury
+            destination: self.treasury.to_account_info(),
+            authority: self.auction.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+}
+
+#[allow(clippy::wrong_self_convention)]
 impl<'info> SupplyResource<'info> {
     pub fn into_create_metadata_context(
         &self,
@@ -591,9 +1554,7 @@ impl<'info> SupplyResource<'info> {
         CpiContext::new(cpi_program, cpi_accounts)
     }
 
-    pub fn into_sign_metadata_context(
-        &self,
-    ) -> CpiContext<'_, '_, '_, 'info, SignMetadata<'info>> {
+    pub fn into_sign_metadata_context(&self) -> CpiContext<'_, '_, '_, 'info, SignMetadata<'info>> {
         let cpi_program = self.token_metadata_program.to_account_info();
 
         let cpi_accounts = SignMetadata {
@@ -647,7 +1608,6 @@ pub struct AddUrisToConfig<'info> {
             seed.as_bytes()
         ],
         bump = auction_factory_bump,
-        constraint = auction_factory.authority.key() == payer.key(),
         constraint = auction_factory.config.key() == config.key(),
         constraint = auction_factory.to_account_info().owner == program_id,
     )]
@@ -655,6 +1615,7 @@ pub struct AddUrisToConfig<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[allow(clippy::wrong_self_convention)]
 impl<'info> InitializeConfig<'info> {
     // 10 MB is max account storage size. max uri len in metaplex metadata standard is 200.
     // assume max str len for upper bound when determining max_supply, e.g. 100.
@@ -673,9 +1634,8 @@ impl<'info> InitializeConfig<'info> {
     // that is greater than that, we will let tx fail in solana rather than setting account size
     // to max of 10280. that could create problems down the road.
     fn space(max_supply: u32) -> usize {
-        return
-            // discriminator
-            8 +
+        // discriminator
+        8 +
             // seed, string + size_of_char * num seed chars
             4 + (8 * CONFIG_SEED_LEN) +
             // max_supply
@@ -685,8 +1645,8 @@ impl<'info> InitializeConfig<'info> {
             // is_udpated
             1 +
             // vec of Strings representing URI
-            4 + ((max_supply as usize) * (4 + MAX_URI_LENGTH));
+            4 + ((max_supply as usize) * (4 + MAX_URI_LENGTH))
         // a little extra buffer
         // 8;
     }
-}
\ No newline at end of file
+}
@@ -9,6 +9,10 @@ pub enum ErrorCode {
     Uninitialized,
     #[msg("Token account not owned by winning bidder")]
     TokenAccountNotOwnedByWinningBidder,
+    #[msg("Token account owner mismatch")]
+    TokenAccountOwnerMismatch,
+    #[msg("Token account is not the auction's associated token account for the bid mint")]
+    AuctionBidTokenAccountMismatch,
 
     // token mint
     #[msg("Mint Mismatch!")]
@@ -23,6 +27,24 @@ pub enum ErrorCode {
     AuctionFactoryUuidInvalidLengthError,
     #[msg("Uuid must be length 5")]
     ConfigUuidInvalidLengthError,
+    #[msg("Invalid auction factory data.")]
+    InvalidAuctionFactoryData,
+    #[msg("Revenue shares must be non-zero and total 10,000 basis points.")]
+    InvalidRevenueSplit,
+    #[msg("Revenue recipient accounts do not match the revenue split.")]
+    RevenueRecipientMismatch,
+    #[msg("Auction factories with a bid mint must set an unsold resource recipient.")]
+    UnsoldResourceRecipientRequired,
+    #[msg("Unsold resource recipient mismatch.")]
+    UnsoldResourceRecipientMismatch,
+    #[msg("Auction factory has no pending authority.")]
+    NoPendingAuthority,
+    #[msg("Signer is not the auction factory's pending authority.")]
+    PendingAuthorityMismatch,
+    #[msg("Auction factory has no pending change of this kind.")]
+    NoPendingChange,
+    #[msg("Pending change cannot be executed until its delay has passed.")]
+    PendingChangeTimelocked,
 
     // config
     #[msg("Config element too short. Config data elements must be at least 1 char in length.")]
@@ -37,6 +59,8 @@ pub enum ErrorCode {
     InactiveAuction,
     #[msg("Auction resource can only be generated once.")]
     AuctionResourceAlreadyExists,
+    #[msg("Auction resource was carried over from the previous auction.")]
+    AuctionResourceRolledOver,
     #[msg("Must settle any ongoing auction before creating a new auction.")]
     UnsettledAuction,
     #[msg("Auction is already settled.")]
@@ -55,10 +79,72 @@ pub enum ErrorCode {
     AuctionsAlreadyInitialized,
     #[msg("Bidder is already winning the auction")]
     BidderAlreadyWinning,
+    #[msg("Bidder is not the winning bidder.")]
+    BidderNotWinning,
     #[msg("Wrong settle auction endpoint!")]
     WrongSettleAuctionEndpoint,
     #[msg("Must supply resource to auction before settling!")]
     AuctionHasNoResourceAvailable,
+    #[msg("Auction must be settled before performing such action.")]
+    AuctionNotSettled,
+    #[msg("Instruction does not support this auction type.")]
+    WrongAuctionType,
+    #[msg("Current price exceeds the max price the buyer is willing to pay.")]
+    PriceExceedsMaxPrice,
+    #[msg("Auction has been cancelled.")]
+    AuctionCancelled,
+    #[msg("Auction has been cancelled or voided.")]
+    AuctionVoid,
+    #[msg("Auction has ended and can no longer be cancelled.")]
+    AuctionEndedCannotBeCancelled,
+    #[msg("Auction factory has no settlement grace period.")]
+    NoSettlementGracePeriod,
+    #[msg("Auction can still be settled.")]
+    SettlementGracePeriodActive,
+    #[msg("The auction factory's current auction cannot be closed.")]
+    CurrentAuctionCannotBeClosed,
+    #[msg("Auction token account must be closed first.")]
+    AuctionTokenAccountNotClosed,
+    #[msg("Auction still holds escrowed bids.")]
+    AuctionHasEscrowedFunds,
+    #[msg("Auction still has open bid refund accounts.")]
+    AuctionHasOpenBidRefunds,
+
+    // bid refund
+    #[msg("Bidder has no funds available to claim from this auction.")]
+    NoRefundAvailable,
+    #[msg("Bid refund still holds funds to claim.")]
+    BidRefundNotEmpty,
+    #[msg("Leading proxy bid account does not match the auction's proxy bidder.")]
+    ProxyBidMismatch,
+
+    // sealed bid
+    #[msg("Bidder has already committed a sealed bid to this auction.")]
+    SealedBidAlreadyCommitted,
+    #[msg("Bidder has no sealed bid to reveal.")]
+    SealedBidNotCommitted,
+    #[msg("Sealed bid has already been revealed.")]
+    SealedBidAlreadyRevealed,
+    #[msg("Revealed bid does not match commitment.")]
+    SealedBidCommitmentMismatch,
+    #[msg("Revealed bid exceeds deposit.")]
+    SealedBidExceedsDeposit,
+
+    // multi-winner auction
+    #[msg("Instruction does not support multi-winner auctions.")]
+    MultiWinnerAuctionNotSupported,
+    #[msg("Instruction only supports multi-winner auctions.")]
+    SingleWinnerAuction,
+    #[msg("No winning bid at this index.")]
+    InvalidWinnerIndex,
+    #[msg("Winner has already received their edition.")]
+    EditionAlreadyDistributed,
+    #[msg("All print editions must be distributed before the master edition.")]
+    PrintEditionsNotDistributed,
+
+    // bid receipt
+    #[msg("Bid receipt address mismatch.")]
+    BidReceiptAddressMismatch,
 
     // numbooooooor ops
     #[msg("Numerical overflow error!")]
@@ -81,4 +167,14 @@ pub enum ErrorCode {
     InsufficientAccountBalance,
     #[msg("Forced error")]
     ForcedError,
+    #[msg("Unable to read slot hashes")]
+    InvalidSlotHashes,
+    #[msg("Candle auction close must be requested before settling.")]
+    CandleCloseNotRequested,
+    #[msg("Candle auction close has already been requested.")]
+    CandleCloseAlreadyRequested,
+    #[msg("Candle auction close slot has not been reached yet.")]
+    CandleCloseSlotNotReached,
+    #[msg("Candle auction close slot is no longer available, request the close again.")]
+    CandleCloseSlotExpired,
 }
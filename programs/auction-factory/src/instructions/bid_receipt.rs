@@ -0,0 +1,106 @@
+use {
+    crate::{
+        constant::BID_RECEIPT_SEED,
+        error::ErrorCode,
+        structs::{
+            auction::Auction,
+            bid_receipt::{BidReceipt, BID_RECEIPT_ACCOUNT_SPACE},
+        },
+    },
+    anchor_lang::{prelude::*, solana_program},
+    solana_program::{
+        program::{invoke, invoke_signed},
+        system_instruction,
+    },
+};
+
+// receipts are optional. callers pass one receipt account per bid recorded by the instruction,
+// in order, via remaining accounts; bids without a matching account are not receipted. a proxy
+// bid can record more than one bid in a single instruction.
+pub fn record_bid_receipts<'info>(
+    program_id: &Pubkey,
+    receipt_accounts: &[AccountInfo<'info>],
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    auction: &Account<'info, Auction>,
+    first_bid_index: u64,
+) -> ProgramResult {
+    let num_new_bids: usize = auction
+        .bid_count
+        .checked_sub(first_bid_index)
+        .ok_or(ErrorCode::NumericalUnderflowError)? as usize;
+    // newest bids are at the end of the bid vec
+    let new_bids = &auction.bids[auction.bids.len().saturating_sub(num_new_bids)..];
+
+    let auction_key = auction.key();
+    let rent_lamports = Rent::get()?.minimum_balance(BID_RECEIPT_ACCOUNT_SPACE);
+
+    for (offset, (bid, receipt_account)) in new_bids.iter().zip(receipt_accounts.iter()).enumerate()
+    {
+        let index = first_bid_index
+            .checked_add(offset as u64)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+        let index_str = index.to_string();
+
+        let (receipt_address, bump) = Pubkey::find_program_address(
+            &[
+                BID_RECEIPT_SEED.as_bytes(),
+                auction_key.as_ref(),
+                index_str.as_bytes(),
+            ],
+            program_id,
+        );
+        if receipt_address != receipt_account.key() {
+            return Err(ErrorCode::BidReceiptAddressMismatch.into());
+        }
+
+        let signer_seeds: &[&[u8]] = &[
+            BID_RECEIPT_SEED.as_bytes(),
+            auction_key.as_ref(),
+            index_str.as_bytes(),
+            &[bump],
+        ];
+
+        // create_account fails if anyone pre-funds the receipt address, so top up, allocate
+        // and assign instead
+        let required_lamports = rent_lamports.saturating_sub(receipt_account.lamports());
+        if required_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, receipt_account.key, required_lamports),
+                &[
+                    payer.clone(),
+                    receipt_account.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+        }
+
+        invoke_signed(
+            &system_instruction::allocate(receipt_account.key, BID_RECEIPT_ACCOUNT_SPACE as u64),
+            &[receipt_account.clone(), system_program.clone()],
+            &[signer_seeds],
+        )?;
+
+        invoke_signed(
+            &system_instruction::assign(receipt_account.key, program_id),
+            &[receipt_account.clone(), system_program.clone()],
+            &[signer_seeds],
+        )?;
+
+        let receipt = BidReceipt {
+            bump,
+            auction: auction_key,
+            index,
+            bidder: bid.bidder,
+            amount: bid.amount,
+            updated_at: bid.updated_at,
+            payer: payer.key(),
+        };
+
+        let mut data = receipt_account.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        receipt.try_serialize(&mut writer)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use {
+    crate::{
+        constant::AUX_SEED,
+        context::Buy,
+        error::ErrorCode,
+        instructions::{
+            revenue_split,
+            transfer::{spl_token_transfer, transfer_from_signer, TokenTransferParams},
+        },
+        structs::auction::Auction,
+        util::general::get_current_timestamp,
+        verify::{verify_token_account, verify_treasury_for_bid_mint},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token,
+};
+
+pub fn get_current_price(auction: &Account<Auction>) -> Result<u64, ProgramError> {
+    let current_timestamp = get_current_timestamp()?;
+
+    let elapsed = current_timestamp
+        .checked_sub(auction.start_time)
+        .ok_or(ErrorCode::NumericalUnderflowError)?;
+    let duration = auction
+        .end_time
+        .checked_sub(auction.start_time)
+        .ok_or(ErrorCode::NumericalUnderflowError)?;
+
+    auction.dutch_auction.price_at(elapsed, duration)
+}
+
+// pay the treasury, or the revenue split recipients passed as remaining accounts, directly.
+// then send the resource to the buyer and settle the auction.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, Buy<'info>>,
+    bump: u8,
+    sequence: u64,
+    price: u64,
+) -> ProgramResult {
+    if let Some(token_mint) = ctx.accounts.auction.token_mint {
+        verify_token_account(
+            &ctx.accounts.buyer_payment_token_account,
+            token_mint,
+            ctx.accounts.buyer.key(),
+        )?;
+        verify_treasury_for_bid_mint(&ctx.accounts.treasury, Some(token_mint))?;
+    }
+
+    revenue_split::distribute_revenue(
+        &ctx.accounts.auction_factory,
+        &ctx.accounts.treasury,
+        ctx.remaining_accounts,
+        price,
+        |destination, amount| {
+            if ctx.accounts.auction.token_mint.is_some() {
+                token::transfer(ctx.accounts.into_token_payment_context(destination), amount)
+            } else {
+                transfer_from_signer(ctx.accounts.into_payment_context(destination), amount)
+            }
+        },
+    )?;
+
+    let seq_str = sequence.to_string();
+    spl_token_transfer(TokenTransferParams {
+        source: ctx.accounts.auction_token_account.to_account_info(),
+        destination: ctx.accounts.buyer_token_account.to_account_info(),
+        authority: ctx.accounts.auction.to_account_info().clone(),
+        authority_signer_seeds: &[
+            AUX_SEED.as_bytes(),
+            ctx.accounts.auction_factory.key().as_ref(),
+            seq_str.as_bytes(),
+            &[bump],
+        ],
+        token_program: ctx.accounts.token_program.to_account_info(),
+        amount: 1,
+    })?;
+
+    ctx.accounts.auction.settle();
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use {
+    crate::{
+        constant::AUX_SEED,
+        error::ErrorCode,
+        instructions::transfer::{spl_token_transfer, TokenTransferParams},
+        verify::verify_token_account,
+        CancelAuction,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token,
+};
+
+// bids are not pushed back here. once cancelled, every bidder, including the leader, can
+// withdraw their full escrow via claim_refund.
+pub fn handle(
+    ctx: Context<CancelAuction>,
+    bump: u8,
+    sequence: u64,
+    burn_resource: bool,
+) -> ProgramResult {
+    if let Some(resource) = ctx.accounts.auction.resource {
+        if ctx.accounts.mint.key() != resource {
+            return Err(ErrorCode::MintMismatch.into());
+        }
+        verify_token_account(
+            &ctx.accounts.auction_token_account,
+            resource,
+            ctx.accounts.auction.key(),
+        )?;
+
+        let auction_factory_key = ctx.accounts.auction_factory.key();
+        let seq_str = sequence.to_string();
+        let auction_seeds: &[&[u8]] = &[
+            AUX_SEED.as_bytes(),
+            auction_factory_key.as_ref(),
+            seq_str.as_bytes(),
+            &[bump],
+        ];
+
+        if burn_resource {
+            token::burn(
+                ctx.accounts
+                    .into_burn_token_context()
+                    .with_signer(&[auction_seeds]),
+                1,
+            )?;
+        } else {
+            // nothing moves tokens out of accounts owned by the auction factory, so the resource
+            // goes to the authority instead
+            verify_token_account(
+                &ctx.accounts.authority_token_account,
+                resource,
+                ctx.accounts.auction_factory.authority,
+            )?;
+
+            spl_token_transfer(TokenTransferParams {
+                source: ctx.accounts.auction_token_account.to_account_info(),
+                destination: ctx.accounts.authority_token_account.to_account_info(),
+                authority: ctx.accounts.auction.to_account_info(),
+                authority_signer_seeds: auction_seeds,
+                token_program: ctx.accounts.token_program.to_account_info(),
+                amount: 1,
+            })?;
+        }
+    }
+
+    ctx.accounts.auction.cancel();
+
+    Ok(())
+}
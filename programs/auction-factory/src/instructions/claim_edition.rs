@@ -0,0 +1,96 @@
+use {
+    crate::{
+        constant::AUX_SEED,
+        context::{ClaimMasterEdition, ClaimPrintEdition},
+        error::ErrorCode,
+        instructions::{
+            mint_edition,
+            transfer::{spl_token_transfer, TokenTransferParams},
+        },
+        verify::verify_token_account,
+    },
+    anchor_lang::prelude::*,
+};
+
+// winners of a multi-winner auction are ranked by bid. the top bidder receives the master
+// edition, every other winner the print numbered by their rank.
+pub fn handle_print_edition(
+    ctx: Context<ClaimPrintEdition>,
+    auction_bump: u8,
+    sequence: u64,
+    winner_index: u8,
+) -> ProgramResult {
+    if winner_index == 0 {
+        return Err(ErrorCode::InvalidWinnerIndex.into());
+    }
+
+    let winning_bid = *ctx
+        .accounts
+        .auction
+        .winning_bids
+        .get(winner_index as usize)
+        .ok_or(ErrorCode::InvalidWinnerIndex)?;
+
+    if ctx.accounts.auction.is_edition_distributed(winner_index) {
+        return Err(ErrorCode::EditionAlreadyDistributed.into());
+    }
+
+    // the new mint's entire supply must belong to the winner
+    let winner_token_account = verify_token_account(
+        &ctx.accounts.winner_token_account,
+        ctx.accounts.new_mint.key(),
+        winning_bid.bidder,
+    )?;
+    if winner_token_account.amount != 1 {
+        return Err(ErrorCode::InsufficientAccountBalance.into());
+    }
+
+    let seq_str = sequence.to_string();
+    mint_edition::handle(
+        ctx.accounts.into_mint_edition_context().with_signer(&[&[
+            AUX_SEED.as_bytes(),
+            ctx.accounts.auction_factory.key().as_ref(),
+            seq_str.as_bytes(),
+            &[auction_bump],
+        ]]),
+        winner_index as u64,
+    )?;
+
+    msg!(
+        "distributed edition {} to {}",
+        winner_index,
+        winning_bid.bidder
+    );
+    ctx.accounts.auction.mark_edition_distributed(winner_index);
+
+    Ok(())
+}
+
+pub fn handle_master_edition(
+    ctx: Context<ClaimMasterEdition>,
+    auction_bump: u8,
+    sequence: u64,
+) -> ProgramResult {
+    if !ctx.accounts.auction.print_editions_distributed() {
+        return Err(ErrorCode::PrintEditionsNotDistributed.into());
+    }
+
+    let seq_str = sequence.to_string();
+    spl_token_transfer(TokenTransferParams {
+        source: ctx.accounts.auction_token_account.to_account_info(),
+        destination: ctx.accounts.bidder_token_account.to_account_info(),
+        authority: ctx.accounts.auction.to_account_info(),
+        authority_signer_seeds: &[
+            AUX_SEED.as_bytes(),
+            ctx.accounts.auction_factory.key().as_ref(),
+            seq_str.as_bytes(),
+            &[auction_bump],
+        ],
+        token_program: ctx.accounts.token_program.to_account_info(),
+        amount: 1,
+    })?;
+
+    ctx.accounts.auction.mark_edition_distributed(0);
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use {
+    crate::{
+        constant::AUX_SEED,
+        context::ClaimRefund,
+        error::ErrorCode,
+        instructions::transfer::{spl_token_transfer, transfer_lamports, TokenTransferParams},
+        structs::bid_refund::BidRefund,
+        verify::{
+            verify_auction_bid_token_account, verify_token_account, verify_treasury_for_bid_mint,
+        },
+    },
+    anchor_lang::{prelude::*, AccountsClose},
+};
+
+// move escrowed funds out of the auction, from its bid token account for SPL auctions
+fn transfer_from_auction<'info>(
+    ctx: &Context<'_, '_, '_, 'info, ClaimRefund<'info>>,
+    destination: AccountInfo<'info>,
+    auction_bump: u8,
+    sequence: u64,
+    amount: u64,
+) -> ProgramResult {
+    if ctx.accounts.auction.token_mint.is_some() {
+        let seq_str = sequence.to_string();
+        spl_token_transfer(TokenTransferParams {
+            source: ctx.accounts.auction_bid_token_account.to_account_info(),
+            destination,
+            authority: ctx.accounts.auction.to_account_info(),
+            authority_signer_seeds: &[
+                AUX_SEED.as_bytes(),
+                ctx.accounts.auction_factory.key().as_ref(),
+                seq_str.as_bytes(),
+                &[auction_bump],
+            ],
+            token_program: ctx.accounts.token_program.to_account_info(),
+            amount,
+        })?;
+    } else {
+        // auction PDA carries data, so lamports are moved directly instead of via the system program
+        transfer_lamports(
+            &ctx.accounts.auction.to_account_info(),
+            &destination,
+            amount,
+        )?;
+    }
+
+    Ok(())
+}
+
+// pay out the claimable amount, less any penalty for an unrevealed sealed bid
+fn withdraw_refund<'info>(
+    ctx: &mut Context<'_, '_, '_, 'info, ClaimRefund<'info>>,
+    amount: u64,
+    auction_bump: u8,
+    sequence: u64,
+) -> ProgramResult {
+    // bidders of a cancelled or voided auction are not at fault, so no penalty applies
+    let penalty_amount = if ctx.accounts.auction.is_void() {
+        0
+    } else {
+        ctx.accounts
+            .bid_refund
+            .take_non_reveal_penalty(ctx.accounts.auction.non_reveal_penalty)?
+    };
+    if penalty_amount > 0 {
+        verify_treasury_for_bid_mint(&ctx.accounts.treasury, ctx.accounts.auction.token_mint)?;
+        transfer_from_auction(
+            ctx,
+            ctx.accounts.treasury.to_account_info(),
+            auction_bump,
+            sequence,
+            penalty_amount,
+        )?;
+    }
+
+    let refund_amount = amount
+        .checked_sub(penalty_amount)
+        .ok_or(ErrorCode::NumericalUnderflowError)?;
+    let destination = if ctx.accounts.auction.token_mint.is_some() {
+        ctx.accounts.bidder_token_account.to_account_info()
+    } else {
+        ctx.accounts.bidder.to_account_info()
+    };
+    transfer_from_auction(ctx, destination, auction_bump, sequence, refund_amount)?;
+
+    ctx.accounts.bid_refund.withdraw(refund_amount)?;
+    ctx.accounts.auction.release_escrow(amount)?;
+
+    Ok(())
+}
+
+pub fn handle<'info>(
+    mut ctx: Context<'_, '_, '_, 'info, ClaimRefund<'info>>,
+    auction_bump: u8,
+    sequence: u64,
+) -> ProgramResult {
+    if let Some(token_mint) = ctx.accounts.auction.token_mint {
+        verify_auction_bid_token_account(
+            &ctx.accounts.auction_bid_token_account,
+            &ctx.accounts.auction,
+            token_mint,
+        )?;
+        verify_token_account(
+            &ctx.accounts.bidder_token_account,
+            token_mint,
+            ctx.accounts.bidder.key(),
+        )?;
+    }
+
+    let amount = ctx
+        .accounts
+        .bid_refund
+        .claimable_amount(&ctx.accounts.auction)?;
+
+    let auction_is_over = ctx.accounts.auction.settled || ctx.accounts.auction.is_void();
+    if amount > 0 {
+        withdraw_refund(&mut ctx, amount, auction_bump, sequence)?;
+    } else if !auction_is_over {
+        return Err(ErrorCode::NoRefundAvailable.into());
+    }
+
+    // once the auction is over and nothing is left to claim, the rent goes back to the bidder.
+    // the record is reset first because anchor persists the account again on exit.
+    let refund_is_withdrawn = ctx
+        .accounts
+        .bid_refund
+        .claimable_amount(&ctx.accounts.auction)?
+        == 0;
+    if auction_is_over && refund_is_withdrawn {
+        let bid_refund: &mut BidRefund = &mut ctx.accounts.bid_refund;
+        *bid_refund = BidRefund::default();
+        ctx.accounts
+            .bid_refund
+            .close(ctx.accounts.bidder.to_account_info())?;
+        ctx.accounts.auction.close_bid_refund()?;
+    }
+
+    Ok(())
+}
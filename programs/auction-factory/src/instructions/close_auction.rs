@@ -0,0 +1,71 @@
+use {
+    crate::{
+        constant::AUX_SEED,
+        instructions::transfer::{spl_token_transfer, transfer_lamports, TokenTransferParams},
+        util::general::get_available_lamports,
+        verify::{verify_auction_bid_token_account, verify_treasury_for_bid_mint},
+        CloseAuction,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token,
+};
+
+// anyone can send funds to an auction. once every bid has been paid out or claimed, whatever
+// the auction still holds beyond its rent goes to the treasury. the emptied bid token account of
+// SPL auctions is closed, its rent also goes to the treasury.
+pub fn sweep_surplus_to_treasury(
+    ctx: &Context<CloseAuction>,
+    bump: u8,
+    sequence: u64,
+) -> ProgramResult {
+    let auction_factory_key = ctx.accounts.auction_factory.key();
+    let seq_str = sequence.to_string();
+    let auction_seeds: &[&[u8]] = &[
+        AUX_SEED.as_bytes(),
+        auction_factory_key.as_ref(),
+        seq_str.as_bytes(),
+        &[bump],
+    ];
+
+    if let Some(token_mint) = ctx.accounts.auction.token_mint {
+        let surplus = verify_auction_bid_token_account(
+            &ctx.accounts.auction_bid_token_account,
+            &ctx.accounts.auction,
+            token_mint,
+        )?
+        .amount;
+        if surplus > 0 {
+            verify_treasury_for_bid_mint(&ctx.accounts.treasury, Some(token_mint))?;
+            msg!("sweeping {} surplus tokens to the treasury", surplus);
+
+            spl_token_transfer(TokenTransferParams {
+                source: ctx.accounts.auction_bid_token_account.to_account_info(),
+                destination: ctx.accounts.treasury.to_account_info(),
+                authority: ctx.accounts.auction.to_account_info(),
+                authority_signer_seeds: auction_seeds,
+                token_program: ctx.accounts.token_program.to_account_info(),
+                amount: surplus,
+            })?;
+        }
+
+        token::close_account(
+            ctx.accounts
+                .into_close_bid_token_account_context()
+                .with_signer(&[auction_seeds]),
+        )?;
+    } else {
+        let surplus = get_available_lamports(&ctx.accounts.auction.to_account_info())?;
+        if surplus == 0 {
+            return Ok(());
+        }
+
+        msg!("sweeping {} surplus lamports to the treasury", surplus);
+        transfer_lamports(
+            &ctx.accounts.auction.to_account_info(),
+            &ctx.accounts.treasury.to_account_info(),
+            surplus,
+        )?;
+    }
+
+    Ok(())
+}
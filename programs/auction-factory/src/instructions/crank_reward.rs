@@ -0,0 +1,43 @@
+use {
+    crate::{
+        constant::BASIS_POINTS_DENOMINATOR, error::ErrorCode,
+        instructions::transfer::transfer_lamports, util::general::get_available_lamports,
+    },
+    anchor_lang::prelude::*,
+};
+
+// fixed crank rewards are funded by the lamports the auction factory collects, e.g. royalties.
+// a factory that cannot cover the reward skips it rather than blocking the crank.
+pub fn pay_fixed_reward(
+    auction_factory: &AccountInfo<'_>,
+    payer: &AccountInfo<'_>,
+    amount: u64,
+) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let available_lamports = get_available_lamports(auction_factory)?;
+    if available_lamports < amount {
+        msg!(
+            "auction factory cannot cover crank reward of {} lamports",
+            amount
+        );
+        return Ok(());
+    }
+
+    transfer_lamports(auction_factory, payer, amount)?;
+
+    Ok(())
+}
+
+// share of the proceeds, in basis points, paid to whoever settles the auction
+pub fn get_winning_bid_reward(amount: u64, winning_bid_share: u64) -> Result<u64, ProgramError> {
+    let reward = (amount as u128)
+        .checked_mul(winning_bid_share as u128)
+        .ok_or(ErrorCode::NumericalOverflowError)?
+        .checked_div(BASIS_POINTS_DENOMINATOR as u128)
+        .ok_or(ErrorCode::NumericalDivisionError)?;
+
+    Ok(reward as u64)
+}
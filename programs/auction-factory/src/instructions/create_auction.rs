@@ -10,8 +10,12 @@ pub fn handle(
     bump: u8,
     auction: &mut Auction,
     auction_factory: &mut AuctionFactory,
+    previous_end_time: Option<u64>,
 ) -> ProgramResult {
     let current_timestamp = get_current_timestamp().unwrap();
+    let start_time = auction_factory
+        .data
+        .get_start_time(current_timestamp, previous_end_time)?;
 
     // don't move: keeps auction factory sequence === auction sequence
     auction_factory.increment_sequence();
@@ -20,7 +24,8 @@ pub fn handle(
         bump,
         auction_factory.sequence,
         auction_factory.authority.key(),
-        current_timestamp,
+        start_time,
+        auction_factory.bid_mint,
         auction_factory.data,
     );
 
@@ -1,8 +1,7 @@
 use {
-    anchor_lang::prelude::*,
-    anchor_spl::token::Token,
+    anchor_lang::prelude::*, anchor_spl::token::Token,
     mpl_token_metadata::instruction::create_master_edition_v3,
-    solana_program::program::invoke_signed
+    solana_program::program::invoke_signed,
 };
 
 #[derive(Accounts)]
@@ -26,6 +25,7 @@ pub struct CreateMasterEdition<'info> {
 
 pub fn handle<'a, 'b, 'c, 'info>(
     ctx: CpiContext<'a, 'b, 'c, 'info, CreateMasterEdition<'info>>,
+    max_supply: u64,
 ) -> ProgramResult {
     invoke_signed(
         &create_master_edition_v3(
@@ -36,7 +36,7 @@ pub fn handle<'a, 'b, 'c, 'info>(
             *ctx.accounts.mint_authority.key,
             *ctx.accounts.metadata.key,
             *ctx.accounts.payer.key,
-            Some(0),
+            Some(max_supply),
         ),
         &[
             ctx.accounts.master_edition.to_account_info(),
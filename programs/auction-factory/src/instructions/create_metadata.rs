@@ -1,9 +1,7 @@
 use {
-    crate::structs::metadata::MetadataInfo,
-    anchor_lang::prelude::*,
-    anchor_spl::token::Token,
+    crate::structs::metadata::MetadataInfo, anchor_lang::prelude::*, anchor_spl::token::Token,
     mpl_token_metadata::instruction::create_metadata_accounts_v2,
-    solana_program::program::invoke_signed
+    solana_program::program::invoke_signed,
 };
 
 #[derive(Accounts)]
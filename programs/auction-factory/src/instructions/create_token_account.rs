@@ -0,0 +1,50 @@
+use {
+    anchor_lang::prelude::*, anchor_spl::token::Token, solana_program::program::invoke_signed,
+    spl_associated_token_account::create_associated_token_account,
+};
+
+#[derive(Accounts)]
+pub struct CreateAssociatedTokenAccount<'info> {
+    pub payer: AccountInfo<'info>,
+    // the following accounts aren't using anchor macros because CPI invocation
+    // will do the required validations.
+    pub associated_token_account: AccountInfo<'info>,
+    // owner of the associated token account
+    pub wallet: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    #[account(address = spl_associated_token_account::id())]
+    pub associated_token_program: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// creating an account that already exists fails, so the account is only created if missing
+pub fn handle<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, CreateAssociatedTokenAccount<'info>>,
+) -> ProgramResult {
+    if !ctx.accounts.associated_token_account.data_is_empty() {
+        return Ok(());
+    }
+
+    invoke_signed(
+        &create_associated_token_account(
+            ctx.accounts.payer.key,
+            ctx.accounts.wallet.key,
+            ctx.accounts.mint.key,
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.associated_token_account.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            ctx.accounts.associated_token_program.to_account_info(),
+        ],
+        ctx.signer_seeds,
+    )?;
+
+    Ok(())
+}
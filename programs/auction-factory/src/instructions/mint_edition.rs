@@ -0,0 +1,74 @@
+use {
+    anchor_lang::prelude::*, anchor_spl::token::Token,
+    mpl_token_metadata::instruction::mint_new_edition_from_master_edition_via_token,
+    solana_program::program::invoke_signed,
+};
+
+#[derive(Accounts)]
+pub struct MintEdition<'info> {
+    pub payer: AccountInfo<'info>,
+    // the following accounts aren't using anchor macros because CPI invocation
+    // will do the required validations.
+    pub new_metadata: AccountInfo<'info>,
+    pub new_edition: AccountInfo<'info>,
+    pub master_edition: AccountInfo<'info>,
+    // mint of the printed edition, with its single token already minted
+    pub new_mint: AccountInfo<'info>,
+    pub new_mint_authority: AccountInfo<'info>,
+    // marks which edition numbers of the master edition have been printed
+    pub edition_mark_pda: AccountInfo<'info>,
+    // owner of the token account holding the master edition token
+    pub token_account_owner: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub new_metadata_update_authority: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub metadata_mint: AccountInfo<'info>,
+    #[account(address = spl_token_metadata::id())]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handle<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, MintEdition<'info>>,
+    edition: u64,
+) -> ProgramResult {
+    invoke_signed(
+        &mint_new_edition_from_master_edition_via_token(
+            *ctx.accounts.token_metadata_program.key,
+            *ctx.accounts.new_metadata.key,
+            *ctx.accounts.new_edition.key,
+            *ctx.accounts.master_edition.key,
+            *ctx.accounts.new_mint.key,
+            *ctx.accounts.new_mint_authority.key,
+            *ctx.accounts.payer.key,
+            *ctx.accounts.token_account_owner.key,
+            *ctx.accounts.token_account.key,
+            *ctx.accounts.new_metadata_update_authority.key,
+            *ctx.accounts.metadata.key,
+            *ctx.accounts.metadata_mint.key,
+            edition,
+        ),
+        &[
+            ctx.accounts.new_metadata.to_account_info(),
+            ctx.accounts.new_edition.to_account_info(),
+            ctx.accounts.master_edition.to_account_info(),
+            ctx.accounts.new_mint.to_account_info(),
+            ctx.accounts.edition_mark_pda.to_account_info(),
+            ctx.accounts.new_mint_authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.token_account_owner.to_account_info(),
+            ctx.accounts.token_account.to_account_info(),
+            ctx.accounts.new_metadata_update_authority.to_account_info(),
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.token_metadata_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        ctx.signer_seeds,
+    )?;
+
+    Ok(())
+}
@@ -1,9 +1,19 @@
+pub mod bid_receipt;
+pub mod buy;
+pub mod cancel_auction;
+pub mod claim_edition;
+pub mod claim_refund;
+pub mod close_auction;
+pub mod crank_reward;
 pub mod create_auction;
-pub mod mint_token;
-pub mod create_metadata;
 pub mod create_master_edition;
-pub mod update_metadata;
-pub mod sign_metadata;
+pub mod create_metadata;
+pub mod create_token_account;
+pub mod mint_edition;
+pub mod mint_token;
 pub mod place_bid;
+pub mod revenue_split;
 pub mod settle_auction;
+pub mod sign_metadata;
 pub mod transfer;
+pub mod update_metadata;
@@ -1,57 +1,138 @@
 use {
-    anchor_lang::prelude::*,
     crate::{
-        context::PlaceBid, error::ErrorCode, instructions::transfer::transfer_from_signer,
-        structs::auction::Auction,
-    }
+        context::PlaceBid,
+        error::ErrorCode,
+        instructions::transfer::transfer_from_signer,
+        structs::{auction::Auction, auction_factory::AuctionFactoryData, bid_refund::BidRefund},
+        verify::{get_minimum_bid_amount, verify_auction_bid_token_account},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token,
 };
 
-pub fn return_losing_bid_amount(ctx: &Context<PlaceBid>) -> ProgramResult {
-    let amount = ctx.accounts.auction.amount;
-    let reserve_price = ctx.accounts.auction_factory.data.min_reserve_price;
-
-    // ignore any amount that is <= min_reserve_price, that will be first bid
-    if amount > reserve_price {
-        let leading_bidder = ctx.accounts.auction.bidder;
-        assert!(leading_bidder.eq(ctx.accounts.leading_bidder.key));
-
-        // since our auction PDA has data in it, we cannot use the system program to withdraw SOL.
-        // otherwise, we will get an error message that says:
-        // >> Transfer: `from` must not carry data
-        // error message source: https://github.com/solana-labs/solana/blob/master/runtime/src/system_instruction_processor.rs#L189
-        // s/o to the solana tech discord history for the help.
-        let newest_losing_bidder = &ctx.accounts.leading_bidder;
-        let auction_as_payer = &ctx.accounts.auction.to_account_info();
-        // protect ourselves from attempting to deduct too many lamports. if auction account lamport balance falls,
-        // below rent price, then we could be subject to paying rent/the garbage collector automatically closing our account,
-        // but i think that is worst case scenario here. any malicious program/behavior would be able to deduct max lamports
-        // in this auction account.
-        let amount_after_deduction: u64 = auction_as_payer
-            .lamports()
-            .checked_sub(amount)
-            .ok_or(ErrorCode::InsufficientAccountBalance)?;
-
-        // sub from auction
-        **auction_as_payer.lamports.borrow_mut() = amount_after_deduction;
-
-        // return lamports to losing bidder
-        **newest_losing_bidder.lamports.borrow_mut() = newest_losing_bidder
-            .lamports()
-            .checked_add(amount)
-            .ok_or(ErrorCode::NumericalOverflowError)?;
+pub fn transfer_bid_amount(ctx: &Context<PlaceBid>, amount: u64) -> ProgramResult {
+    if let Some(token_mint) = ctx.accounts.auction.token_mint {
+        // escrow must be owned by the auction so that it can sign for refunds & settlement
+        verify_auction_bid_token_account(
+            &ctx.accounts.auction_bid_token_account,
+            &ctx.accounts.auction,
+            token_mint,
+        )?;
+
+        token::transfer(ctx.accounts.into_receive_token_bid_context(), amount)?;
+    } else {
+        transfer_from_signer(ctx.accounts.into_receive_bid_context(), amount)?;
     }
 
     Ok(())
 }
 
-pub fn transfer_bid_amount(ctx: &Context<PlaceBid>, amount: u64) -> ProgramResult {
-    transfer_from_signer(ctx.accounts.into_receive_bid_context(), amount)?;
+// outbid funds are not returned here. the previous leader withdraws them via claim_refund,
+// so placing a bid never needs to touch the previous leader's accounts.
+pub fn record_bid_refund(
+    bid_refund: &mut BidRefund,
+    bid_refund_bump: u8,
+    auction: &mut Account<Auction>,
+    bidder: Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    if !bid_refund.is_initialized() {
+        bid_refund.init(bid_refund_bump, auction.key(), bidder);
+        auction.open_bid_refund()?;
+    }
+
+    bid_refund.deposit(amount)?;
+    auction.escrow(amount)?;
 
     Ok(())
 }
 
-pub fn handle(amount: u64, bidder: Pubkey, auction: &mut Auction) -> ProgramResult {
-    auction.update_auction_with_bid(amount, bidder)?;
+// returns the leading proxy bidder and its ceiling, if the leading bid is a proxy bid
+pub fn get_leading_proxy(ctx: &Context<PlaceBid>) -> Result<Option<(Pubkey, u64)>, ProgramError> {
+    let proxy_bidder = match ctx.accounts.auction.proxy_bidder {
+        Some(proxy_bidder) => proxy_bidder,
+        None => return Ok(None),
+    };
+
+    let leading_bid_refund: Account<BidRefund> =
+        Account::try_from(&ctx.accounts.leading_bid_refund)?;
+    if leading_bid_refund.auction != ctx.accounts.auction.key()
+        || leading_bid_refund.bidder != proxy_bidder
+    {
+        return Err(ErrorCode::ProxyBidMismatch.into());
+    }
+
+    Ok(Some((proxy_bidder, leading_bid_refund.max_amount)))
+}
+
+fn place_proxy_bid(
+    auction: &mut Auction,
+    factory_data: &AuctionFactoryData,
+    proxy_bidder: Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    // nothing to counter once the auction was won at the buy it now price
+    if auction.has_closed() {
+        return Ok(());
+    }
+
+    auction.update_auction_with_bid(amount, proxy_bidder, factory_data)?;
+    auction.proxy_bidder = Some(proxy_bidder);
+
+    Ok(())
+}
+
+// records the incoming bid, then lets a leading proxy bid counter at the minimum increment
+// for as long as its ceiling allows. when both bids are proxies, the higher ceiling wins at
+// one increment above the other ceiling; ties go to the proxy that was placed first.
+pub fn handle(
+    auction: &mut Auction,
+    factory_data: &AuctionFactoryData,
+    bidder: Pubkey,
+    amount: u64,
+    max_amount: Option<u64>,
+    leading_proxy: Option<(Pubkey, u64)>,
+) -> ProgramResult {
+    auction.update_auction_with_bid(amount, bidder, factory_data)?;
+    auction.proxy_bidder = max_amount.map(|_| bidder);
+
+    if auction.has_closed() {
+        return Ok(());
+    }
+
+    let (proxy_bidder, proxy_max_amount) = match leading_proxy {
+        Some(leading_proxy) => leading_proxy,
+        None => return Ok(()),
+    };
+
+    // leading proxy has been outbid beyond its ceiling
+    let counter_amount = get_minimum_bid_amount(auction.amount, factory_data)?;
+    if proxy_max_amount < counter_amount {
+        return Ok(());
+    }
+
+    let incoming_max_amount = match max_amount {
+        Some(incoming_max_amount) => incoming_max_amount,
+        None => return place_proxy_bid(auction, factory_data, proxy_bidder, counter_amount),
+    };
+
+    let amount_to_beat_incoming = get_minimum_bid_amount(incoming_max_amount, factory_data)?;
+    if proxy_max_amount >= amount_to_beat_incoming {
+        // incoming proxy exhausts its ceiling, then the leading proxy counters
+        if incoming_max_amount > auction.amount {
+            place_proxy_bid(auction, factory_data, bidder, incoming_max_amount)?;
+        }
+
+        return place_proxy_bid(auction, factory_data, proxy_bidder, amount_to_beat_incoming);
+    }
+
+    // leading proxy exhausts its ceiling, then the incoming proxy counters if it can
+    place_proxy_bid(auction, factory_data, proxy_bidder, proxy_max_amount)?;
+
+    let amount_to_beat_leading = get_minimum_bid_amount(proxy_max_amount, factory_data)?;
+    if incoming_max_amount >= amount_to_beat_leading {
+        place_proxy_bid(auction, factory_data, bidder, amount_to_beat_leading)?;
+    }
 
     Ok(())
 }
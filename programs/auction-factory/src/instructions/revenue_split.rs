@@ -0,0 +1,37 @@
+use {
+    crate::{structs::auction_factory::AuctionFactory, verify::verify_revenue_recipients},
+    anchor_lang::prelude::*,
+};
+
+// pays amount out across the auction factory's revenue split via pay, or entirely to the treasury
+// if there is no split. recipient accounts are passed in revenue split order.
+pub fn distribute_revenue<'info, F>(
+    auction_factory: &AuctionFactory,
+    treasury: &AccountInfo<'info>,
+    recipient_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    mut pay: F,
+) -> ProgramResult
+where
+    F: FnMut(AccountInfo<'info>, u64) -> ProgramResult,
+{
+    if auction_factory.revenue_split.is_empty() {
+        return pay(treasury.clone(), amount);
+    }
+
+    // recipients are checked when the split is queued, check again in case an account changed since
+    verify_revenue_recipients(
+        &auction_factory.revenue_split,
+        recipient_accounts,
+        auction_factory.bid_mint,
+    )?;
+
+    let amounts = auction_factory.get_revenue_split_amounts(amount)?;
+    for (share_amount, recipient_account) in amounts.into_iter().zip(recipient_accounts.iter()) {
+        if share_amount > 0 {
+            pay(recipient_account.clone(), share_amount)?;
+        }
+    }
+
+    Ok(())
+}
@@ -1,41 +1,135 @@
 use {
-    anchor_lang::prelude::*,
-    anchor_spl::token,
     crate::{
-        SettleAuction,
         constant::AUX_SEED,
-        instructions::transfer::{spl_token_transfer, TokenTransferParams, transfer_lamports}
-    }
+        error::ErrorCode,
+        instructions::{
+            crank_reward::get_winning_bid_reward,
+            revenue_split,
+            transfer::{spl_token_transfer, transfer_lamports, TokenTransferParams},
+        },
+        structs::{
+            auction::Auction,
+            auction_factory::{AuctionFactoryData, UnsoldResourcePolicy},
+        },
+        util::general::{get_auction_account_address, get_random_value_from_slot_hashes},
+        verify::{get_minimum_bid_amount, verify_token_account},
+        SettleAuction,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token,
+    spl_token::instruction::AuthorityType,
 };
 
-pub fn handle_empty_auction(
-    ctx: Context<SettleAuction>,
-    bump: u8,
-    sequence: u64
-) -> ProgramResult {
+// candle auctions close at a random moment of their ending period, sampled from the hash of the
+// close slot requested once bidding was over. the settler cannot pick another hash.
+pub fn close_candle_auction(auction: &mut Auction, slot_hashes: &AccountInfo) -> ProgramResult {
+    if auction.candle_close_slot == 0 {
+        return Err(ErrorCode::CandleCloseNotRequested.into());
+    }
+
+    let ending_period = auction
+        .end_time
+        .checked_sub(auction.candle_start_time)
+        .ok_or(ErrorCode::NumericalUnderflowError)?;
+
+    let close_offset = get_random_value_from_slot_hashes(slot_hashes, auction.candle_close_slot)?
+        .checked_rem(ending_period)
+        .ok_or(ErrorCode::CheckedRemError)?;
+    let close_time = auction
+        .candle_start_time
+        .checked_add(close_offset)
+        .ok_or(ErrorCode::NumericalOverflowError)?;
+
+    auction.close_candle_auction(close_time)?;
+    msg!("candle auction closed at {}", auction.finalized_end_time);
+
+    Ok(())
+}
+
+// second price: one increment above the second highest bid, or above the reserve price if
+// there was only one bid. never more than the winning bid.
+pub fn get_second_price_amount(
+    auction: &Auction,
+    factory_data: &AuctionFactoryData,
+) -> Result<u64, ProgramError> {
+    let base_amount = if auction.second_highest_amount > 0 {
+        auction.second_highest_amount
+    } else {
+        factory_data.min_reserve_price
+    };
+
+    let second_price_amount = get_minimum_bid_amount(base_amount, factory_data)?;
+
+    Ok(std::cmp::min(second_price_amount, auction.amount))
+}
+
+pub fn handle_empty_auction(ctx: Context<SettleAuction>, bump: u8, sequence: u64) -> ProgramResult {
+    let auction_factory_key = ctx.accounts.auction_factory.key();
     let seq_str = sequence.to_string();
-    token::burn(
-        ctx.accounts
-            .into_burn_token_context()
-            .with_signer(&[&[
-                AUX_SEED.as_bytes(),
-                ctx.accounts.auction_factory.key().as_ref(),
-                seq_str.as_bytes(),
-                &[bump],
-            ]]),
-        1
-    )?;
+    let auction_seeds = &[
+        AUX_SEED.as_bytes(),
+        auction_factory_key.as_ref(),
+        seq_str.as_bytes(),
+        &[bump],
+    ];
+
+    match ctx.accounts.auction.unsold_resource_policy {
+        UnsoldResourcePolicy::Burn => {
+            token::burn(
+                ctx.accounts
+                    .into_burn_token_context()
+                    .with_signer(&[auction_seeds]),
+                1,
+            )?;
+        }
+        UnsoldResourcePolicy::Transfer => {
+            spl_token_transfer(TokenTransferParams {
+                source: ctx.accounts.auction_token_account.to_account_info(),
+                destination: ctx.accounts.bidder_token_account.to_account_info(),
+                authority: ctx.accounts.auction.to_account_info().clone(),
+                authority_signer_seeds: auction_seeds,
+                token_program: ctx.accounts.token_program.to_account_info(),
+                amount: 1,
+            })?;
+        }
+        // the next auction does not exist yet, but its address is known. it takes over the
+        // token account once created via create_next_auction.
+        UnsoldResourcePolicy::RollOver => {
+            let next_sequence = sequence
+                .checked_add(1)
+                .ok_or(ErrorCode::NumericalOverflowError)?;
+            let (next_auction, _bump) =
+                get_auction_account_address(next_sequence, auction_factory_key);
+
+            token::set_authority(
+                ctx.accounts
+                    .into_set_token_account_owner_context()
+                    .with_signer(&[auction_seeds]),
+                AuthorityType::AccountOwner,
+                Some(next_auction),
+            )?;
+        }
+    }
 
     ctx.accounts.auction.settle();
 
     Ok(())
 }
 
-pub fn handle_auction(
-    ctx: Context<SettleAuction>,
+pub fn handle_auction<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleAuction<'info>>,
     bump: u8,
-    sequence: u64
+    sequence: u64,
 ) -> ProgramResult {
+    // the difference between the winning bid and the second price stays escrowed for the
+    // winner to withdraw via claim_refund.
+    if ctx.accounts.auction.second_price_settlement {
+        let amount =
+            get_second_price_amount(&ctx.accounts.auction, &ctx.accounts.auction_factory.data)?;
+        msg!("second price settlement amount = {}", amount);
+        ctx.accounts.auction.set_settlement_amount(amount);
+    }
+
     let seq_str = sequence.to_string();
     spl_token_transfer(TokenTransferParams {
         source: ctx.accounts.auction_token_account.to_account_info(),
@@ -51,14 +145,109 @@ pub fn handle_auction(
         amount: 1,
     })?;
 
-    transfer_lamports(
-        &ctx.accounts.auction.to_account_info(),
-        &ctx.accounts.treasury.to_account_info(),
-        ctx.accounts.auction.amount
-    )?;
+    let amount = ctx.accounts.auction.amount;
+    distribute_revenue(&ctx, bump, sequence, amount)?;
+    ctx.accounts.auction.release_escrow(amount)?;
 
     // mark auction as settled
     ctx.accounts.auction.settle();
 
     Ok(())
 }
+
+// every winner pays their own bid. the master edition stays in the auction until all prints
+// have been claimed via claim_print_edition, then goes to the top bidder via claim_master_edition.
+pub fn handle_multi_winner_auction<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleAuction<'info>>,
+    bump: u8,
+    sequence: u64,
+) -> ProgramResult {
+    let amount = ctx.accounts.auction.total_winning_amount()?;
+    distribute_revenue(&ctx, bump, sequence, amount)?;
+    ctx.accounts.auction.release_escrow(amount)?;
+
+    ctx.accounts.auction.settle();
+
+    Ok(())
+}
+
+// the settler's crank reward share comes out of the proceeds first. revenue split recipients
+// are passed as remaining accounts.
+fn distribute_revenue<'info>(
+    ctx: &Context<'_, '_, '_, 'info, SettleAuction<'info>>,
+    bump: u8,
+    sequence: u64,
+    amount: u64,
+) -> ProgramResult {
+    let reward = get_winning_bid_reward(
+        amount,
+        ctx.accounts
+            .auction_factory
+            .data
+            .crank_reward
+            .winning_bid_share,
+    )?;
+    if reward > 0 {
+        let reward_destination = match ctx.accounts.auction.token_mint {
+            Some(token_mint) => {
+                verify_token_account(
+                    &ctx.accounts.payer_bid_token_account,
+                    token_mint,
+                    ctx.accounts.payer.key(),
+                )?;
+                ctx.accounts.payer_bid_token_account.to_account_info()
+            }
+            None => ctx.accounts.payer.to_account_info(),
+        };
+
+        msg!("paying crank reward of {}", reward);
+        transfer_from_auction(ctx, reward_destination, bump, sequence, reward)?;
+    }
+
+    let revenue = amount
+        .checked_sub(reward)
+        .ok_or(ErrorCode::NumericalUnderflowError)?;
+
+    revenue_split::distribute_revenue(
+        &ctx.accounts.auction_factory,
+        &ctx.accounts.treasury,
+        ctx.remaining_accounts,
+        revenue,
+        |destination, share_amount| {
+            transfer_from_auction(ctx, destination, bump, sequence, share_amount)
+        },
+    )
+}
+
+fn transfer_from_auction<'info>(
+    ctx: &Context<'_, '_, '_, 'info, SettleAuction<'info>>,
+    destination: AccountInfo<'info>,
+    bump: u8,
+    sequence: u64,
+    amount: u64,
+) -> ProgramResult {
+    if ctx.accounts.auction.token_mint.is_some() {
+        let seq_str = sequence.to_string();
+        spl_token_transfer(TokenTransferParams {
+            source: ctx.accounts.auction_bid_token_account.to_account_info(),
+            destination,
+            authority: ctx.accounts.auction.to_account_info().clone(),
+            authority_signer_seeds: &[
+                AUX_SEED.as_bytes(),
+                ctx.accounts.auction_factory.key().as_ref(),
+                seq_str.as_bytes(),
+                &[bump],
+            ],
+            token_program: ctx.accounts.token_program.to_account_info(),
+            amount,
+        })?;
+    } else {
+        transfer_lamports(
+            &ctx.accounts.auction.to_account_info(),
+            &destination,
+            amount,
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,7 +1,6 @@
 use {
-    anchor_lang::prelude::*,
-    mpl_token_metadata::instruction::sign_metadata,
-    solana_program::program::invoke_signed
+    anchor_lang::prelude::*, mpl_token_metadata::instruction::sign_metadata,
+    solana_program::program::invoke_signed,
 };
 
 // https://github.com/metaplex-foundation/metaplex-program-library/blob/master/token-metadata/program/src/instruction.rs#L177-L180
@@ -33,4 +32,3 @@ pub fn handle<'a, 'b, 'c, 'info>(
 
     Ok(())
 }
-
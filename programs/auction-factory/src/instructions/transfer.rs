@@ -5,8 +5,6 @@ use {
     spl_token::instruction::transfer,
 };
 
-pub use spl_token::ID;
-
 #[derive(Accounts)]
 pub struct TransferLamports<'info> {
     pub from: AccountInfo<'info>,
@@ -78,7 +76,7 @@ pub fn spl_token_transfer(params: TokenTransferParams<'_, '_>) -> ProgramResult
 pub fn transfer_lamports(
     source: &AccountInfo<'_>,
     dest: &AccountInfo<'_>,
-    amount: u64
+    amount: u64,
 ) -> ProgramResult {
     let amount_after_deduction: u64 = source
         .lamports()
@@ -1,7 +1,6 @@
 use {
-    anchor_lang::prelude::*,
-    mpl_token_metadata::instruction::update_metadata_accounts_v2,
-    solana_program::program::invoke_signed
+    anchor_lang::prelude::*, mpl_token_metadata::instruction::update_metadata_accounts_v2,
+    solana_program::program::invoke_signed,
 };
 
 #[derive(Accounts)]
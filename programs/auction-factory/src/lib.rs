@@ -11,17 +11,17 @@ use {
     anchor_spl::token,
     constant::*,
     context::*,
+    error::ErrorCode,
     solana_program::msg,
     std::convert::TryInto,
     structs::{
-        auction::Auction,
-        auction_factory::{AuctionFactory, AuctionFactoryData},
+        auction::{Auction, MinimumBidAmount},
+        auction_factory::{
+            AuctionFactory, AuctionFactoryChange, AuctionFactoryData, AuctionFactoryRole,
+            AuctionType, RevenueShare, UnsoldResourcePolicy,
+        },
     },
-    util::{
-        general::get_available_lamports,
-        metadata::provide_metadata,
-    },
-    error::ErrorCode
+    util::{general::get_available_lamports, metadata::provide_metadata},
 };
 
 declare_id!("2jbfTkQ4DgbSZtb8KTq61v2ox8s1GCuGebKa1EPq3tbY");
@@ -30,9 +30,9 @@ declare_id!("2jbfTkQ4DgbSZtb8KTq61v2ox8s1GCuGebKa1EPq3tbY");
 pub mod auction_factory {
     use super::*;
 
-    /// ===================================
-    /// unrestricted instructions       ///
-    /// ===================================
+    // ===================================
+    // unrestricted instructions       ///
+    // ===================================
 
     pub fn mint_to_auction(
         ctx: Context<CreateTokenMint>,
@@ -79,12 +79,19 @@ pub mod auction_factory {
             Some(&mut ctx.accounts.current_auction),
         )?;
 
+        instructions::crank_reward::pay_fixed_reward(
+            &ctx.accounts.auction_factory.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            ctx.accounts.auction_factory.data.crank_reward.fixed_amount,
+        )?;
+
         Ok(())
     }
 
     // separate ix from create_auction because we cannot call ix this until an auction acount has been created.
     // from the client, we might be able to pack these ixns into 1 txn, assuming we will not exceed computational budge.
     // otherwise, user might have to sign 2 separate transactions when creating an auction & supplying a resource to that auction.
+    // settle_and_advance runs the whole cycle in a single instruction.
     pub fn supply_resource_to_auction(
         ctx: Context<SupplyResource>,
         auction_factory_bump: u8,
@@ -138,10 +145,13 @@ pub mod auction_factory {
             metadata_info,
         )?;
 
+        // every winner but the top bidder receives a print of the master edition
+        let max_supply = u64::from(ctx.accounts.auction.num_winners).saturating_sub(1);
         instructions::create_master_edition::handle(
             ctx.accounts
                 .into_create_master_edition_metadata_context()
                 .with_signer(&[auction_seeds]),
+            max_supply,
         )?;
 
         // update token metadata so that primary_sale_happened = true
@@ -153,13 +163,11 @@ pub mod auction_factory {
 
         // auction factory immediately signs metadata as a creator so that it doesn't have to do later
         instructions::sign_metadata::handle(
-            ctx.accounts
-                .into_sign_metadata_context()
-                .with_signer(&[&[
-                    AUX_FACTORY_SEED.as_bytes(),
-                    seed.as_bytes(),
-                    &[auction_factory_bump],
-                ]])
+            ctx.accounts.into_sign_metadata_context().with_signer(&[&[
+                AUX_FACTORY_SEED.as_bytes(),
+                seed.as_bytes(),
+                &[auction_factory_bump],
+            ]]),
         )?;
 
         ctx.accounts.auction.add_resource(ctx.accounts.mint.key());
@@ -167,13 +175,14 @@ pub mod auction_factory {
         Ok(())
     }
 
-    pub fn place_bid(
-        ctx: Context<PlaceBid>,
+    pub fn place_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceBid<'info>>,
         _auction_factory_bump: u8,
         _seed: String,
         _auction_bump: u8,
         _sequence: u64,
         amount: u64,
+        bid_refund_bump: u8,
     ) -> ProgramResult {
         verify::verify_auction_factory_is_active(&ctx.accounts.auction_factory)?;
 
@@ -183,13 +192,15 @@ pub mod auction_factory {
             ctx.accounts.auction.key(),
         )?;
 
-        verify::verify_bidder_has_sufficient_account_balance(
+        verify::verify_bidder_can_cover_bid(
+            &ctx.accounts.auction,
             ctx.accounts.bidder.to_account_info(),
+            ctx.accounts.bidder_token_account.to_account_info(),
             amount,
         )?;
 
         verify::verify_bidder_not_already_winning(
-            ctx.accounts.auction.bidder,
+            &ctx.accounts.auction,
             ctx.accounts.bidder.key(),
         )?;
 
@@ -199,20 +210,500 @@ pub mod auction_factory {
             amount,
         )?;
 
+        let leading_proxy = instructions::place_bid::get_leading_proxy(&ctx)?;
+
         instructions::place_bid::transfer_bid_amount(&ctx, amount)?;
-        instructions::place_bid::return_losing_bid_amount(&ctx)?;
+        instructions::place_bid::record_bid_refund(
+            &mut ctx.accounts.bid_refund,
+            bid_refund_bump,
+            &mut ctx.accounts.auction,
+            ctx.accounts.bidder.key(),
+            amount,
+        )?;
+        let first_bid_index = ctx.accounts.auction.bid_count;
         instructions::place_bid::handle(
+            &mut ctx.accounts.auction,
+            &ctx.accounts.auction_factory.data,
+            ctx.accounts.bidder.key(),
             amount,
+            None,
+            leading_proxy,
+        )?;
+        instructions::bid_receipt::record_bid_receipts(
+            ctx.program_id,
+            ctx.remaining_accounts,
+            ctx.accounts.bidder.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.auction,
+            first_bid_index,
+        )?;
+
+        Ok(())
+    }
+
+    // the leading bidder raises their own bid, paying only the difference from their current bid.
+    // the same increment rules apply. raising a bid replaces any active max bid.
+    pub fn raise_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceBid<'info>>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        _auction_bump: u8,
+        _sequence: u64,
+        amount: u64,
+        bid_refund_bump: u8,
+    ) -> ProgramResult {
+        verify::verify_auction_factory_is_active(&ctx.accounts.auction_factory)?;
+
+        verify::verify_auction_address_for_factory(
+            ctx.accounts.auction_factory.sequence,
+            ctx.accounts.auction_factory.key(),
+            ctx.accounts.auction.key(),
+        )?;
+
+        verify::verify_single_winner_auction(&ctx.accounts.auction)?;
+        verify::verify_bidder_is_winning(ctx.accounts.auction.bidder, ctx.accounts.bidder.key())?;
+
+        verify::verify_bid_for_auction(
+            &ctx.accounts.auction_factory,
+            &ctx.accounts.auction,
+            amount,
+        )?;
+
+        let delta = amount
+            .checked_sub(ctx.accounts.auction.amount)
+            .ok_or(ErrorCode::NumericalUnderflowError)?;
+
+        verify::verify_bidder_can_cover_bid(
+            &ctx.accounts.auction,
+            ctx.accounts.bidder.to_account_info(),
+            ctx.accounts.bidder_token_account.to_account_info(),
+            delta,
+        )?;
+
+        instructions::place_bid::transfer_bid_amount(&ctx, delta)?;
+        instructions::place_bid::record_bid_refund(
+            &mut ctx.accounts.bid_refund,
+            bid_refund_bump,
+            &mut ctx.accounts.auction,
             ctx.accounts.bidder.key(),
+            delta,
+        )?;
+        let first_bid_index = ctx.accounts.auction.bid_count;
+        instructions::place_bid::handle(
             &mut ctx.accounts.auction,
+            &ctx.accounts.auction_factory.data,
+            ctx.accounts.bidder.key(),
+            amount,
+            None,
+            None,
+        )?;
+        instructions::bid_receipt::record_bid_receipts(
+            ctx.program_id,
+            ctx.remaining_accounts,
+            ctx.accounts.bidder.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.auction,
+            first_bid_index,
         )?;
 
         Ok(())
     }
 
-    pub fn settle_auction(
-        ctx: Context<SettleAuction>,
-        bidder_account_bump: u8,
+    // escrow a max bid. the program bids the minimum amount needed to lead now, and
+    // automatically counters competing bids on the bidder's behalf up to max_amount.
+    pub fn place_max_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceBid<'info>>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        _auction_bump: u8,
+        _sequence: u64,
+        max_amount: u64,
+        bid_refund_bump: u8,
+    ) -> ProgramResult {
+        verify::verify_auction_factory_is_active(&ctx.accounts.auction_factory)?;
+
+        verify::verify_auction_address_for_factory(
+            ctx.accounts.auction_factory.sequence,
+            ctx.accounts.auction_factory.key(),
+            ctx.accounts.auction.key(),
+        )?;
+
+        verify::verify_single_winner_auction(&ctx.accounts.auction)?;
+        verify::verify_bidder_can_cover_bid(
+            &ctx.accounts.auction,
+            ctx.accounts.bidder.to_account_info(),
+            ctx.accounts.bidder_token_account.to_account_info(),
+            max_amount,
+        )?;
+
+        verify::verify_bidder_not_already_winning(
+            &ctx.accounts.auction,
+            ctx.accounts.bidder.key(),
+        )?;
+
+        // ceiling must at least cover the opening bid
+        verify::verify_bid_for_auction(
+            &ctx.accounts.auction_factory,
+            &ctx.accounts.auction,
+            max_amount,
+        )?;
+
+        let opening_amount = verify::get_minimum_bid_amount(
+            ctx.accounts.auction.amount,
+            &ctx.accounts.auction_factory.data,
+        )?;
+        let leading_proxy = instructions::place_bid::get_leading_proxy(&ctx)?;
+
+        instructions::place_bid::transfer_bid_amount(&ctx, max_amount)?;
+        instructions::place_bid::record_bid_refund(
+            &mut ctx.accounts.bid_refund,
+            bid_refund_bump,
+            &mut ctx.accounts.auction,
+            ctx.accounts.bidder.key(),
+            max_amount,
+        )?;
+        ctx.accounts.bid_refund.set_max_amount(max_amount);
+
+        let first_bid_index = ctx.accounts.auction.bid_count;
+        instructions::place_bid::handle(
+            &mut ctx.accounts.auction,
+            &ctx.accounts.auction_factory.data,
+            ctx.accounts.bidder.key(),
+            opening_amount,
+            Some(max_amount),
+            leading_proxy,
+        )?;
+        instructions::bid_receipt::record_bid_receipts(
+            ctx.program_id,
+            ctx.remaining_accounts,
+            ctx.accounts.bidder.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.auction,
+            first_bid_index,
+        )?;
+
+        Ok(())
+    }
+
+    // dutch auctions only. buys the resource at the current price, as long as it does not exceed
+    // max_price, and settles the auction in the same instruction.
+    pub fn buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, Buy<'info>>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        auction_bump: u8,
+        sequence: u64,
+        max_price: u64,
+    ) -> ProgramResult {
+        verify::verify_auction_factory_is_active(&ctx.accounts.auction_factory)?;
+
+        verify::verify_auction_address_for_factory(
+            ctx.accounts.auction_factory.sequence,
+            ctx.accounts.auction_factory.key(),
+            ctx.accounts.auction.key(),
+        )?;
+
+        verify::verify_auction_type(&ctx.accounts.auction, AuctionType::Dutch)?;
+        verify::verify_auction_is_live(&ctx.accounts.auction)?;
+        verify::verify_auction_has_resource(&ctx.accounts.auction)?;
+        verify::verify_treasury(&ctx.accounts.auction_factory, ctx.accounts.treasury.key())?;
+        let resource = ctx.accounts.auction.resource.unwrap();
+
+        let price = instructions::buy::get_current_price(&ctx.accounts.auction)?;
+        if price > max_price {
+            return Err(ErrorCode::PriceExceedsMaxPrice.into());
+        }
+
+        verify::verify_bidder_can_cover_bid(
+            &ctx.accounts.auction,
+            ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.buyer_payment_token_account.to_account_info(),
+            price,
+        )?;
+
+        msg!(
+            "buying auction [{}] at price = {}",
+            ctx.accounts.auction.key().to_string(),
+            price
+        );
+        ctx.accounts
+            .auction
+            .record_bid(price, ctx.accounts.buyer.key())?;
+
+        instructions::create_token_account::handle(
+            ctx.accounts.into_create_buyer_token_account_context(),
+        )?;
+
+        verify::verify_associated_token_account(
+            &ctx.accounts.buyer_token_account,
+            ctx.accounts.buyer.key(),
+            resource,
+        )?;
+
+        instructions::buy::handle(ctx, auction_bump, sequence, price)?;
+
+        Ok(())
+    }
+
+    // sealed bid auctions only. escrows a deposit alongside a commitment to a hidden bid,
+    // sha256(amount as little endian bytes || salt || bidder). the deposit must cover the bid.
+    #[allow(clippy::too_many_arguments)]
+    pub fn commit_sealed_bid(
+        ctx: Context<PlaceBid>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        _auction_bump: u8,
+        _sequence: u64,
+        deposit: u64,
+        bid_refund_bump: u8,
+        commitment: [u8; 32],
+    ) -> ProgramResult {
+        verify::verify_auction_factory_is_active(&ctx.accounts.auction_factory)?;
+
+        verify::verify_auction_address_for_factory(
+            ctx.accounts.auction_factory.sequence,
+            ctx.accounts.auction_factory.key(),
+            ctx.accounts.auction.key(),
+        )?;
+
+        verify::verify_auction_type(&ctx.accounts.auction, AuctionType::SealedBid)?;
+        verify::verify_auction_is_live(&ctx.accounts.auction)?;
+
+        if deposit == 0 || deposit < ctx.accounts.auction_factory.data.min_reserve_price {
+            return Err(ErrorCode::InvalidBidAmount.into());
+        }
+
+        if ctx.accounts.bid_refund.has_commitment() {
+            return Err(ErrorCode::SealedBidAlreadyCommitted.into());
+        }
+
+        verify::verify_bidder_can_cover_bid(
+            &ctx.accounts.auction,
+            ctx.accounts.bidder.to_account_info(),
+            ctx.accounts.bidder_token_account.to_account_info(),
+            deposit,
+        )?;
+
+        instructions::place_bid::transfer_bid_amount(&ctx, deposit)?;
+        instructions::place_bid::record_bid_refund(
+            &mut ctx.accounts.bid_refund,
+            bid_refund_bump,
+            &mut ctx.accounts.auction,
+            ctx.accounts.bidder.key(),
+            deposit,
+        )?;
+        ctx.accounts.bid_refund.commit(commitment);
+
+        Ok(())
+    }
+
+    // sealed bid auctions only. the highest revealed bid leads; ties go to the first reveal.
+    #[allow(clippy::too_many_arguments)]
+    pub fn reveal_sealed_bid(
+        ctx: Context<RevealSealedBid>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        _auction_bump: u8,
+        _sequence: u64,
+        _bid_refund_bump: u8,
+        amount: u64,
+        salt: [u8; 32],
+    ) -> ProgramResult {
+        verify::verify_auction_address_for_factory(
+            ctx.accounts.auction.sequence,
+            ctx.accounts.auction_factory.key(),
+            ctx.accounts.auction.key(),
+        )?;
+
+        verify::verify_sealed_bid_reveal_phase(&ctx.accounts.auction)?;
+
+        if !ctx.accounts.bid_refund.has_commitment() {
+            return Err(ErrorCode::SealedBidNotCommitted.into());
+        }
+
+        if ctx.accounts.bid_refund.revealed {
+            return Err(ErrorCode::SealedBidAlreadyRevealed.into());
+        }
+
+        verify::verify_sealed_bid_commitment(
+            ctx.accounts.bid_refund.commitment,
+            amount,
+            salt,
+            ctx.accounts.bidder.key(),
+        )?;
+
+        if amount > ctx.accounts.bid_refund.amount {
+            return Err(ErrorCode::SealedBidExceedsDeposit.into());
+        }
+
+        ctx.accounts.bid_refund.reveal();
+
+        // bids below the reserve are revealed, so that the deposit is not penalized, but cannot win
+        let meets_reserve =
+            amount > 0 && amount >= ctx.accounts.auction_factory.data.min_reserve_price;
+        if meets_reserve && amount > ctx.accounts.auction.amount {
+            ctx.accounts
+                .auction
+                .record_bid(amount, ctx.accounts.bidder.key())?;
+        } else if meets_reserve {
+            ctx.accounts.auction.record_losing_bid_amount(amount);
+        }
+
+        Ok(())
+    }
+
+    // outbid bidders, and losing bidders after settlement, withdraw their escrowed funds here.
+    // the refund account is closed to the bidder once the auction is over and it is emptied.
+    pub fn claim_refund<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimRefund<'info>>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        auction_bump: u8,
+        sequence: u64,
+        _bid_refund_bump: u8,
+    ) -> ProgramResult {
+        verify::verify_auction_address_for_factory(
+            ctx.accounts.auction.sequence,
+            ctx.accounts.auction_factory.key(),
+            ctx.accounts.auction.key(),
+        )?;
+
+        instructions::claim_refund::handle(ctx, auction_bump, sequence)?;
+
+        Ok(())
+    }
+
+    // emergency exit for auctions that were never settled, e.g. because the resource was never
+    // supplied. once the settlement grace period has passed, any bidder can void the auction and
+    // withdraw their full escrow, the leading bid included. other bidders follow via claim_refund.
+    pub fn reclaim_stuck_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimRefund<'info>>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        auction_bump: u8,
+        sequence: u64,
+        _bid_refund_bump: u8,
+    ) -> ProgramResult {
+        verify::verify_auction_address_for_factory(
+            ctx.accounts.auction.sequence,
+            ctx.accounts.auction_factory.key(),
+            ctx.accounts.auction.key(),
+        )?;
+
+        if !ctx.accounts.auction.is_void() {
+            verify::verify_auction_is_stuck(
+                &ctx.accounts.auction,
+                &ctx.accounts.auction_factory.data,
+            )?;
+
+            msg!(
+                "voiding auction: {}",
+                ctx.accounts.auction.key().to_string()
+            );
+            ctx.accounts.auction.void();
+        }
+
+        instructions::claim_refund::handle(ctx, auction_bump, sequence)?;
+
+        Ok(())
+    }
+
+    // permissionless; returns an emptied bid refund account's rent to its bidder once the auction
+    // is settled or void, so that bidders cannot keep the auction from being closed.
+    pub fn close_bid_refund(
+        ctx: Context<CloseBidRefund>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        _auction_bump: u8,
+        _sequence: u64,
+    ) -> ProgramResult {
+        if !ctx.accounts.auction.is_void() {
+            verify::verify_auction_is_settled(&ctx.accounts.auction)?;
+        }
+
+        if ctx
+            .accounts
+            .bid_refund
+            .claimable_amount(&ctx.accounts.auction)?
+            > 0
+        {
+            return Err(ErrorCode::BidRefundNotEmpty.into());
+        }
+
+        ctx.accounts.auction.close_bid_refund()?;
+
+        Ok(())
+    }
+
+    // view: emits the smallest bid the auction will currently accept. simulate to read it.
+    pub fn get_minimum_bid(
+        ctx: Context<ReadAuction>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        _auction_bump: u8,
+        _sequence: u64,
+    ) -> ProgramResult {
+        let amount = verify::get_minimum_bid_amount(
+            ctx.accounts.auction.amount_to_beat(),
+            &ctx.accounts.auction_factory.data,
+        )?;
+
+        msg!("minimum bid: {}", amount);
+        emit!(MinimumBidAmount {
+            auction: ctx.accounts.auction.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+
+    // permissionless; once bidding is over, picks the slot whose hash will close a candle auction.
+    // the auction can be settled once that slot has passed.
+    pub fn request_candle_close(
+        ctx: Context<RequestCandleClose>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        _auction_bump: u8,
+        _sequence: u64,
+    ) -> ProgramResult {
+        verify::verify_candle_close_can_be_requested(
+            &ctx.accounts.auction,
+            &ctx.accounts.slot_hashes,
+        )?;
+
+        let current_slot = Clock::get()?.slot;
+        ctx.accounts.auction.request_candle_close(current_slot)?;
+        msg!(
+            "candle auction closes at the hash of slot {}",
+            ctx.accounts.auction.candle_close_slot
+        );
+
+        Ok(())
+    }
+
+    // permissionless; returns receipt rent to whoever paid for it once the auction is settled or void
+    pub fn close_bid_receipt(
+        ctx: Context<CloseBidReceipt>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        _auction_bump: u8,
+        _sequence: u64,
+    ) -> ProgramResult {
+        // auctions are only closed once settled or void
+        if ctx.accounts.auction.data_is_empty() {
+            return Ok(());
+        }
+
+        let auction: Account<Auction> = Account::try_from(&ctx.accounts.auction)?;
+        if !auction.is_void() {
+            verify::verify_auction_is_settled(&auction)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn settle_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleAuction<'info>>,
         _auction_factory_bump: u8,
         _seed: String,
         auction_bump: u8,
@@ -226,12 +717,42 @@ pub mod auction_factory {
         )?;
         verify::verify_auction_can_be_settled(&ctx.accounts.auction)?;
         verify::verify_auction_has_resource(&ctx.accounts.auction)?;
+        let resource = ctx.accounts.auction.resource.unwrap();
+
+        instructions::crank_reward::pay_fixed_reward(
+            &ctx.accounts.auction_factory.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            ctx.accounts.auction_factory.data.crank_reward.fixed_amount,
+        )?;
+
+        if ctx.accounts.auction.auction_type == AuctionType::Candle {
+            instructions::settle_auction::close_candle_auction(
+                &mut ctx.accounts.auction,
+                &ctx.accounts.slot_hashes,
+            )?;
+        }
 
         if ctx.accounts.auction.amount == 0 {
             msg!(
                 "settling auction with no bids: {}",
                 ctx.accounts.auction.key().to_string()
             );
+            if ctx.accounts.auction.unsold_resource_policy == UnsoldResourcePolicy::Transfer {
+                verify::verify_unsold_resource_recipient(
+                    &ctx.accounts.auction_factory,
+                    ctx.accounts.bidder.key(),
+                )?;
+                instructions::create_token_account::handle(
+                    ctx.accounts.into_create_bidder_token_account_context(),
+                )?;
+
+                verify::verify_associated_token_account(
+                    &ctx.accounts.bidder_token_account,
+                    ctx.accounts.bidder.key(),
+                    resource,
+                )?;
+            }
+
             instructions::settle_auction::handle_empty_auction(ctx, auction_bump, sequence)?;
         } else {
             msg!(
@@ -240,15 +761,178 @@ pub mod auction_factory {
                 ctx.accounts.auction.amount
             );
             verify::verify_treasury(&ctx.accounts.auction_factory, ctx.accounts.treasury.key())?;
-            verify::verify_bidder_token_account(
-                ctx.accounts.bidder_token_account.to_account_info(),
-                &ctx.accounts.auction,
-                bidder_account_bump,
-            )?;
+            if let Some(token_mint) = ctx.accounts.auction.token_mint {
+                verify::verify_treasury_for_bid_mint(&ctx.accounts.treasury, Some(token_mint))?;
+                verify::verify_auction_bid_token_account(
+                    &ctx.accounts.auction_bid_token_account,
+                    &ctx.accounts.auction,
+                    token_mint,
+                )?;
+            }
+
+            if ctx.accounts.auction.is_multi_winner() {
+                instructions::settle_auction::handle_multi_winner_auction(
+                    ctx,
+                    auction_bump,
+                    sequence,
+                )?;
+            } else {
+                // anyone can settle for a winner that has no token account for the resource yet
+                verify::verify_bidder_is_winning(
+                    ctx.accounts.auction.bidder,
+                    ctx.accounts.bidder.key(),
+                )?;
+                instructions::create_token_account::handle(
+                    ctx.accounts.into_create_bidder_token_account_context(),
+                )?;
+
+                verify::verify_associated_token_account(
+                    &ctx.accounts.bidder_token_account,
+                    ctx.accounts.bidder.key(),
+                    resource,
+                )?;
+
+                instructions::settle_auction::handle_auction(ctx, auction_bump, sequence)?;
+            }
+        }
+
+        Ok(())
+    }
 
-            instructions::settle_auction::handle_auction(ctx, auction_bump, sequence)?;
+    // settles the current auction, then creates the next auction, mints its token & supplies it as
+    // the resource. the next auction's mint & token account are created ahead of time, e.g. earlier
+    // in the same transaction. if any step fails the whole cycle is rolled back, so an auction is
+    // never live without a resource.
+    #[allow(clippy::too_many_arguments)]
+    pub fn settle_and_advance<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleAndAdvance<'info>>,
+        auction_factory_bump: u8,
+        seed: String,
+        current_auction_bump: u8,
+        next_auction_bump: u8,
+        config_bump: u8,
+        config_seed: String,
+        current_seq: u64,
+        next_seq: u64,
+    ) -> ProgramResult {
+        // the settle & supply handlers run on copies of the accounts. write back their changes,
+        // then reload our own copies so that they are not overwritten on exit.
+        let mut settle_accounts = ctx.accounts.into_settle_auction_accounts();
+        settle_auction(
+            Context::new(ctx.program_id, &mut settle_accounts, ctx.remaining_accounts),
+            auction_factory_bump,
+            seed.clone(),
+            current_auction_bump,
+            current_seq,
+        )?;
+        settle_accounts.exit(ctx.program_id)?;
+        ctx.accounts.current_auction.reload()?;
+
+        create_auction_helper(
+            &mut ctx.accounts.auction_factory,
+            next_auction_bump,
+            &mut ctx.accounts.next_auction,
+            Some(&mut ctx.accounts.current_auction),
+        )?;
+
+        instructions::crank_reward::pay_fixed_reward(
+            &ctx.accounts.auction_factory.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            ctx.accounts.auction_factory.data.crank_reward.fixed_amount,
+        )?;
+
+        // the unsold resource was carried into the next auction, the next mint goes unused
+        if ctx.accounts.next_auction.resource_rolled_over {
+            return Ok(());
         }
 
+        let next_seq_str = next_seq.to_string();
+        token::mint_to(
+            ctx.accounts.into_mint_token_context().with_signer(&[&[
+                AUX_SEED.as_bytes(),
+                ctx.accounts.auction_factory.key().as_ref(),
+                next_seq_str.as_bytes(),
+                &[next_auction_bump],
+            ]]),
+            1,
+        )?;
+
+        let mut supply_accounts = ctx.accounts.into_supply_resource_accounts();
+        supply_resource_to_auction(
+            Context::new(ctx.program_id, &mut supply_accounts, &[]),
+            auction_factory_bump,
+            seed,
+            next_auction_bump,
+            config_bump,
+            config_seed,
+            next_seq,
+        )?;
+        supply_accounts.exit(ctx.program_id)?;
+        ctx.accounts.next_auction.reload()?;
+        ctx.accounts.config.reload()?;
+
+        Ok(())
+    }
+
+    // multi-winner auctions only. permissionless; prints the edition of the winner at winner_index
+    // into a new mint, minted ahead of time with its single token held by the winner.
+    pub fn claim_print_edition(
+        ctx: Context<ClaimPrintEdition>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        auction_bump: u8,
+        sequence: u64,
+        winner_index: u8,
+    ) -> ProgramResult {
+        verify::verify_auction_address_for_factory(
+            ctx.accounts.auction.sequence,
+            ctx.accounts.auction_factory.key(),
+            ctx.accounts.auction.key(),
+        )?;
+        verify::verify_multi_winner_auction(&ctx.accounts.auction)?;
+        verify::verify_auction_is_settled(&ctx.accounts.auction)?;
+
+        instructions::claim_edition::handle_print_edition(
+            ctx,
+            auction_bump,
+            sequence,
+            winner_index,
+        )?;
+
+        Ok(())
+    }
+
+    // multi-winner auctions only. permissionless; sends the master edition to the top bidder once
+    // every print has been distributed. their token account is created if missing.
+    pub fn claim_master_edition(
+        ctx: Context<ClaimMasterEdition>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        auction_bump: u8,
+        sequence: u64,
+    ) -> ProgramResult {
+        verify::verify_auction_address_for_factory(
+            ctx.accounts.auction.sequence,
+            ctx.accounts.auction_factory.key(),
+            ctx.accounts.auction.key(),
+        )?;
+        verify::verify_multi_winner_auction(&ctx.accounts.auction)?;
+        verify::verify_auction_is_settled(&ctx.accounts.auction)?;
+        verify::verify_auction_has_resource(&ctx.accounts.auction)?;
+        let resource = ctx.accounts.auction.resource.unwrap();
+
+        verify::verify_bidder_is_winning(ctx.accounts.auction.bidder, ctx.accounts.bidder.key())?;
+        instructions::create_token_account::handle(
+            ctx.accounts.into_create_bidder_token_account_context(),
+        )?;
+        verify::verify_associated_token_account(
+            &ctx.accounts.bidder_token_account,
+            ctx.accounts.bidder.key(),
+            resource,
+        )?;
+
+        instructions::claim_edition::handle_master_edition(ctx, auction_bump, sequence)?;
+
         Ok(())
     }
 
@@ -271,12 +955,50 @@ pub mod auction_factory {
                 ]]),
         )?;
 
+        if ctx.accounts.auction.resource == Some(ctx.accounts.auction_token_account.mint) {
+            ctx.accounts.auction.close_token_account();
+        }
+
         Ok(())
     }
 
-    /// ===================================
-    /// admin instructions              ///
-    /// ===================================
+    // permissionless; archives a settled auction as an AuctionSummary and closes it. any surplus
+    // funds sent to the auction are swept to the treasury. the payer is reimbursed the summary's
+    // rent out of the auction's rent, the remainder goes to the treasury.
+    pub fn close_auction(
+        ctx: Context<CloseAuction>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        auction_bump: u8,
+        sequence: u64,
+        auction_summary_bump: u8,
+    ) -> ProgramResult {
+        verify::verify_auction_can_be_closed(&ctx.accounts.auction_factory, &ctx.accounts.auction)?;
+        verify::verify_auction_escrow_is_empty(&ctx.accounts.auction)?;
+        verify::verify_auction_bid_refunds_are_closed(&ctx.accounts.auction)?;
+        instructions::close_auction::sweep_surplus_to_treasury(&ctx, auction_bump, sequence)?;
+
+        let auction_factory_key = ctx.accounts.auction_factory.key();
+        ctx.accounts.auction_summary.init(
+            auction_summary_bump,
+            auction_factory_key,
+            &ctx.accounts.auction,
+        );
+
+        instructions::transfer::transfer_lamports(
+            &ctx.accounts.auction.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            ctx.accounts.auction_summary.to_account_info().lamports(),
+        )?;
+
+        msg!("closed auction: {}", ctx.accounts.auction.key().to_string());
+
+        Ok(())
+    }
+
+    // ===================================
+    // admin instructions              ///
+    // ===================================
 
     pub fn initialize_auction_factory(
         ctx: Context<InitializeAuctionFactory>,
@@ -284,9 +1006,13 @@ pub mod auction_factory {
         seed: String,
         _config_bump: u8,
         _config_seed: String,
+        bid_mint: Option<Pubkey>,
         data: AuctionFactoryData,
     ) -> ProgramResult {
         verify::verify_auction_factory_seed(&seed)?;
+        verify::verify_auction_factory_data(&data)?;
+        verify::verify_unsold_resource_recipient_for_bid_mint(&data, bid_mint)?;
+        verify::verify_treasury_for_bid_mint(&ctx.accounts.treasury, bid_mint)?;
 
         ctx.accounts.auction_factory.init(
             bump,
@@ -294,6 +1020,7 @@ pub mod auction_factory {
             ctx.accounts.payer.key(),
             ctx.accounts.treasury.key(),
             ctx.accounts.config.key(),
+            bid_mint,
             data,
         );
 
@@ -305,9 +1032,10 @@ pub mod auction_factory {
         _bump: u8,
         _seed: String,
     ) -> ProgramResult {
-        verify::verify_auction_factory_authority(
+        verify::verify_auction_factory_role(
+            &ctx.accounts.auction_factory,
+            AuctionFactoryRole::Pauser,
             ctx.accounts.payer.key(),
-            ctx.accounts.auction_factory.authority,
         )?;
 
         if ctx.accounts.auction_factory.is_active {
@@ -321,25 +1049,131 @@ pub mod auction_factory {
         Ok(())
     }
 
+    // takedown of an auction that is still taking bids, or was voided. the resource is burned, or
+    // returned to a token account owned by the authority, and every bid becomes refundable.
+    pub fn cancel_auction(
+        ctx: Context<CancelAuction>,
+        _auction_factory_bump: u8,
+        _seed: String,
+        auction_bump: u8,
+        sequence: u64,
+        burn_resource: bool,
+    ) -> ProgramResult {
+        verify::verify_auction_factory_authority(
+            ctx.accounts.payer.key(),
+            ctx.accounts.auction_factory.authority,
+        )?;
+        verify::verify_auction_address_for_factory(
+            ctx.accounts.auction.sequence,
+            ctx.accounts.auction_factory.key(),
+            ctx.accounts.auction.key(),
+        )?;
+        verify::verify_auction_can_be_cancelled(&ctx.accounts.auction)?;
+
+        msg!(
+            "cancelling auction: {}",
+            ctx.accounts.auction.key().to_string()
+        );
+        instructions::cancel_auction::handle(ctx, auction_bump, sequence, burn_resource)?;
+
+        Ok(())
+    }
+
+    // queued for the auction factory's change_delay, then applied via execute_pending_change
     pub fn modify_auction_factory_data(
         ctx: Context<ModifyAuctionFactory>,
         _bump: u8,
         _seed: String,
         data: AuctionFactoryData,
+    ) -> ProgramResult {
+        verify::verify_auction_factory_role(
+            &ctx.accounts.auction_factory,
+            AuctionFactoryRole::ParameterManager,
+            ctx.accounts.payer.key(),
+        )?;
+        verify::verify_auction_factory_data(&data)?;
+        verify::verify_unsold_resource_recipient_for_bid_mint(
+            &data,
+            ctx.accounts.auction_factory.bid_mint,
+        )?;
+
+        let execute_after = ctx.accounts.auction_factory.queue_data_change(data)?;
+        msg!(
+            "auction factory data change executable after {}",
+            execute_after
+        );
+
+        Ok(())
+    }
+
+    // settled auction revenue is split across recipients by basis points. an empty split sends
+    // all revenue to the treasury. like treasury changes, the new split is queued. recipient
+    // accounts are passed as remaining accounts, in revenue split order.
+    pub fn update_revenue_split(
+        ctx: Context<ModifyAuctionFactory>,
+        _bump: u8,
+        _seed: String,
+        revenue_split: Vec<RevenueShare>,
+    ) -> ProgramResult {
+        verify::verify_auction_factory_role(
+            &ctx.accounts.auction_factory,
+            AuctionFactoryRole::Treasurer,
+            ctx.accounts.payer.key(),
+        )?;
+        verify::verify_revenue_split(&revenue_split)?;
+        verify::verify_revenue_recipients(
+            &revenue_split,
+            ctx.remaining_accounts,
+            ctx.accounts.auction_factory.bid_mint,
+        )?;
+
+        let execute_after = ctx
+            .accounts
+            .auction_factory
+            .queue_revenue_split_change(revenue_split)?;
+        msg!("revenue split change executable after {}", execute_after);
+
+        Ok(())
+    }
+
+    // authority transfers take two steps, so that a mistyped authority cannot lock the auction
+    // factory. the proposed authority only takes over once it signs accept_authority. proposing
+    // again replaces any pending authority.
+    pub fn propose_authority(
+        ctx: Context<UpdateAuctionFactoryAuthority>,
+        _bump: u8,
+        _seed: String,
     ) -> ProgramResult {
         verify::verify_auction_factory_authority(
             ctx.accounts.payer.key(),
             ctx.accounts.auction_factory.authority,
         )?;
 
-        ctx.accounts.auction_factory.update_data(data);
+        msg!(
+            "proposing authority: {}",
+            ctx.accounts.new_authority.key().to_string()
+        );
+        ctx.accounts
+            .auction_factory
+            .propose_authority(*ctx.accounts.new_authority.key);
 
         Ok(())
     }
 
-    // note: not tested with anchor tests
-    pub fn update_authority(
-        ctx: Context<UpdateAuctionFactoryAuthority>,
+    pub fn accept_authority(
+        ctx: Context<AcceptAuctionFactoryAuthority>,
+        _bump: u8,
+        _seed: String,
+    ) -> ProgramResult {
+        verify::verify_pending_authority(&ctx.accounts.auction_factory, ctx.accounts.payer.key())?;
+
+        ctx.accounts.auction_factory.accept_authority();
+
+        Ok(())
+    }
+
+    pub fn cancel_authority_transfer(
+        ctx: Context<ModifyAuctionFactory>,
         _bump: u8,
         _seed: String,
     ) -> ProgramResult {
@@ -348,22 +1182,118 @@ pub mod auction_factory {
             ctx.accounts.auction_factory.authority,
         )?;
 
-        ctx.accounts.auction_factory.update_authority(*ctx.accounts.new_authority.key);
+        if ctx.accounts.auction_factory.pending_authority.is_none() {
+            return Err(ErrorCode::NoPendingAuthority.into());
+        }
+
+        ctx.accounts.auction_factory.cancel_authority_transfer();
 
         Ok(())
     }
 
-    pub fn update_treasury(
-        ctx: Context<UpdateAuctionFactoryTreasury>,
+    // the authority delegates pausing, config, parameter & treasury management to other keys,
+    // one holder per role. granting a role replaces its current holder.
+    pub fn grant_role(
+        ctx: Context<ModifyAuctionFactory>,
+        _bump: u8,
+        _seed: String,
+        role: AuctionFactoryRole,
+        holder: Pubkey,
+    ) -> ProgramResult {
+        verify::verify_auction_factory_authority(
+            ctx.accounts.payer.key(),
+            ctx.accounts.auction_factory.authority,
+        )?;
+
+        msg!("granting {:?} role to {}", role, holder.to_string());
+        ctx.accounts.auction_factory.grant_role(role, holder);
+
+        Ok(())
+    }
+
+    pub fn revoke_role(
+        ctx: Context<ModifyAuctionFactory>,
         _bump: u8,
         _seed: String,
+        role: AuctionFactoryRole,
     ) -> ProgramResult {
         verify::verify_auction_factory_authority(
             ctx.accounts.payer.key(),
             ctx.accounts.auction_factory.authority,
         )?;
 
-        ctx.accounts.auction_factory.update_treasury(*ctx.accounts.treasury.key);
+        msg!("revoking {:?} role", role);
+        ctx.accounts.auction_factory.revoke_role(role);
+
+        Ok(())
+    }
+
+    // queued for the auction factory's change_delay, then applied via execute_pending_change
+    pub fn update_treasury(
+        ctx: Context<UpdateAuctionFactoryTreasury>,
+        _bump: u8,
+        _seed: String,
+    ) -> ProgramResult {
+        verify::verify_auction_factory_role(
+            &ctx.accounts.auction_factory,
+            AuctionFactoryRole::Treasurer,
+            ctx.accounts.payer.key(),
+        )?;
+
+        verify::verify_treasury_for_bid_mint(
+            &ctx.accounts.treasury,
+            ctx.accounts.auction_factory.bid_mint,
+        )?;
+
+        let execute_after = ctx
+            .accounts
+            .auction_factory
+            .queue_treasury_change(*ctx.accounts.treasury.key)?;
+        msg!("treasury change executable after {}", execute_after);
+
+        Ok(())
+    }
+
+    // pending changes can be cancelled by whoever can queue them, at any time before execution
+    pub fn cancel_pending_change(
+        ctx: Context<ModifyAuctionFactory>,
+        _bump: u8,
+        _seed: String,
+        change: AuctionFactoryChange,
+    ) -> ProgramResult {
+        let role = match change {
+            AuctionFactoryChange::Data => AuctionFactoryRole::ParameterManager,
+            AuctionFactoryChange::Treasury | AuctionFactoryChange::RevenueSplit => {
+                AuctionFactoryRole::Treasurer
+            }
+        };
+        verify::verify_auction_factory_role(
+            &ctx.accounts.auction_factory,
+            role,
+            ctx.accounts.payer.key(),
+        )?;
+
+        if !ctx.accounts.auction_factory.has_pending_change(change) {
+            return Err(ErrorCode::NoPendingChange.into());
+        }
+
+        msg!("cancelling pending {:?} change", change);
+        ctx.accounts.auction_factory.cancel_pending_change(change);
+
+        Ok(())
+    }
+
+    // permissionless; applies a pending change once its delay has passed
+    pub fn execute_pending_change(
+        ctx: Context<ExecutePendingChange>,
+        _bump: u8,
+        _seed: String,
+        change: AuctionFactoryChange,
+    ) -> ProgramResult {
+        verify::verify_pending_change_can_be_executed(&ctx.accounts.auction_factory, change)?;
+
+        msg!("executing pending {:?} change", change);
+        ctx.accounts.auction_factory.execute_pending_change(change);
 
         Ok(())
     }
@@ -375,9 +1305,10 @@ pub mod auction_factory {
         _bump: u8,
         _seed: String,
     ) -> ProgramResult {
-        verify::verify_auction_factory_authority(
+        verify::verify_auction_factory_role(
+            &ctx.accounts.auction_factory,
+            AuctionFactoryRole::Treasurer,
             ctx.accounts.payer.key(),
-            ctx.accounts.auction_factory.authority,
         )?;
 
         let auction_factory_account_info = &ctx.accounts.auction_factory.to_account_info();
@@ -386,15 +1317,15 @@ pub mod auction_factory {
         instructions::transfer::transfer_lamports(
             auction_factory_account_info,
             &ctx.accounts.treasury.to_account_info(),
-            amount_to_transfer
+            amount_to_transfer,
         )?;
 
         Ok(())
     }
 
-    /// ===================================
-    /// conifg instructions             ///
-    /// ===================================
+    // ===================================
+    // conifg instructions             ///
+    // ===================================
 
     pub fn initialize_config(
         ctx: Context<InitializeConfig>,
@@ -420,6 +1351,12 @@ pub mod auction_factory {
         _config_seed: String,
         config_data: Vec<String>,
     ) -> ProgramResult {
+        verify::verify_auction_factory_role(
+            &ctx.accounts.auction_factory,
+            AuctionFactoryRole::ConfigManager,
+            ctx.accounts.payer.key(),
+        )?;
+
         ctx.accounts
             .config
             .add_data(ctx.accounts.auction_factory.sequence as usize, config_data)?;
@@ -428,10 +1365,10 @@ pub mod auction_factory {
     }
 }
 
-/// ====================================================================
-/// ixn helper function to  until i  can figure out how to combine   ///
-/// create 0...n auctions in the fn                                  ///
-/// ====================================================================
+// ====================================================================
+// ixn helper function to  until i  can figure out how to combine   ///
+// create 0...n auctions in the fn                                  ///
+// ====================================================================
 
 pub fn create_auction_helper(
     auction_factory: &mut Account<AuctionFactory>,
@@ -439,9 +1376,10 @@ pub fn create_auction_helper(
     next_auction: &mut Account<Auction>,
     current_auction: Option<&mut Account<Auction>>,
 ) -> ProgramResult {
-    verify::verify_auction_factory_is_active(&auction_factory)?;
+    verify::verify_auction_factory_is_active(auction_factory)?;
 
-    let next_sequence = auction_factory.sequence
+    let next_sequence = auction_factory
+        .sequence
         .checked_add(1)
         .ok_or(ErrorCode::NumericalOverflowError)?;
     verify::verify_auction_address_for_factory(
@@ -450,14 +1388,30 @@ pub fn create_auction_helper(
         next_auction.key(),
     )?;
 
-    if let Some(curr_auction) = current_auction {
+    let (previous_end_time, rolled_over_resource) = if let Some(curr_auction) = current_auction {
         // ensure settled auction before creating a new auction, if we are past the first auction
-        verify::verify_current_auction_is_over(&curr_auction)?;
+        verify::verify_current_auction_is_over(curr_auction)?;
+        (
+            Some(curr_auction.finalized_end_time),
+            curr_auction.get_rolled_over_resource(),
+        )
     } else {
-        verify::verify_auction_factory_for_first_auction(&auction_factory)?;
-    }
+        verify::verify_auction_factory_for_first_auction(auction_factory)?;
+        (None, None)
+    };
+
+    instructions::create_auction::handle(
+        next_auction_bump,
+        next_auction,
+        auction_factory,
+        previous_end_time,
+    )?;
 
-    instructions::create_auction::handle(next_auction_bump, next_auction, auction_factory)?;
+    // settlement already handed the unsold resource's token account over to this auction
+    if let Some(resource) = rolled_over_resource {
+        msg!("rolling over resource: {}", resource.to_string());
+        next_auction.roll_over_resource(resource);
+    }
 
     Ok(())
 }
@@ -1,9 +1,18 @@
 use {
     crate::{
-        constant::MAX_BIDS_TO_RECORD, structs::auction_factory::AuctionFactoryData,
-        util::general::get_current_timestamp, util::vec::update_vec,
+        constant::{
+            CANDLE_CLOSE_SLOT_DELAY, MAX_BIDS_TO_RECORD, MAX_CANDLE_CHECKPOINTS,
+            MAX_WINNERS_PER_AUCTION,
+        },
+        error::ErrorCode,
+        structs::auction_factory::{
+            AuctionFactoryData, AuctionType, DutchAuctionData, UnsoldResourcePolicy,
+        },
+        util::general::get_current_timestamp,
+        util::vec::update_vec,
     },
     anchor_lang::prelude::*,
+    solana_program::msg,
 };
 
 #[repr(C)]
@@ -17,12 +26,21 @@ pub struct Bid {
     pub amount: u64,
 }
 
+// emitted by the get_minimum_bid view
+#[event]
+pub struct MinimumBidAmount {
+    pub auction: Pubkey,
+    pub amount: u64,
+}
+
 #[account]
 #[derive(Default)]
 pub struct Auction {
     pub bump: u8,
     // index of auction managed by the auction factory, zero indexed
     pub sequence: u64,
+    // type of auction, copied from the auction factory when the auction is created
+    pub auction_type: AuctionType,
     // authority with permission to modify this auction
     pub authority: Pubkey,
     // epoch time that the auction started
@@ -31,26 +49,79 @@ pub struct Auction {
     pub end_time: u64,
     // epoch time that the auction actually ended; affected by auction extension from competing bids
     pub finalized_end_time: u64,
+    // dutch auctions only. price schedule, copied from the auction factory when the auction is created
+    pub dutch_auction: DutchAuctionData,
+    // sealed bid auctions only. bids are committed until end_time, then revealed until reveal_end_time.
+    pub reveal_end_time: u64,
+    // sealed bid auctions only. basis points withheld from deposits that are never revealed,
+    // copied from the auction factory when the auction is created
+    pub non_reveal_penalty: u64,
+    // candle auctions only. the auction closes at a random time between candle_start_time and end_time.
+    pub candle_start_time: u64,
+    // candle auctions only. the ending period is split into MAX_CANDLE_CHECKPOINTS equal windows,
+    // and checkpoint i is the leading bid at the end of window i. recorded up to the latest bid.
+    pub candle_checkpoints: Vec<Bid>,
+    // candle auctions only. slot requested once bidding is over; the hash of the first slot from
+    // here on closes the auction, so nobody knows the close time while bids can still be placed.
+    pub candle_close_slot: u64,
+    // total amount of time, in seconds, that end_time has been extended by late bids
+    pub time_extension: u64,
     // Whether ofr not the auction has been settled
     pub settled: bool,
-    // current highest bid amount
+    // current highest bid amount. for second price settlement, the amount the winner paid once settled.
     pub amount: u64,
+    // highest bid amount from a bidder other than the current highest bidder
+    pub second_highest_amount: u64,
+    // whether the winner pays the second highest bid, copied from the auction factory when the
+    // auction is created
+    pub second_price_settlement: bool,
     // address of the current highest bid, nullable if no bid
     pub bidder: Pubkey,
+    // set when the current highest bid is backed by a max bid that will automatically counter
+    // competing bids. the ceiling itself is stored in the bidder's refund account.
+    pub proxy_bidder: Option<Pubkey>,
     // epoch time of the most recent bid was placed. used to keep track of auction timing.
     pub bid_time: u64,
     // address of the resource being auctioned; should not be null.
     pub resource: Option<Pubkey>,
-    // vec of submitted bids. some PDA for each bid tied to an auction could enable tracking an ininite
-    // number of bids. but, that poses the question — why care about storing that much data? this would
-    // also require each bidder to create another account for each bid submitted. seems like a waste.
-    // given the current size of an auction, we can extend the bid vec to ~200 bids before hitting the
-    // limit for solana account size.
+    // vec of the most recent submitted bids. given the current size of an auction, we can extend the bid
+    // vec to ~200 bids before hitting the limit for solana account size. bidders can optionally create a
+    // BidReceipt PDA per bid, keyed by bid_count, to keep the full bid history on-chain.
     pub bids: Vec<Bid>,
+    // total number of bids placed in the auction; index of the next bid
+    pub bid_count: u64,
+    // bid funds held by the auction that have been neither paid out at settlement nor claimed.
+    // anything the auction holds beyond this was sent to it directly.
+    pub escrowed_amount: u64,
+    // number of bid refund accounts that have not been closed yet. the auction can only be closed
+    // once they are, since their rent can no longer be recovered without the auction.
+    pub open_bid_refunds: u64,
     // token mint address for the SPL token being used to bid; default to SOL. creating an auction where
-    // bids are demonited in an SPL token means that all bids must use that SPL token.
-    // ancillary note: there is more work to be done before SPL tokens could be used for auctions.
-    // pub token_mint: Option<Pubkey>,
+    // bids are demonited in an SPL token means that all bids must use that SPL token. copied from the
+    // auction factory when the auction is created.
+    pub token_mint: Option<Pubkey>,
+    // number of winning bids, copied from the auction factory when the auction is created
+    pub num_winners: u8,
+    // multi-winner auctions only. the top num_winners bids, highest first. amount & bidder
+    // mirror the top bid.
+    pub winning_bids: Vec<Bid>,
+    // multi-winner auctions only. bit i is set once the winner at index i received their edition.
+    pub distributed_editions: u64,
+    // price at which a bid wins the auction immediately, copied from the auction factory
+    // when the auction is created
+    pub buy_now_price: Option<u64>,
+    // set when the authority cancels the auction before settlement. all escrowed bids become refundable.
+    pub cancelled: bool,
+    // set when the auction was never settled within the auction factory's settlement grace period.
+    // all escrowed bids become refundable.
+    pub voided: bool,
+    // disposition of the resource if the auction ends without any bids, copied from the auction
+    // factory when the auction is created
+    pub unsold_resource_policy: UnsoldResourcePolicy,
+    // set when the resource was carried over from the previous auction, which ended without any bids
+    pub resource_rolled_over: bool,
+    // set once the token account that held the resource has been closed
+    pub token_account_closed: bool,
 }
 
 impl Auction {
@@ -59,52 +130,450 @@ impl Auction {
         bump: u8,
         sequence: u64,
         authority: Pubkey,
-        current_timestamp: u64,
+        start_time: u64,
+        token_mint: Option<Pubkey>,
         factory_data: AuctionFactoryData,
     ) {
         self.bump = bump;
         self.sequence = sequence;
+        self.auction_type = factory_data.auction_type;
         self.authority = authority;
-        self.start_time = current_timestamp;
-        self.end_time = current_timestamp + factory_data.duration;
+        self.start_time = start_time;
+        self.end_time = start_time + factory_data.duration;
+        self.time_extension = 0;
+        self.dutch_auction = match factory_data.auction_type {
+            AuctionType::Dutch => factory_data.dutch_auction,
+            _ => DutchAuctionData::default(),
+        };
+        self.reveal_end_time = match factory_data.auction_type {
+            AuctionType::SealedBid => self.end_time + factory_data.sealed_bid.reveal_duration,
+            _ => 0,
+        };
+        self.non_reveal_penalty = match factory_data.auction_type {
+            AuctionType::SealedBid => factory_data.sealed_bid.non_reveal_penalty,
+            _ => 0,
+        };
+        self.candle_start_time = match factory_data.auction_type {
+            AuctionType::Candle => self.end_time - factory_data.candle.ending_period,
+            _ => 0,
+        };
+        self.candle_checkpoints = Vec::new();
+        self.candle_close_slot = 0;
         self.settled = false;
         self.amount = 0;
+        self.second_highest_amount = 0;
+        self.second_price_settlement = factory_data.second_price_settlement;
+        self.proxy_bidder = None;
         self.resource = None;
         self.bids = Vec::new();
+        self.bid_count = 0;
+        self.escrowed_amount = 0;
+        self.open_bid_refunds = 0;
+        self.token_mint = token_mint;
+        self.num_winners = std::cmp::max(factory_data.num_winners, 1);
+        self.winning_bids = Vec::new();
+        self.distributed_editions = 0;
+        self.buy_now_price = factory_data.buy_now_price;
+        self.cancelled = false;
+        self.voided = false;
+        self.unsold_resource_policy = factory_data.unsold_resource_policy;
+        self.resource_rolled_over = false;
+        self.token_account_closed = false;
     }
 
     pub fn add_resource(&mut self, resource: Pubkey) {
         self.resource = Some(resource);
     }
 
+    pub fn roll_over_resource(&mut self, resource: Pubkey) {
+        self.resource = Some(resource);
+        self.resource_rolled_over = true;
+    }
+
+    pub fn close_token_account(&mut self) {
+        self.token_account_closed = true;
+    }
+
+    // the resource of a settled auction without any bids, if it was carried into the next auction
+    pub fn get_rolled_over_resource(&self) -> Option<Pubkey> {
+        if !self.settled
+            || self.amount > 0
+            || self.unsold_resource_policy != UnsoldResourcePolicy::RollOver
+        {
+            return None;
+        }
+
+        self.resource
+    }
+
+    pub fn escrow(&mut self, amount: u64) -> ProgramResult {
+        self.escrowed_amount = self
+            .escrowed_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+
+        Ok(())
+    }
+
+    pub fn release_escrow(&mut self, amount: u64) -> ProgramResult {
+        self.escrowed_amount = self
+            .escrowed_amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::NumericalUnderflowError)?;
+
+        Ok(())
+    }
+
+    pub fn open_bid_refund(&mut self) -> ProgramResult {
+        self.open_bid_refunds = self
+            .open_bid_refunds
+            .checked_add(1)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+
+        Ok(())
+    }
+
+    pub fn close_bid_refund(&mut self) -> ProgramResult {
+        self.open_bid_refunds = self
+            .open_bid_refunds
+            .checked_sub(1)
+            .ok_or(ErrorCode::NumericalUnderflowError)?;
+
+        Ok(())
+    }
+
     pub fn settle(&mut self) {
         let current_timestamp = get_current_timestamp().unwrap();
 
         self.settled = true;
+        // auctions that closed before settlement already have a finalized end time
+        if self.finalized_end_time == 0 {
+            self.finalized_end_time = current_timestamp;
+        }
+        // release any unused proxy ceiling so that it can be claimed
+        self.proxy_bidder = None;
+    }
+
+    pub fn cancel(&mut self) {
+        let current_timestamp = get_current_timestamp().unwrap();
+
+        self.cancelled = true;
         self.finalized_end_time = current_timestamp;
+        self.proxy_bidder = None;
     }
 
-    pub fn update_auction_with_bid(&mut self, amount: u64, bidder: Pubkey) -> ProgramResult {
+    pub fn void(&mut self) {
         let current_timestamp = get_current_timestamp().unwrap();
 
-        self.amount = amount;
-        self.bidder = bidder;
-        self.bid_time = current_timestamp;
+        self.voided = true;
+        self.finalized_end_time = current_timestamp;
+        self.proxy_bidder = None;
+    }
+
+    // cancelled & voided auctions end without a winner
+    pub fn is_void(&self) -> bool {
+        self.cancelled || self.voided
+    }
+
+    // records a bid without extending the auction. dutch & sealed bid auctions have no
+    // competing live bids, so they record bids directly.
+    pub fn record_bid(&mut self, amount: u64, bidder: Pubkey) -> ProgramResult {
+        let current_timestamp = get_current_timestamp().unwrap();
 
         let bid = Bid {
-            bidder: bidder,
+            bidder,
             updated_at: current_timestamp,
-            amount: amount,
+            amount,
         };
 
+        if self.auction_type == AuctionType::Candle {
+            self.record_candle_checkpoint(bid)?;
+        }
+
+        if self.is_multi_winner() {
+            self.record_winning_bid(bid);
+        } else {
+            // a leader raising their own bid does not change the second highest bid
+            if self.bidder != bidder {
+                self.second_highest_amount = self.amount;
+            }
+
+            self.amount = amount;
+            self.bidder = bidder;
+        }
+        self.bid_time = current_timestamp;
+
         update_vec(&mut self.bids, bid, MAX_BIDS_TO_RECORD)?;
+        self.bid_count = self
+            .bid_count
+            .checked_add(1)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+
+        Ok(())
+    }
+
+    // ranks the bid among the winning bids; ties keep the earlier bid ahead. the lowest
+    // winning bid is displaced once all num_winners places are taken.
+    fn record_winning_bid(&mut self, bid: Bid) {
+        let position = self
+            .winning_bids
+            .iter()
+            .position(|winning_bid| winning_bid.amount < bid.amount)
+            .unwrap_or(self.winning_bids.len());
+
+        self.winning_bids.insert(position, bid);
+        self.winning_bids.truncate(self.num_winners as usize);
+
+        let top_bid = self.winning_bids[0];
+        self.amount = top_bid.amount;
+        self.bidder = top_bid.bidder;
+    }
+
+    pub fn is_multi_winner(&self) -> bool {
+        self.num_winners > 1
+    }
+
+    // bid that a new bid has to beat. bids into a multi-winner auction with open places only
+    // have to meet the reserve price.
+    pub fn amount_to_beat(&self) -> u64 {
+        if !self.is_multi_winner() {
+            return self.amount;
+        }
+
+        if self.winning_bids.len() < self.num_winners as usize {
+            return 0;
+        }
+
+        self.winning_bids.last().map_or(0, |bid| bid.amount)
+    }
+
+    pub fn is_winning_bidder(&self, bidder: Pubkey) -> bool {
+        if self.is_multi_winner() {
+            return self.winning_bids.iter().any(|bid| bid.bidder == bidder);
+        }
+
+        self.bidder == bidder
+    }
+
+    // amount of the bidder's winning bid, 0 if the bidder is not winning
+    pub fn winning_amount_for(&self, bidder: Pubkey) -> u64 {
+        if self.is_multi_winner() {
+            return self
+                .winning_bids
+                .iter()
+                .find(|bid| bid.bidder == bidder)
+                .map_or(0, |bid| bid.amount);
+        }
+
+        if self.bidder == bidder {
+            self.amount
+        } else {
+            0
+        }
+    }
+
+    // every winner pays their own bid
+    pub fn total_winning_amount(&self) -> Result<u64, ProgramError> {
+        if !self.is_multi_winner() {
+            return Ok(self.amount);
+        }
+
+        let mut total_amount: u64 = 0;
+        for bid in self.winning_bids.iter() {
+            total_amount = total_amount
+                .checked_add(bid.amount)
+                .ok_or(ErrorCode::NumericalOverflowError)?;
+        }
+
+        Ok(total_amount)
+    }
+
+    pub fn is_edition_distributed(&self, winner_index: u8) -> bool {
+        self.distributed_editions & (1 << winner_index) != 0
+    }
+
+    pub fn mark_edition_distributed(&mut self, winner_index: u8) {
+        self.distributed_editions |= 1 << winner_index;
+    }
+
+    // the master edition has to print every other winner's edition, so it is distributed last
+    pub fn print_editions_distributed(&self) -> bool {
+        (1..self.winning_bids.len())
+            .all(|winner_index| self.is_edition_distributed(winner_index as u8))
+    }
+
+    // sealed bids are revealed in any order, so a non-winning reveal can still be the second highest bid
+    pub fn record_losing_bid_amount(&mut self, amount: u64) {
+        self.second_highest_amount = std::cmp::max(self.second_highest_amount, amount);
+    }
+
+    pub fn set_settlement_amount(&mut self, amount: u64) {
+        self.amount = amount;
+    }
+
+    pub fn update_auction_with_bid(
+        &mut self,
+        amount: u64,
+        bidder: Pubkey,
+        factory_data: &AuctionFactoryData,
+    ) -> ProgramResult {
+        let current_timestamp = get_current_timestamp().unwrap();
+
+        // bids above the buy it now price only pay the buy it now price, the excess stays claimable
+        let amount = match self.buy_now_price {
+            Some(buy_now_price) => std::cmp::min(amount, buy_now_price),
+            None => amount,
+        };
 
-        // feat: this is where we can extend the auction end time if someone
-        // submits a winning bid within n time of original ending. pull extension from somewhere else.
-        // let auction_extension: u64 = 600;
-        // if self.end_time.checked_sub(current_timestamp) < auction_extension {
-        //     self.end_time = self.end_time.checked_add(auction_extension).unwrap();
-        // }
+        self.record_bid(amount, bidder)?;
+
+        if self.buy_now_price == Some(amount) {
+            msg!("auction won at buy it now price {}", amount);
+            self.finalized_end_time = current_timestamp;
+            return Ok(());
+        }
+
+        // candle auctions never extend, the random close already makes sniping pointless
+        if self.auction_type != AuctionType::English {
+            return Ok(());
+        }
+
+        self.extend_end_time(
+            current_timestamp,
+            factory_data.time_buffer,
+            factory_data.max_time_extension,
+        )?;
+
+        Ok(())
+    }
+
+    // index of the ending period window containing timestamp
+    fn get_candle_window(&self, timestamp: u64) -> Result<usize, ProgramError> {
+        let ending_period = self
+            .end_time
+            .checked_sub(self.candle_start_time)
+            .ok_or(ErrorCode::NumericalUnderflowError)?;
+        let elapsed = timestamp.saturating_sub(self.candle_start_time);
+        let window = (elapsed as u128)
+            .checked_mul(MAX_CANDLE_CHECKPOINTS as u128)
+            .ok_or(ErrorCode::NumericalOverflowError)?
+            .checked_div(ending_period as u128)
+            .ok_or(ErrorCode::NumericalDivisionError)?;
+
+        Ok(std::cmp::min(window as usize, MAX_CANDLE_CHECKPOINTS - 1))
+    }
+
+    // windows without any bids carry the previous leader forward. the latest bid within a window
+    // overwrites its checkpoint, so bid history is bounded no matter how many bids are placed.
+    fn record_candle_checkpoint(&mut self, bid: Bid) -> ProgramResult {
+        if bid.updated_at < self.candle_start_time {
+            return Ok(());
+        }
+
+        let window = self.get_candle_window(bid.updated_at)?;
+        let leading_bid = Bid {
+            bidder: self.bidder,
+            updated_at: self.bid_time,
+            amount: self.amount,
+        };
+        while self.candle_checkpoints.len() < window {
+            self.candle_checkpoints.push(leading_bid);
+        }
+
+        if self.candle_checkpoints.len() == window {
+            self.candle_checkpoints.push(bid);
+        } else {
+            self.candle_checkpoints[window] = bid;
+        }
+
+        Ok(())
+    }
+
+    // the close slot is a few slots ahead, so its hash is unknown to whoever requests it
+    pub fn request_candle_close(&mut self, current_slot: u64) -> ProgramResult {
+        self.candle_close_slot = current_slot
+            .checked_add(CANDLE_CLOSE_SLOT_DELAY)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+
+        Ok(())
+    }
+
+    // picks the leader at the end of the window containing close_time as the winner. a later bid
+    // from the winner, i.e. a raise, is not part of the winning bid.
+    pub fn close_candle_auction(&mut self, close_time: u64) -> ProgramResult {
+        let window = self.get_candle_window(close_time)?;
+        // no checkpoint means no bids since that window, so the current leader was leading then
+        if let Some(winning_bid) = self.candle_checkpoints.get(window).copied() {
+            self.amount = winning_bid.amount;
+            self.bidder = winning_bid.bidder;
+        }
+
+        let ending_period = self.end_time - self.candle_start_time;
+        let window_end_offset = (window as u64 + 1) * ending_period / MAX_CANDLE_CHECKPOINTS as u64;
+        self.finalized_end_time = self.candle_start_time + window_end_offset;
+
+        Ok(())
+    }
+
+    // whether or not the auction stopped accepting bids before its end time, e.g. via buy it
+    // now or cancellation, or has been settled
+    pub fn has_closed(&self) -> bool {
+        self.finalized_end_time > 0
+    }
+
+    // epoch time from which the auction can be settled. sealed bid auctions have a reveal
+    // phase after bidding ends. auctions that closed early can be settled right away.
+    pub fn settleable_at(&self) -> u64 {
+        if self.has_closed() {
+            return self.finalized_end_time;
+        }
+
+        match self.auction_type {
+            AuctionType::SealedBid => self.reveal_end_time,
+            _ => self.end_time,
+        }
+    }
+
+    // guarantee at least time_buffer seconds remain in the auction after a bid, so that
+    // last second bids can be answered. the total extension can optionally be capped.
+    fn extend_end_time(
+        &mut self,
+        current_timestamp: u64,
+        time_buffer: u64,
+        max_time_extension: Option<u64>,
+    ) -> ProgramResult {
+        let time_remaining = self.end_time.saturating_sub(current_timestamp);
+        if time_remaining >= time_buffer {
+            return Ok(());
+        }
+
+        let mut extension = time_buffer
+            .checked_sub(time_remaining)
+            .ok_or(ErrorCode::NumericalUnderflowError)?;
+
+        if let Some(max_extension) = max_time_extension {
+            let remaining_extension = max_extension.saturating_sub(self.time_extension);
+            extension = std::cmp::min(extension, remaining_extension);
+        }
+
+        if extension == 0 {
+            return Ok(());
+        }
+
+        self.end_time = self
+            .end_time
+            .checked_add(extension)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+        self.time_extension = self
+            .time_extension
+            .checked_add(extension)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+
+        msg!(
+            "extended auction end time by {} seconds to {}",
+            extension,
+            self.end_time
+        );
 
         Ok(())
     }
@@ -126,6 +595,8 @@ pub const AUCTION_ACCOUNT_SPACE: usize =
     1 +
     // sequence
     8 +
+    // auction_type
+    1 +
     // authority
     32 +
     // start_time
@@ -134,15 +605,145 @@ pub const AUCTION_ACCOUNT_SPACE: usize =
     8 +
     // finalized_end_time
     8 +
+    // dutch_auction: start_price, floor_price, curve, decay_rate, decay_interval
+    8 + 8 + 1 + 8 + 8 +
+    // reveal_end_time
+    8 +
+    // non_reveal_penalty
+    8 +
+    // candle_start_time
+    8 +
+    // candle_checkpoints
+    4 + (BID_SPACE * MAX_CANDLE_CHECKPOINTS) +
+    // candle_close_slot
+    8 +
+    // time_extension
+    8 +
     // settled
     1 +
     // amount
     8 +
+    // second_highest_amount
+    8 +
+    // second_price_settlement
+    1 +
     // bidder
     32 +
+    // proxy_bidder
+    1 + 32 +
     // bid_time
     8 +
     // resource
     1 + 32 +
     // bids
-    4 + (BID_SPACE * MAX_BIDS_TO_RECORD);
+    4 + (BID_SPACE * MAX_BIDS_TO_RECORD) +
+    // bid_count
+    8 +
+    // escrowed_amount
+    8 +
+    // open_bid_refunds
+    8 +
+    // token_mint
+    1 + 32 +
+    // num_winners
+    1 +
+    // winning_bids
+    4 + (BID_SPACE * MAX_WINNERS_PER_AUCTION) +
+    // distributed_editions
+    8 +
+    // buy_now_price
+    1 + 8 +
+    // cancelled
+    1 +
+    // voided
+    1 +
+    // unsold_resource_policy
+    1 +
+    // resource_rolled_over
+    1 +
+    // token_account_closed
+    1;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bid(amount: u64, updated_at: u64) -> Bid {
+        Bid {
+            bidder: Pubkey::new_unique(),
+            updated_at,
+            amount,
+        }
+    }
+
+    #[test]
+    fn winning_bids_are_ranked_and_capped() {
+        let mut auction = Auction {
+            num_winners: 3,
+            ..Auction::default()
+        };
+
+        let first = bid(100, 0);
+        let highest = bid(300, 1);
+        let middle = bid(200, 2);
+        let tie = bid(300, 3);
+        auction.record_winning_bid(first);
+        auction.record_winning_bid(highest);
+        auction.record_winning_bid(middle);
+        assert_eq!(auction.winning_bids, vec![highest, middle, first]);
+
+        // ties keep the earlier bid ahead and displace the lowest winning bid
+        auction.record_winning_bid(tie);
+        assert_eq!(auction.winning_bids, vec![highest, tie, middle]);
+
+        auction.record_winning_bid(bid(50, 4));
+        assert_eq!(auction.winning_bids, vec![highest, tie, middle]);
+
+        assert_eq!(auction.amount, 300);
+        assert_eq!(auction.bidder, highest.bidder);
+        assert_eq!(auction.amount_to_beat(), 200);
+    }
+
+    #[test]
+    fn candle_auction_closes_on_window_checkpoint() {
+        let mut auction = Auction {
+            auction_type: AuctionType::Candle,
+            num_winners: 1,
+            candle_start_time: 1_000,
+            end_time: 1_100,
+            ..Auction::default()
+        };
+
+        let before_ending_period = bid(100, 900);
+        let window_two = bid(200, 1_025);
+        let window_two_raise = bid(250, 1_029);
+        let window_seven = bid(300, 1_075);
+        for new_bid in [
+            before_ending_period,
+            window_two,
+            window_two_raise,
+            window_seven,
+        ] {
+            auction.record_candle_checkpoint(new_bid).unwrap();
+            auction.amount = new_bid.amount;
+            auction.bidder = new_bid.bidder;
+            auction.bid_time = new_bid.updated_at;
+        }
+        assert_eq!(auction.candle_checkpoints.len(), 8);
+
+        let mut closed_early = auction.clone();
+        closed_early.close_candle_auction(1_005).unwrap();
+        assert_eq!(closed_early.bidder, before_ending_period.bidder);
+        assert_eq!(closed_early.finalized_end_time, 1_010);
+
+        let mut closed_mid = auction.clone();
+        closed_mid.close_candle_auction(1_050).unwrap();
+        assert_eq!(closed_mid.bidder, window_two_raise.bidder);
+        assert_eq!(closed_mid.amount, 250);
+
+        let mut closed_late = auction.clone();
+        closed_late.close_candle_auction(1_099).unwrap();
+        assert_eq!(closed_late.bidder, window_seven.bidder);
+        assert_eq!(closed_late.finalized_end_time, 1_100);
+    }
+}
@@ -1,4 +1,276 @@
-use {crate::util::general::get_current_timestamp, anchor_lang::prelude::*};
+use {
+    crate::{
+        constant::{BASIS_POINTS_DENOMINATOR, MAX_BID_INCREMENT_TIERS, MAX_REVENUE_RECIPIENTS},
+        error::ErrorCode,
+        util::general::get_current_timestamp,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub enum AuctionType {
+    // ascending price, highest bid at end_time wins
+    #[default]
+    English,
+    // descending price, first buyer at the current price wins
+    Dutch,
+    // bids are committed as hashes, then revealed. highest revealed bid wins
+    SealedBid,
+    // ascending price, but the auction closes at a random moment during its ending period,
+    // picked after the fact. the leader at that moment wins.
+    Candle,
+}
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub enum PriceCurve {
+    // price falls by an equal amount every second
+    #[default]
+    Linear,
+    // price falls by decay_rate basis points every decay_interval seconds
+    Exponential,
+}
+
+// price schedule for dutch auctions. price starts at start_price and decays to floor_price
+// over the auction duration, after which the auction ends unsold.
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct DutchAuctionData {
+    pub start_price: u64,
+    pub floor_price: u64,
+    pub curve: PriceCurve,
+    // exponential curve only, in basis points
+    pub decay_rate: u64,
+    // exponential curve only, in seconds
+    pub decay_interval: u64,
+}
+
+// bids are committed until the auction's end_time, then revealed for reveal_duration seconds.
+// deposits that are never revealed are refundable, less non_reveal_penalty basis points.
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct SealedBidData {
+    pub reveal_duration: u64,
+    pub non_reveal_penalty: u64,
+}
+
+// the last ending_period seconds before an auction's end_time, during which the candle
+// auction may have already closed.
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct CandleAuctionData {
+    pub ending_period: u64,
+}
+
+// what happens to the resource of an auction that ends without any bids
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub enum UnsoldResourcePolicy {
+    #[default]
+    Burn,
+    // sent to the auction factory's unsold resource recipient
+    Transfer,
+    // carried into the next auction, which is not supplied a newly minted resource
+    RollOver,
+}
+
+// pins auction start times to a recurring wall-clock time. auctions start offset seconds into
+// an interval, counted from the unix epoch; e.g. interval = 86400 & offset = 64800 starts
+// auctions daily at 18:00 UTC.
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct AuctionSchedule {
+    pub interval: u64,
+    pub offset: u64,
+}
+
+impl AuctionSchedule {
+    // first scheduled start time at or after earliest_start_time
+    pub fn next_start_time(&self, earliest_start_time: u64) -> Result<u64, ProgramError> {
+        let interval_start_time = earliest_start_time
+            .checked_div(self.interval)
+            .ok_or(ErrorCode::NumericalDivisionError)?
+            .checked_mul(self.interval)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+
+        let start_time = interval_start_time
+            .checked_add(self.offset)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+        if start_time >= earliest_start_time {
+            return Ok(start_time);
+        }
+
+        let next_start_time = start_time
+            .checked_add(self.interval)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+
+        Ok(next_start_time)
+    }
+}
+
+impl DutchAuctionData {
+    pub fn price_at(&self, elapsed: u64, duration: u64) -> Result<u64, ProgramError> {
+        if elapsed >= duration {
+            return Ok(self.floor_price);
+        }
+
+        let price_range = self
+            .start_price
+            .checked_sub(self.floor_price)
+            .ok_or(ErrorCode::NumericalUnderflowError)?;
+
+        let price = match self.curve {
+            PriceCurve::Linear => {
+                let decayed_amount = (price_range as u128)
+                    .checked_mul(elapsed as u128)
+                    .ok_or(ErrorCode::NumericalOverflowError)?
+                    .checked_div(duration as u128)
+                    .ok_or(ErrorCode::NumericalDivisionError)?;
+
+                self.start_price
+                    .checked_sub(decayed_amount as u64)
+                    .ok_or(ErrorCode::NumericalUnderflowError)?
+            }
+            PriceCurve::Exponential => {
+                let num_intervals = elapsed
+                    .checked_div(self.decay_interval)
+                    .ok_or(ErrorCode::NumericalDivisionError)?;
+                let retained_per_interval = BASIS_POINTS_DENOMINATOR
+                    .checked_sub(self.decay_rate)
+                    .ok_or(ErrorCode::NumericalUnderflowError)?;
+
+                // (retained / denominator) ^ num_intervals, in basis points, via exponentiation by squaring
+                let denominator = BASIS_POINTS_DENOMINATOR as u128;
+                let mut retained: u128 = denominator;
+                let mut base = retained_per_interval as u128;
+                let mut exponent = num_intervals;
+                while exponent > 0 && retained > 0 {
+                    if exponent & 1 == 1 {
+                        retained = retained * base / denominator;
+                    }
+                    base = base * base / denominator;
+                    exponent >>= 1;
+                }
+
+                let price = (self.start_price as u128)
+                    .checked_mul(retained)
+                    .ok_or(ErrorCode::NumericalOverflowError)?
+                    .checked_div(denominator)
+                    .ok_or(ErrorCode::NumericalDivisionError)?;
+
+                std::cmp::max(price as u64, self.floor_price)
+            }
+        };
+
+        Ok(price)
+    }
+}
+
+// once the current bid reaches threshold, the next bid must beat it by at least increment
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct BidIncrementTier {
+    pub threshold: u64,
+    // unused tiers have an increment of 0
+    pub increment: u64,
+}
+
+// reward for whoever cranks the auction factory. fixed_amount lamports are paid from the auction
+// factory's own lamports for each settle_auction & create_next_auction. winning_bid_share basis
+// points of the proceeds are paid on settlement, in the bid currency.
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct CrankRewardData {
+    pub fixed_amount: u64,
+    pub winning_bid_share: u64,
+}
+
+// share of settled auction revenue sent to recipient, in basis points. for auction factories with
+// a bid mint, recipient is a token account of that mint.
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct RevenueShare {
+    pub recipient: Pubkey,
+    pub share: u64,
+}
+
+// admin permissions that the authority can delegate. the authority itself holds every role.
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum AuctionFactoryRole {
+    // pauses & resumes the auction factory
+    Pauser,
+    // adds uris to the config
+    ConfigManager,
+    // modifies auction factory data
+    ParameterManager,
+    // updates the treasury & sweeps auction factory lamports to it
+    Treasurer,
+}
+
+// holder of each delegated role, if granted
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct AuctionFactoryRoles {
+    pub pauser: Option<Pubkey>,
+    pub config_manager: Option<Pubkey>,
+    pub parameter_manager: Option<Pubkey>,
+    pub treasurer: Option<Pubkey>,
+}
+
+impl AuctionFactoryRoles {
+    pub fn get(&self, role: AuctionFactoryRole) -> Option<Pubkey> {
+        match role {
+            AuctionFactoryRole::Pauser => self.pauser,
+            AuctionFactoryRole::ConfigManager => self.config_manager,
+            AuctionFactoryRole::ParameterManager => self.parameter_manager,
+            AuctionFactoryRole::Treasurer => self.treasurer,
+        }
+    }
+
+    pub fn set(&mut self, role: AuctionFactoryRole, holder: Option<Pubkey>) {
+        match role {
+            AuctionFactoryRole::Pauser => self.pauser = holder,
+            AuctionFactoryRole::ConfigManager => self.config_manager = holder,
+            AuctionFactoryRole::ParameterManager => self.parameter_manager = holder,
+            AuctionFactoryRole::Treasurer => self.treasurer = holder,
+        }
+    }
+}
+
+// changes that are queued for change_delay seconds before they can be executed
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum AuctionFactoryChange {
+    Data,
+    Treasury,
+    RevenueSplit,
+}
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct PendingDataChange {
+    pub data: AuctionFactoryData,
+    // epoch time from which the change can be executed
+    pub execute_after: u64,
+}
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct PendingTreasuryChange {
+    pub treasury: Pubkey,
+    // epoch time from which the change can be executed
+    pub execute_after: u64,
+}
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Debug)]
+pub struct PendingRevenueSplitChange {
+    pub revenue_split: Vec<RevenueShare>,
+    // epoch time from which the change can be executed
+    pub execute_after: u64,
+}
 
 #[repr(C)]
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
@@ -7,10 +279,75 @@ pub struct AuctionFactoryData {
     pub time_buffer: u64,
     // min percentage difference between the last bid amount and the current bid, in basis points
     pub min_bid_percentage_increase: u64,
+    // min absolute difference between the last bid amount and the current bid
+    pub min_bid_increment: u64,
+    // min difference between the last bid amount and the current bid, tiered by the last bid amount.
+    // length is MAX_BID_INCREMENT_TIERS; written as a literal because the idl parser can't resolve consts.
+    pub bid_increment_tiers: [BidIncrementTier; 4],
     // min price accepted in an auction
     pub min_reserve_price: u64,
     // duration of a single auction, in seconds
     pub duration: u64,
+    // max total amount of time, in seconds, that bids can extend a single auction past its
+    // original end time. no cap if none.
+    pub max_time_extension: Option<u64>,
+    // type of auction created by the auction factory
+    pub auction_type: AuctionType,
+    // price schedule, only used by dutch auctions
+    pub dutch_auction: DutchAuctionData,
+    // reveal phase configuration, only used by sealed bid auctions
+    pub sealed_bid: SealedBidData,
+    // ending period configuration, only used by candle auctions
+    pub candle: CandleAuctionData,
+    // if true, the winner pays the second highest bid (or the reserve price if there was
+    // only one bid) plus one increment, capped at their own bid.
+    pub second_price_settlement: bool,
+    // number of winning bids in each auction. the top bidder receives the master edition and
+    // every other winner a print of it. 0 or 1 for a single winner auction.
+    pub num_winners: u8,
+    // english auctions only. a bid at or above this price wins the auction immediately, at
+    // this price. no buy it now if none.
+    pub buy_now_price: Option<u64>,
+    // min amount of time, in seconds, between the end of an auction and the start of the next one
+    pub cooldown: u64,
+    // if present, auctions start at the next scheduled time instead of as soon as they are created
+    pub schedule: Option<AuctionSchedule>,
+    // amount of time, in seconds, after which an auction that could have been settled but was not
+    // can be voided by its bidders to recover their bids. no emergency exit if none.
+    pub settlement_grace_period: Option<u64>,
+    // reward paid to whoever settles auctions & creates the next one
+    pub crank_reward: CrankRewardData,
+    // disposition of the resource of auctions that end without any bids
+    pub unsold_resource_policy: UnsoldResourcePolicy,
+    // wallet that receives unsold resources under the transfer policy; the treasury if none.
+    // required for auction factories with a bid mint, whose treasury is a token account.
+    pub unsold_resource_recipient: Option<Pubkey>,
+    // min amount of time, in seconds, between queueing a change to auction factory data or the
+    // treasury and executing it
+    pub change_delay: u64,
+}
+
+impl AuctionFactoryData {
+    // auctions start once created, but no sooner than cooldown seconds after the previous auction
+    // ended, and on schedule if there is one. bids are rejected until the start time.
+    pub fn get_start_time(
+        &self,
+        current_timestamp: u64,
+        previous_end_time: Option<u64>,
+    ) -> Result<u64, ProgramError> {
+        let mut earliest_start_time = current_timestamp;
+        if let Some(previous_end_time) = previous_end_time {
+            let cooldown_end_time = previous_end_time
+                .checked_add(self.cooldown)
+                .ok_or(ErrorCode::NumericalOverflowError)?;
+            earliest_start_time = std::cmp::max(earliest_start_time, cooldown_end_time);
+        }
+
+        match self.schedule {
+            Some(schedule) => schedule.next_start_time(earliest_start_time),
+            None => Ok(earliest_start_time),
+        }
+    }
 }
 
 #[account]
@@ -40,9 +377,26 @@ pub struct AuctionFactory {
     // account with uri config data. used in token minting, managed at the auction
     // factory method, static across auctions.
     pub config: Pubkey,
+    // mint of the SPL token in which bids are denominated; bids are in SOL if none. set once
+    // at initialization. when present, the treasury must be a token account of this mint.
+    pub bid_mint: Option<Pubkey>,
+    // recipients of settled auction revenue; shares total 10,000 basis points. if empty, all
+    // revenue goes to the treasury.
+    pub revenue_split: Vec<RevenueShare>,
+    // authority proposed by the current authority. only takes over once it accepts.
+    pub pending_authority: Option<Pubkey>,
+    // admin roles delegated by the authority
+    pub roles: AuctionFactoryRoles,
+    // queued auction factory data, applied via execute_pending_change once its delay has passed
+    pub pending_data: Option<PendingDataChange>,
+    // queued treasury, applied via execute_pending_change once its delay has passed
+    pub pending_treasury: Option<PendingTreasuryChange>,
+    // queued revenue split, applied via execute_pending_change once its delay has passed
+    pub pending_revenue_split: Option<PendingRevenueSplitChange>,
 }
 
 impl AuctionFactory {
+    #[allow(clippy::too_many_arguments)]
     pub fn init(
         &mut self,
         bump: u8,
@@ -50,6 +404,7 @@ impl AuctionFactory {
         authority: Pubkey,
         treasury: Pubkey,
         config: Pubkey,
+        bid_mint: Option<Pubkey>,
         data: AuctionFactoryData,
     ) {
         let current_timestamp = get_current_timestamp().unwrap();
@@ -64,6 +419,13 @@ impl AuctionFactory {
         self.active_since = current_timestamp;
         self.treasury = treasury;
         self.config = config;
+        self.bid_mint = bid_mint;
+        self.revenue_split = Vec::new();
+        self.pending_authority = None;
+        self.roles = AuctionFactoryRoles::default();
+        self.pending_data = None;
+        self.pending_treasury = None;
+        self.pending_revenue_split = None;
     }
 
     pub fn pause(&mut self) {
@@ -82,8 +444,34 @@ impl AuctionFactory {
         self.sequence = updated_sequence;
     }
 
-    pub fn update_authority(&mut self, authority: Pubkey) {
-        self.authority = authority;
+    pub fn propose_authority(&mut self, authority: Pubkey) {
+        self.pending_authority = Some(authority);
+    }
+
+    // roles granted by the previous authority do not carry over to the new authority
+    pub fn accept_authority(&mut self) {
+        if let Some(pending_authority) = self.pending_authority {
+            self.authority = pending_authority;
+            self.pending_authority = None;
+            self.roles = AuctionFactoryRoles::default();
+        }
+    }
+
+    pub fn cancel_authority_transfer(&mut self) {
+        self.pending_authority = None;
+    }
+
+    // the authority or the role's holder
+    pub fn has_role(&self, role: AuctionFactoryRole, account: Pubkey) -> bool {
+        self.authority == account || self.roles.get(role) == Some(account)
+    }
+
+    pub fn grant_role(&mut self, role: AuctionFactoryRole, holder: Pubkey) {
+        self.roles.set(role, Some(holder));
+    }
+
+    pub fn revoke_role(&mut self, role: AuctionFactoryRole) {
+        self.roles.set(role, None);
     }
 
     pub fn update_treasury(&mut self, treasury: Pubkey) {
@@ -93,6 +481,139 @@ impl AuctionFactory {
     pub fn update_data(&mut self, data: AuctionFactoryData) {
         self.data = data;
     }
+
+    // the delay in effect when a change is queued applies to it, so that lowering the delay is
+    // itself delayed
+    fn get_execute_after(&self) -> Result<u64, ProgramError> {
+        let current_timestamp = get_current_timestamp()?;
+
+        let execute_after = current_timestamp
+            .checked_add(self.data.change_delay)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+
+        Ok(execute_after)
+    }
+
+    // queueing a change replaces any pending change of the same kind
+    pub fn queue_data_change(&mut self, data: AuctionFactoryData) -> Result<u64, ProgramError> {
+        let execute_after = self.get_execute_after()?;
+        self.pending_data = Some(PendingDataChange {
+            data,
+            execute_after,
+        });
+
+        Ok(execute_after)
+    }
+
+    pub fn queue_treasury_change(&mut self, treasury: Pubkey) -> Result<u64, ProgramError> {
+        let execute_after = self.get_execute_after()?;
+        self.pending_treasury = Some(PendingTreasuryChange {
+            treasury,
+            execute_after,
+        });
+
+        Ok(execute_after)
+    }
+
+    pub fn queue_revenue_split_change(
+        &mut self,
+        revenue_split: Vec<RevenueShare>,
+    ) -> Result<u64, ProgramError> {
+        let execute_after = self.get_execute_after()?;
+        self.pending_revenue_split = Some(PendingRevenueSplitChange {
+            revenue_split,
+            execute_after,
+        });
+
+        Ok(execute_after)
+    }
+
+    pub fn has_pending_change(&self, change: AuctionFactoryChange) -> bool {
+        match change {
+            AuctionFactoryChange::Data => self.pending_data.is_some(),
+            AuctionFactoryChange::Treasury => self.pending_treasury.is_some(),
+            AuctionFactoryChange::RevenueSplit => self.pending_revenue_split.is_some(),
+        }
+    }
+
+    pub fn get_pending_change_execute_after(&self, change: AuctionFactoryChange) -> Option<u64> {
+        match change {
+            AuctionFactoryChange::Data => self.pending_data.map(|pending| pending.execute_after),
+            AuctionFactoryChange::Treasury => {
+                self.pending_treasury.map(|pending| pending.execute_after)
+            }
+            AuctionFactoryChange::RevenueSplit => self
+                .pending_revenue_split
+                .as_ref()
+                .map(|pending| pending.execute_after),
+        }
+    }
+
+    pub fn execute_pending_change(&mut self, change: AuctionFactoryChange) {
+        match change {
+            AuctionFactoryChange::Data => {
+                if let Some(pending_data) = self.pending_data.take() {
+                    self.update_data(pending_data.data);
+                }
+            }
+            AuctionFactoryChange::Treasury => {
+                if let Some(pending_treasury) = self.pending_treasury.take() {
+                    self.update_treasury(pending_treasury.treasury);
+                }
+            }
+            AuctionFactoryChange::RevenueSplit => {
+                if let Some(pending_revenue_split) = self.pending_revenue_split.take() {
+                    self.update_revenue_split(pending_revenue_split.revenue_split);
+                }
+            }
+        }
+    }
+
+    pub fn cancel_pending_change(&mut self, change: AuctionFactoryChange) {
+        match change {
+            AuctionFactoryChange::Data => self.pending_data = None,
+            AuctionFactoryChange::Treasury => self.pending_treasury = None,
+            AuctionFactoryChange::RevenueSplit => self.pending_revenue_split = None,
+        }
+    }
+
+    pub fn get_unsold_resource_recipient(&self) -> Pubkey {
+        self.data.unsold_resource_recipient.unwrap_or(self.treasury)
+    }
+
+    pub fn update_revenue_split(&mut self, revenue_split: Vec<RevenueShare>) {
+        self.revenue_split = revenue_split;
+    }
+
+    // each recipient's share of amount, in revenue_split order. shares are rounded down and the
+    // remainder goes to the first recipient, so that exactly amount is distributed.
+    pub fn get_revenue_split_amounts(&self, amount: u64) -> Result<Vec<u64>, ProgramError> {
+        let mut amounts = Vec::with_capacity(self.revenue_split.len());
+        let mut distributed_amount: u64 = 0;
+        for revenue_share in self.revenue_split.iter() {
+            let share_amount = (amount as u128)
+                .checked_mul(revenue_share.share as u128)
+                .ok_or(ErrorCode::NumericalOverflowError)?
+                .checked_div(BASIS_POINTS_DENOMINATOR as u128)
+                .ok_or(ErrorCode::NumericalDivisionError)? as u64;
+
+            distributed_amount = distributed_amount
+                .checked_add(share_amount)
+                .ok_or(ErrorCode::NumericalOverflowError)?;
+            amounts.push(share_amount);
+        }
+
+        let remainder = amount
+            .checked_sub(distributed_amount)
+            .ok_or(ErrorCode::NumericalUnderflowError)?;
+        if let Some(first_amount) = amounts.first_mut() {
+            *first_amount = first_amount
+                .checked_add(remainder)
+                .ok_or(ErrorCode::NumericalOverflowError)?;
+        }
+
+        Ok(amounts)
+    }
 }
 
 // auction factory account struct sizing for account init
@@ -101,9 +622,43 @@ pub const AUCTION_FACTORY_DATA_SPACE: usize =
     8 +
     // min_bid_percentage_increase
     8 +
+    // min_bid_increment
+    8 +
+    // bid_increment_tiers
+    (MAX_BID_INCREMENT_TIERS * (8 + 8)) +
     // min_reserve_price
     8 +
     // duration
+    8 +
+    // max_time_extension
+    1 + 8 +
+    // auction_type
+    1 +
+    // dutch_auction: start_price, floor_price, curve, decay_rate, decay_interval
+    8 + 8 + 1 + 8 + 8 +
+    // sealed_bid: reveal_duration, non_reveal_penalty
+    8 + 8 +
+    // candle: ending_period
+    8 +
+    // second_price_settlement
+    1 +
+    // num_winners
+    1 +
+    // buy_now_price
+    1 + 8 +
+    // cooldown
+    8 +
+    // schedule: interval, offset
+    1 + 8 + 8 +
+    // settlement_grace_period
+    1 + 8 +
+    // crank_reward: fixed_amount, winning_bid_share
+    8 + 8 +
+    // unsold_resource_policy
+    1 +
+    // unsold_resource_recipient
+    1 + 32 +
+    // change_delay
     8;
 
 pub const AUCTION_FACTORY_ACCOUNT_SPACE: usize =
@@ -128,4 +683,101 @@ pub const AUCTION_FACTORY_ACCOUNT_SPACE: usize =
     // treasury
     32 +
     // config
-    32;
+    32 +
+    // bid_mint
+    1 + 32 +
+    // revenue_split: recipient, share
+    4 + (MAX_REVENUE_RECIPIENTS * (32 + 8)) +
+    // pending_authority
+    1 + 32 +
+    // roles: pauser, config_manager, parameter_manager, treasurer
+    (4 * (1 + 32)) +
+    // pending_data: data, execute_after
+    1 + AUCTION_FACTORY_DATA_SPACE + 8 +
+    // pending_treasury: treasury, execute_after
+    1 + 32 + 8 +
+    // pending_revenue_split: revenue_split, execute_after
+    1 + 4 + (MAX_REVENUE_RECIPIENTS * (32 + 8)) + 8;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dutch_auction(curve: PriceCurve) -> DutchAuctionData {
+        DutchAuctionData {
+            start_price: 1_000,
+            floor_price: 100,
+            curve,
+            decay_rate: 1_000,
+            decay_interval: 10,
+        }
+    }
+
+    #[test]
+    fn linear_price_decays_evenly_to_floor() {
+        let dutch_auction = dutch_auction(PriceCurve::Linear);
+
+        assert_eq!(dutch_auction.price_at(0, 100).unwrap(), 1_000);
+        assert_eq!(dutch_auction.price_at(50, 100).unwrap(), 550);
+        assert_eq!(dutch_auction.price_at(99, 100).unwrap(), 109);
+        assert_eq!(dutch_auction.price_at(100, 100).unwrap(), 100);
+        assert_eq!(dutch_auction.price_at(150, 100).unwrap(), 100);
+    }
+
+    #[test]
+    fn exponential_price_decays_per_interval_to_floor() {
+        let dutch_auction = dutch_auction(PriceCurve::Exponential);
+
+        assert_eq!(dutch_auction.price_at(0, 1_000).unwrap(), 1_000);
+        assert_eq!(dutch_auction.price_at(9, 1_000).unwrap(), 1_000);
+        assert_eq!(dutch_auction.price_at(10, 1_000).unwrap(), 900);
+        assert_eq!(dutch_auction.price_at(20, 1_000).unwrap(), 810);
+        assert_eq!(dutch_auction.price_at(990, 1_000).unwrap(), 100);
+    }
+
+    #[test]
+    fn next_start_time_snaps_to_schedule() {
+        let schedule = AuctionSchedule {
+            interval: 86_400,
+            offset: 64_800,
+        };
+
+        assert_eq!(schedule.next_start_time(0).unwrap(), 64_800);
+        assert_eq!(schedule.next_start_time(64_800).unwrap(), 64_800);
+        assert_eq!(schedule.next_start_time(64_801).unwrap(), 151_200);
+        assert_eq!(schedule.next_start_time(100_000).unwrap(), 151_200);
+    }
+
+    #[test]
+    fn revenue_split_gives_rounding_remainder_to_first_recipient() {
+        let mut auction_factory = AuctionFactory::default();
+        assert!(auction_factory
+            .get_revenue_split_amounts(100)
+            .unwrap()
+            .is_empty());
+
+        auction_factory.update_revenue_split(vec![
+            RevenueShare {
+                recipient: Pubkey::new_unique(),
+                share: 3_333,
+            },
+            RevenueShare {
+                recipient: Pubkey::new_unique(),
+                share: 3_333,
+            },
+            RevenueShare {
+                recipient: Pubkey::new_unique(),
+                share: 3_334,
+            },
+        ]);
+
+        assert_eq!(
+            auction_factory.get_revenue_split_amounts(100).unwrap(),
+            vec![34, 33, 33]
+        );
+        assert_eq!(
+            auction_factory.get_revenue_split_amounts(0).unwrap(),
+            vec![0, 0, 0]
+        );
+    }
+}
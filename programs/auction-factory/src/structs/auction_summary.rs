@@ -0,0 +1,60 @@
+use {crate::structs::auction::Auction, anchor_lang::prelude::*};
+
+/// AuctionSummary is the permanent record of an auction, written when the auction account is
+/// closed via close_auction. the auction's rent, bid history included, goes back to the auction
+/// factory.
+#[account]
+#[derive(Default)]
+pub struct AuctionSummary {
+    pub bump: u8,
+    // auction factory that created the auction
+    pub auction_factory: Pubkey,
+    // index of the auction in the auction factory's sequence
+    pub sequence: u64,
+    // winning bidder; none if the auction ended without any bids, or was cancelled or voided.
+    // the top bidder for multi-winner auctions.
+    pub winner: Option<Pubkey>,
+    // winning bid amount; the amount paid by the winner once settled
+    pub amount: u64,
+    // address of the auctioned resource
+    pub mint: Option<Pubkey>,
+    // epoch time that the auction actually ended
+    pub finalized_end_time: u64,
+}
+
+impl AuctionSummary {
+    pub fn init(&mut self, bump: u8, auction_factory: Pubkey, auction: &Auction) {
+        let has_winner = auction.settled && auction.amount > 0;
+
+        self.bump = bump;
+        self.auction_factory = auction_factory;
+        self.sequence = auction.sequence;
+        self.winner = if has_winner {
+            Some(auction.bidder)
+        } else {
+            None
+        };
+        self.amount = if has_winner { auction.amount } else { 0 };
+        self.mint = auction.resource;
+        self.finalized_end_time = auction.finalized_end_time;
+    }
+}
+
+// auction summary account struct sizing for account init
+pub const AUCTION_SUMMARY_ACCOUNT_SPACE: usize =
+    // discriminator
+    8 +
+    // bump
+    1 +
+    // auction_factory
+    32 +
+    // sequence
+    8 +
+    // winner
+    1 + 32 +
+    // amount
+    8 +
+    // mint
+    1 + 32 +
+    // finalized_end_time
+    8;
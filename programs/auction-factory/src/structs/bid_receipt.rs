@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// BidReceipt is an optional, permanent record of a single bid. the auction only keeps the
+/// most recent bids, so receipts keyed by auction & bid index let anyone rebuild the full
+/// bid history from chain state. receipts can be closed once the auction is settled.
+#[account]
+#[derive(Default)]
+pub struct BidReceipt {
+    pub bump: u8,
+    // auction in which the bid was placed
+    pub auction: Pubkey,
+    // zero indexed position of the bid in the auction's bid history
+    pub index: u64,
+    // pubkey of bidder
+    pub bidder: Pubkey,
+    // bid amount
+    pub amount: u64,
+    // timestamp at which bid was submitted
+    pub updated_at: u64,
+    // account that paid rent for the receipt; receives the rent when the receipt is closed
+    pub payer: Pubkey,
+}
+
+// bid receipt account struct sizing for account init
+pub const BID_RECEIPT_ACCOUNT_SPACE: usize =
+    // discriminator
+    8 +
+    // bump
+    1 +
+    // auction
+    32 +
+    // index
+    8 +
+    // bidder
+    32 +
+    // amount
+    8 +
+    // updated_at
+    8 +
+    // payer
+    32;
@@ -0,0 +1,252 @@
+use {
+    crate::{
+        constant::BASIS_POINTS_DENOMINATOR,
+        error::ErrorCode,
+        structs::{auction::Auction, auction_factory::AuctionType},
+        util::general::get_current_timestamp,
+    },
+    anchor_lang::prelude::*,
+};
+
+/// BidRefund tracks the funds a bidder has escrowed in a single auction. bids are never
+/// pushed back to an outbid bidder. instead, each bid is added to the bidder's refund
+/// account and the bidder later withdraws anything that is not backing the leading bid.
+/// funds themselves stay in the auction PDA (or its bid token account for SPL auctions).
+///
+/// a bidder can also escrow a max (proxy) bid. the ceiling lives here rather than on the
+/// auction so that only the auto-bids placed on the bidder's behalf are visible in the
+/// auction's bid history. while the proxy is active, the full ceiling stays locked.
+///
+/// in sealed bid auctions, the escrowed amount is the bidder's deposit and the commitment to
+/// the hidden bid is stored alongside it. deposits stay locked until the reveal phase ends.
+#[account]
+#[derive(Default)]
+pub struct BidRefund {
+    pub bump: u8,
+    // auction in which the funds are escrowed
+    pub auction: Pubkey,
+    // bidder to which the escrowed funds belong
+    pub bidder: Pubkey,
+    // total amount escrowed by the bidder that has not yet been claimed
+    pub amount: u64,
+    // ceiling of the bidder's most recent max bid; only meaningful while the auction's
+    // proxy_bidder is this bidder.
+    pub max_amount: u64,
+    // sealed bid auctions only. hash of the bid amount, salt and bidder; zeroed if none.
+    pub commitment: [u8; 32],
+    // sealed bid auctions only. whether or not the committed bid has been revealed.
+    pub revealed: bool,
+}
+
+impl BidRefund {
+    pub fn is_initialized(&self) -> bool {
+        self.bidder != Pubkey::default()
+    }
+
+    pub fn init(&mut self, bump: u8, auction: Pubkey, bidder: Pubkey) {
+        self.bump = bump;
+        self.auction = auction;
+        self.bidder = bidder;
+        self.amount = 0;
+        self.max_amount = 0;
+        self.commitment = [0; 32];
+        self.revealed = false;
+    }
+
+    pub fn deposit(&mut self, amount: u64) -> ProgramResult {
+        self.amount = self
+            .amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+
+        Ok(())
+    }
+
+    pub fn set_max_amount(&mut self, max_amount: u64) {
+        self.max_amount = max_amount;
+    }
+
+    pub fn has_commitment(&self) -> bool {
+        self.commitment != [0; 32]
+    }
+
+    pub fn commit(&mut self, commitment: [u8; 32]) {
+        self.commitment = commitment;
+        self.revealed = false;
+    }
+
+    pub fn reveal(&mut self) {
+        self.revealed = true;
+    }
+
+    // sealed bids that were never revealed forfeit penalty basis points of the deposit. the
+    // commitment is cleared so that the penalty is only taken once.
+    pub fn take_non_reveal_penalty(&mut self, penalty: u64) -> Result<u64, ProgramError> {
+        if !self.has_commitment() || self.revealed {
+            return Ok(0);
+        }
+
+        let penalty_amount = self
+            .amount
+            .checked_mul(penalty)
+            .ok_or(ErrorCode::NumericalOverflowError)?
+            .checked_div(BASIS_POINTS_DENOMINATOR)
+            .ok_or(ErrorCode::NumericalDivisionError)?;
+
+        self.commitment = [0; 32];
+        self.withdraw(penalty_amount)?;
+
+        Ok(penalty_amount)
+    }
+
+    // the leading bid, or any winning bid of a multi-winner auction, stays locked in the auction
+    // until settlement, as does the full ceiling of an active proxy bid. everything else is claimable,
+    // as is everything once the auction is cancelled or voided.
+    pub fn claimable_amount(&self, auction: &Auction) -> Result<u64, ProgramError> {
+        if auction.is_void() {
+            return Ok(self.amount);
+        }
+
+        if auction.auction_type == AuctionType::SealedBid
+            && get_current_timestamp()? < auction.settleable_at()
+        {
+            return Ok(0);
+        }
+
+        // any bid could turn out to be the winning candle bid, so nothing is claimable until settled
+        if auction.auction_type == AuctionType::Candle && !auction.settled {
+            return Ok(0);
+        }
+
+        let locked_amount = if auction.proxy_bidder == Some(self.bidder) {
+            std::cmp::max(auction.amount, self.max_amount)
+        } else {
+            auction.winning_amount_for(self.bidder)
+        };
+
+        let claimable_amount = self
+            .amount
+            .checked_sub(locked_amount)
+            .ok_or(ErrorCode::NumericalUnderflowError)?;
+
+        Ok(claimable_amount)
+    }
+
+    pub fn withdraw(&mut self, amount: u64) -> ProgramResult {
+        self.amount = self
+            .amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::NumericalUnderflowError)?;
+
+        Ok(())
+    }
+}
+
+// bid refund account struct sizing for account init
+pub const BID_REFUND_ACCOUNT_SPACE: usize =
+    // discriminator
+    8 +
+    // bump
+    1 +
+    // auction
+    32 +
+    // bidder
+    32 +
+    // amount
+    8 +
+    // max_amount
+    8 +
+    // commitment
+    32 +
+    // revealed
+    1;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bid_refund(bidder: Pubkey, amount: u64) -> BidRefund {
+        BidRefund {
+            bidder,
+            amount,
+            ..BidRefund::default()
+        }
+    }
+
+    fn auction(leader: Pubkey, amount: u64) -> Auction {
+        Auction {
+            auction_type: AuctionType::English,
+            num_winners: 1,
+            bidder: leader,
+            amount,
+            ..Auction::default()
+        }
+    }
+
+    #[test]
+    fn leading_bid_stays_locked() {
+        let leader = Pubkey::new_unique();
+        let auction = auction(leader, 100);
+
+        assert_eq!(
+            bid_refund(leader, 100).claimable_amount(&auction).unwrap(),
+            0
+        );
+        // earlier bids from the leader are claimable
+        assert_eq!(
+            bid_refund(leader, 150).claimable_amount(&auction).unwrap(),
+            50
+        );
+        assert_eq!(
+            bid_refund(Pubkey::new_unique(), 80)
+                .claimable_amount(&auction)
+                .unwrap(),
+            80
+        );
+    }
+
+    #[test]
+    fn proxy_ceiling_stays_locked() {
+        let leader = Pubkey::new_unique();
+        let mut auction = auction(leader, 100);
+        auction.proxy_bidder = Some(leader);
+
+        let mut refund = bid_refund(leader, 350);
+        refund.set_max_amount(300);
+
+        assert_eq!(refund.claimable_amount(&auction).unwrap(), 50);
+    }
+
+    #[test]
+    fn void_auction_releases_everything() {
+        let leader = Pubkey::new_unique();
+        let mut auction = auction(leader, 100);
+        auction.cancelled = true;
+
+        assert_eq!(
+            bid_refund(leader, 100).claimable_amount(&auction).unwrap(),
+            100
+        );
+    }
+
+    #[test]
+    fn unsettled_candle_auction_locks_everything() {
+        let mut auction = auction(Pubkey::new_unique(), 100);
+        auction.auction_type = AuctionType::Candle;
+
+        assert_eq!(
+            bid_refund(Pubkey::new_unique(), 80)
+                .claimable_amount(&auction)
+                .unwrap(),
+            0
+        );
+
+        auction.settled = true;
+        assert_eq!(
+            bid_refund(Pubkey::new_unique(), 80)
+                .claimable_amount(&auction)
+                .unwrap(),
+            80
+        );
+    }
+}
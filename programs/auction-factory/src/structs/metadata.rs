@@ -1,4 +1,4 @@
-use mpl_token_metadata::state::{Creator, Collection, Uses};
+use mpl_token_metadata::state::{Collection, Creator, Uses};
 
 pub struct MetadataInfo {
     pub name: String,
@@ -9,5 +9,5 @@ pub struct MetadataInfo {
     pub update_authority_is_signer: bool,
     pub is_mutable: bool,
     pub collection: Option<Collection>,
-    pub uses: Option<Uses>
+    pub uses: Option<Uses>,
 }
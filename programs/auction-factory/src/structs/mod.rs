@@ -1,4 +1,7 @@
 pub mod auction;
 pub mod auction_factory;
-pub mod metadata;
+pub mod auction_summary;
+pub mod bid_receipt;
+pub mod bid_refund;
 pub mod config;
+pub mod metadata;
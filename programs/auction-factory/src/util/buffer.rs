@@ -1,21 +1,20 @@
 use {
     crate::{constant::MAX_URI_LENGTH, error::ErrorCode},
     solana_program::msg,
-    std::convert::TryInto,
 };
 
 pub fn get_valid_element(
     idx: usize,
-    config_data: &Vec<String>,
+    config_data: &[String],
 ) -> std::result::Result<String, ErrorCode> {
     let element: &str = config_data.get(idx).unwrap();
 
-    if element.len() == 0 {
-        return Err(ErrorCode::ConfigElementTooShortError.into());
+    if element.is_empty() {
+        return Err(ErrorCode::ConfigElementTooShortError);
     }
 
     if element.len() > MAX_URI_LENGTH {
-        return Err(ErrorCode::ConfigElementTooLongError.into());
+        return Err(ErrorCode::ConfigElementTooLongError);
     }
 
     Ok(element.to_string())
@@ -25,7 +24,7 @@ pub fn add_to_circular_buffer(
     seq: usize,
     buffer: &mut Vec<String>,
     max_buffer_len: usize,
-    config_data: &Vec<String>,
+    config_data: &[String],
     last_updated_idx: &mut usize,
     is_updated: bool,
 ) -> std::result::Result<bool, ErrorCode> {
@@ -66,7 +65,7 @@ pub fn add_to_circular_buffer(
                 break;
             }
 
-            let element = get_valid_element(config_idx, &config_data)?;
+            let element = get_valid_element(config_idx, config_data)?;
 
             if should_append {
                 buffer.push(element);
@@ -105,7 +104,7 @@ pub fn add_to_circular_buffer(
 }
 
 pub fn get_item(
-    buffer: &Vec<String>,
+    buffer: &[String],
     max_supply: usize,
     sequence: usize,
     update_idx: usize,
@@ -118,7 +117,7 @@ pub fn get_item(
     // uri vec isn't full yet. check that we have enough uri's to get a new uri.
     if buffer.len() < max_supply {
         if idx >= buffer.len() {
-            return Err(ErrorCode::InsufficientConfigError.into());
+            return Err(ErrorCode::InsufficientConfigError);
         }
     } else {
         // edge case is first auction when buffer is full (aka update_idx == 0)
@@ -126,9 +125,9 @@ pub fn get_item(
             // no matter what the leading pointer is, we never want to the idx
             // (adjusted seq) to exceed the update_idx adjusted for 0 indexed offset.
             // let adj_update_idx = if update_idx == 0 { 0 } else { update_idx - 1 };
-            if idx == update_idx.try_into().unwrap() && !is_updated {
+            if idx == update_idx && !is_updated {
                 msg!("update_idx == idx");
-                return Err(ErrorCode::InsufficientConfigError.into());
+                return Err(ErrorCode::InsufficientConfigError);
             }
         }
     }
@@ -186,10 +185,10 @@ fn get_bounds(
     }
 
     // else
-    return Ok(Bounds {
+    Ok(Bounds {
         lower: adj_sequence,
         upper: adj_update_idx,
         can_wrap: adj_sequence != adj_update_idx,
         append: false,
-    });
+    })
 }
@@ -10,6 +10,7 @@ use {
         program_error::ProgramError,
         program_pack::{IsInitialized, Pack},
         pubkey::Pubkey,
+        sysvar,
     },
     std::convert::TryInto,
     std::str::FromStr,
@@ -23,7 +24,7 @@ pub fn get_auction_account_address(sequence: u64, auction_factory: Pubkey) -> (P
         seq_seed.as_bytes(),
     ];
     let program_id: Pubkey = Pubkey::from_str(AUX_FAX_PROGRAM_ID).unwrap();
-    return Pubkey::find_program_address(seeds, &program_id);
+    Pubkey::find_program_address(seeds, &program_id)
 }
 
 pub fn get_current_timestamp() -> Result<u64, ProgramError> {
@@ -31,6 +32,54 @@ pub fn get_current_timestamp() -> Result<u64, ProgramError> {
     Ok(clock::Clock::get()?.unix_timestamp.try_into().unwrap())
 }
 
+// the SlotHashes sysvar is too large to deserialize on-chain, so it is read directly: entry count
+// (u64), then (slot (u64), hash ([u8; 32])) entries, most recent first.
+const SLOT_HASH_ENTRY_LEN: usize = 40;
+
+// random value from the hash of the first slot at or after target_slot. skipped slots have no hash,
+// so the next produced slot stands in for them. the sysvar must still reach back to target_slot,
+// otherwise the first candidate may already have been dropped & a later hash could be picked.
+pub fn get_random_value_from_slot_hashes(
+    slot_hashes: &AccountInfo,
+    target_slot: u64,
+) -> Result<u64, ProgramError> {
+    if *slot_hashes.key != sysvar::slot_hashes::id() {
+        return Err(ErrorCode::PublicKeyMismatch.into());
+    }
+
+    let data = slot_hashes.try_borrow_data()?;
+    get_slot_hash_value(&data, target_slot)
+}
+
+fn get_slot_hash_value(data: &[u8], target_slot: u64) -> Result<u64, ProgramError> {
+    let count = data.get(0..8).ok_or(ErrorCode::InvalidSlotHashes)?;
+    let count = u64::from_le_bytes(count.try_into().unwrap()) as usize;
+
+    let mut value = None;
+    let mut oldest_slot = u64::MAX;
+    for index in 0..count {
+        let start = 8 + index * SLOT_HASH_ENTRY_LEN;
+        let entry = data
+            .get(start..start + SLOT_HASH_ENTRY_LEN)
+            .ok_or(ErrorCode::InvalidSlotHashes)?;
+        let slot = u64::from_le_bytes(entry[0..8].try_into().unwrap());
+
+        oldest_slot = slot;
+        if slot >= target_slot {
+            value = Some(u64::from_le_bytes(entry[8..16].try_into().unwrap()));
+        }
+        if slot <= target_slot {
+            break;
+        }
+    }
+
+    match value {
+        None => Err(ErrorCode::CandleCloseSlotNotReached.into()),
+        Some(_) if oldest_slot > target_slot => Err(ErrorCode::CandleCloseSlotExpired.into()),
+        Some(value) => Ok(value),
+    }
+}
+
 pub fn get_lamports_for_rent(account: &AccountInfo<'_>) -> Result<u64, ProgramError> {
     Ok(Rent::get()?.minimum_balance(account.data_len()))
 }
@@ -63,3 +112,48 @@ pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot_hashes_data(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            // derive the hash from the slot, so that the picked entry is easy to tell
+            let mut hash = [0u8; 32];
+            hash[0..8].copy_from_slice(&(slot * 1000).to_le_bytes());
+            data.extend_from_slice(&hash);
+        }
+        data
+    }
+
+    #[test]
+    fn slot_hash_value_uses_first_slot_at_or_after_target() {
+        // slot 13 was skipped
+        let data = slot_hashes_data(&[16, 15, 14, 12, 11]);
+
+        assert_eq!(get_slot_hash_value(&data, 12).unwrap(), 12_000);
+        assert_eq!(get_slot_hash_value(&data, 13).unwrap(), 14_000);
+        assert_eq!(get_slot_hash_value(&data, 16).unwrap(), 16_000);
+    }
+
+    #[test]
+    fn slot_hash_value_requires_target_within_slot_hashes() {
+        let data = slot_hashes_data(&[16, 15, 14, 12, 11]);
+
+        assert_eq!(
+            get_slot_hash_value(&data, 17).unwrap_err(),
+            ProgramError::from(ErrorCode::CandleCloseSlotNotReached)
+        );
+        assert_eq!(
+            get_slot_hash_value(&data, 10).unwrap_err(),
+            ProgramError::from(ErrorCode::CandleCloseSlotExpired)
+        );
+        assert_eq!(
+            get_slot_hash_value(&[], 10).unwrap_err(),
+            ProgramError::from(ErrorCode::InvalidSlotHashes)
+        );
+    }
+}
@@ -1,4 +1,4 @@
-pub mod general;
 pub mod buffer;
+pub mod general;
+pub mod metadata;
 pub mod vec;
-pub mod metadata;
\ No newline at end of file
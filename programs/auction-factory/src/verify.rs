@@ -1,16 +1,23 @@
 use {
-    anchor_lang::prelude::*,
-    std::str::FromStr,
     crate::{
-        SPL_ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+        constant::{
+            AUCTION_FACTORY_SEED_LEN, BASIS_POINTS_DENOMINATOR, CONFIG_SEED_LEN,
+            MAX_REVENUE_RECIPIENTS, MAX_WINNERS_PER_AUCTION,
+        },
         error::ErrorCode,
         structs::auction::Auction,
-        structs::auction_factory::AuctionFactory,
+        structs::auction_factory::{
+            AuctionFactory, AuctionFactoryChange, AuctionFactoryData, AuctionFactoryRole,
+            AuctionType, PriceCurve, RevenueShare, UnsoldResourcePolicy,
+        },
         util::general::{
-            assert_initialized, assert_owned_by, get_auction_account_address, get_current_timestamp,
+            assert_initialized, assert_owned_by, get_auction_account_address,
+            get_current_timestamp, get_random_value_from_slot_hashes,
         },
-        constant::{AUCTION_FACTORY_SEED_LEN, CONFIG_SEED_LEN}
-    }
+        SPL_ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID,
+    },
+    anchor_lang::{prelude::*, solana_program::hash::hashv},
+    std::str::FromStr,
 };
 
 pub fn verify_auction_address_for_factory(
@@ -30,18 +37,21 @@ pub fn verify_auction_address_for_factory(
 
 pub fn verify_auction_has_resource(auction: &Account<Auction>) -> ProgramResult {
     match auction.resource {
-        None => {
-            return Err(ErrorCode::AuctionHasNoResourceAvailable.into());
-        }
-        Some(_) => Ok(())
+        None => Err(ErrorCode::AuctionHasNoResourceAvailable.into()),
+        Some(_) => Ok(()),
     }
 }
 
 pub fn verify_current_auction_is_over(auction: &Account<Auction>) -> ProgramResult {
     let current_timestamp: u64 = get_current_timestamp().unwrap();
 
+    // cancelled & voided auctions are over immediately
+    if auction.is_void() {
+        return Ok(());
+    }
+
     // if not settled, auction is live. must be settled before creating a new auction.
-    if !auction.settled || current_timestamp < auction.end_time {
+    if !auction.settled || current_timestamp < auction.settleable_at() {
         return Err(ErrorCode::UnsettledAuction.into());
     }
 
@@ -53,21 +63,153 @@ pub fn verify_auction_can_be_settled(auction: &Account<Auction>) -> ProgramResul
         return Err(ErrorCode::AuctionAlreadySettled.into());
     }
 
+    if auction.is_void() {
+        return Err(ErrorCode::AuctionVoid.into());
+    }
+
     let current_timestamp: u64 = get_current_timestamp().unwrap();
 
-    if current_timestamp < auction.end_time {
+    if current_timestamp < auction.settleable_at() {
         return Err(ErrorCode::AuctionIsLive.into());
     }
 
     Ok(())
 }
 
+// the close slot can only be requested once bidding is over. it can be requested again once its
+// hash has dropped out of the slot hashes without the auction being settled.
+pub fn verify_candle_close_can_be_requested(
+    auction: &Account<Auction>,
+    slot_hashes: &AccountInfo,
+) -> ProgramResult {
+    if auction.auction_type != AuctionType::Candle {
+        return Err(ErrorCode::WrongAuctionType.into());
+    }
+
+    verify_auction_can_be_settled(auction)?;
+
+    if auction.candle_close_slot == 0 {
+        return Ok(());
+    }
+
+    match get_random_value_from_slot_hashes(slot_hashes, auction.candle_close_slot) {
+        Err(err) if err == ProgramError::from(ErrorCode::CandleCloseSlotExpired) => Ok(()),
+        _ => Err(ErrorCode::CandleCloseAlreadyRequested.into()),
+    }
+}
+
+// an auction that ended belongs to its winner, so only auctions still taking bids can be cancelled.
+// sealed bid auctions stop taking bids at end_time, so they cannot be cancelled during or after their
+// reveal phase either. voided auctions can still be cancelled, so that their resource can be burned
+// or recovered.
+pub fn verify_auction_can_be_cancelled(auction: &Account<Auction>) -> ProgramResult {
+    if auction.settled {
+        return Err(ErrorCode::AuctionAlreadySettled.into());
+    }
+
+    if auction.cancelled {
+        return Err(ErrorCode::AuctionCancelled.into());
+    }
+
+    if auction.voided {
+        return Ok(());
+    }
+
+    let current_timestamp: u64 = get_current_timestamp().unwrap();
+    if current_timestamp > auction.end_time || auction.has_closed() {
+        return Err(ErrorCode::AuctionEndedCannotBeCancelled.into());
+    }
+
+    Ok(())
+}
+
+// auctions that could have been settled, but were not within the settlement grace period
+pub fn verify_auction_is_stuck(
+    auction: &Account<Auction>,
+    factory_data: &AuctionFactoryData,
+) -> ProgramResult {
+    if auction.settled {
+        return Err(ErrorCode::AuctionAlreadySettled.into());
+    }
+
+    let settlement_grace_period = factory_data
+        .settlement_grace_period
+        .ok_or(ErrorCode::NoSettlementGracePeriod)?;
+    let reclaimable_at = auction
+        .settleable_at()
+        .checked_add(settlement_grace_period)
+        .ok_or(ErrorCode::NumericalOverflowError)?;
+
+    let current_timestamp: u64 = get_current_timestamp().unwrap();
+
+    if current_timestamp < reclaimable_at {
+        return Err(ErrorCode::SettlementGracePeriodActive.into());
+    }
+
+    Ok(())
+}
+
+pub fn verify_auction_is_settled(auction: &Account<Auction>) -> ProgramResult {
+    if !auction.settled {
+        return Err(ErrorCode::AuctionNotSettled.into());
+    }
+
+    Ok(())
+}
+
+// closed auctions can no longer be read, so closing must not strand any funds or resources
+pub fn verify_auction_can_be_closed(
+    auction_factory: &Account<AuctionFactory>,
+    auction: &Account<Auction>,
+) -> ProgramResult {
+    // the next auction is created from the current auction
+    if auction.sequence >= auction_factory.sequence {
+        return Err(ErrorCode::CurrentAuctionCannotBeClosed.into());
+    }
+
+    if !auction.settled && !auction.is_void() {
+        return Err(ErrorCode::AuctionNotSettled.into());
+    }
+
+    // a rolled over resource's token account belongs to the next auction
+    if auction.resource.is_some()
+        && !auction.token_account_closed
+        && auction.get_rolled_over_resource().is_none()
+    {
+        return Err(ErrorCode::AuctionTokenAccountNotClosed.into());
+    }
+
+    Ok(())
+}
+
+// every bid must have been paid out or claimed
+pub fn verify_auction_escrow_is_empty(auction: &Account<Auction>) -> ProgramResult {
+    if auction.escrowed_amount > 0 {
+        return Err(ErrorCode::AuctionHasEscrowedFunds.into());
+    }
+
+    Ok(())
+}
+
+// every bid refund account must have been closed, their rent cannot be recovered once the auction
+// is gone
+pub fn verify_auction_bid_refunds_are_closed(auction: &Account<Auction>) -> ProgramResult {
+    if auction.open_bid_refunds > 0 {
+        return Err(ErrorCode::AuctionHasOpenBidRefunds.into());
+    }
+
+    Ok(())
+}
+
 pub fn verify_auction_resource_dne(auction: &Account<Auction>) -> ProgramResult {
+    // auctions following an unsold auction under the roll over policy are created with a resource
+    if auction.resource_rolled_over {
+        return Err(ErrorCode::AuctionResourceRolledOver.into());
+    }
+
     match auction.resource {
         None => Ok(()),
-        Some(_) => {
-            return Err(ErrorCode::AuctionResourceAlreadyExists.into());
-        }
+        Some(_) => Err(ErrorCode::AuctionResourceAlreadyExists.into()),
     }
 }
 
@@ -114,35 +256,100 @@ pub fn verify_auction_factory_authority(
     Ok(())
 }
 
+pub fn verify_auction_factory_role(
+    auction_factory: &Account<AuctionFactory>,
+    role: AuctionFactoryRole,
+    signer: Pubkey,
+) -> ProgramResult {
+    if !auction_factory.has_role(role, signer) {
+        return Err(ErrorCode::NotAuthorized.into());
+    }
+
+    Ok(())
+}
+
+pub fn verify_pending_change_can_be_executed(
+    auction_factory: &Account<AuctionFactory>,
+    change: AuctionFactoryChange,
+) -> ProgramResult {
+    let execute_after = auction_factory
+        .get_pending_change_execute_after(change)
+        .ok_or(ErrorCode::NoPendingChange)?;
+
+    let current_timestamp: u64 = get_current_timestamp().unwrap();
+
+    if current_timestamp < execute_after {
+        return Err(ErrorCode::PendingChangeTimelocked.into());
+    }
+
+    Ok(())
+}
+
+pub fn verify_pending_authority(
+    auction_factory: &Account<AuctionFactory>,
+    signer: Pubkey,
+) -> ProgramResult {
+    let pending_authority = auction_factory
+        .pending_authority
+        .ok_or(ErrorCode::NoPendingAuthority)?;
+
+    if pending_authority != signer {
+        return Err(ErrorCode::PendingAuthorityMismatch.into());
+    }
+
+    Ok(())
+}
+
+// smallest bid that would beat the current bid. the first bid only has to meet the reserve price;
+// every later bid has to beat the current bid by the largest of
+// > min_bid_percentage_increase, in basis points of the current bid
+// > min_bid_increment, an absolute floor
+// > the increment of the highest tier whose threshold the current bid has reached
+// and by at least 1 unit.
+pub fn get_minimum_bid_amount(
+    original: u64,
+    factory_data: &AuctionFactoryData,
+) -> Result<u64, ProgramError> {
+    if original == 0 {
+        return Ok(std::cmp::max(factory_data.min_reserve_price, 1));
+    }
+
+    let percentage_increment = original
+        .checked_mul(factory_data.min_bid_percentage_increase)
+        .ok_or(ErrorCode::NumericalOverflowError)?
+        .checked_div(BASIS_POINTS_DENOMINATOR)
+        .ok_or(ErrorCode::NumericalDivisionError)?;
+
+    let tier_increment = factory_data
+        .bid_increment_tiers
+        .iter()
+        .filter(|tier| tier.increment > 0 && original >= tier.threshold)
+        .max_by_key(|tier| tier.threshold)
+        .map_or(0, |tier| tier.increment);
+
+    let increment = percentage_increment
+        .max(factory_data.min_bid_increment)
+        .max(tier_increment)
+        .max(1);
+
+    let minimum_bid = original
+        .checked_add(increment)
+        .ok_or(ErrorCode::NumericalOverflowError)?;
+
+    Ok(std::cmp::max(minimum_bid, factory_data.min_reserve_price))
+}
+
 pub fn verify_bid_amount(
     new: u64,
     original: u64,
-    min_increase_percentage: u64,
-    min_reserve_price: u64,
+    factory_data: &AuctionFactoryData,
 ) -> ProgramResult {
     // immediately reject bids lower than min reserve price, or equal to 0
-    if new < min_reserve_price || new == 0 {
+    if new < factory_data.min_reserve_price || new == 0 {
         return Err(ErrorCode::InvalidBidAmount.into());
     }
 
-    let mut minimum_bid = original
-        .checked_add(
-            original
-                .checked_mul(min_increase_percentage)
-                .ok_or(ErrorCode::NumericalOverflowError)?
-                .checked_div(100)
-                .ok_or(ErrorCode::NumericalDivisionError)?
-        )
-        .ok_or(ErrorCode::NumericalOverflowError)?;
-
-    // if bid amount is small enough, it's that min increase is going to be 0
-    // and thus, original = minimum bid. in this case, set minimum bid to at
-    // least 1 unit more than current bid.
-    if minimum_bid == original {
-        minimum_bid = original
-            .checked_add(1)
-            .ok_or(ErrorCode::NumericalOverflowError)?;
-    }
+    let minimum_bid = get_minimum_bid_amount(original, factory_data)?;
 
     if new < minimum_bid {
         return Err(ErrorCode::InvalidBidAmount.into());
@@ -151,6 +358,24 @@ pub fn verify_bid_amount(
     Ok(())
 }
 
+// bids are escrowed in SOL or in the auction's SPL token
+pub fn verify_bidder_can_cover_bid(
+    auction: &Account<Auction>,
+    bidder: AccountInfo,
+    bidder_token_account: AccountInfo,
+    amount: u64,
+) -> ProgramResult {
+    match auction.token_mint {
+        Some(token_mint) => verify_bidder_has_sufficient_token_balance(
+            bidder_token_account,
+            bidder.key(),
+            token_mint,
+            amount,
+        ),
+        None => verify_bidder_has_sufficient_account_balance(bidder, amount),
+    }
+}
+
 pub fn verify_bidder_has_sufficient_account_balance(
     bidder: AccountInfo,
     amount: u64,
@@ -162,98 +387,360 @@ pub fn verify_bidder_has_sufficient_account_balance(
     Ok(())
 }
 
-// looks at both the amount and auction state
-pub fn verify_bid_for_auction(
-    auction_factory: &Account<AuctionFactory>,
-    auction: &Account<Auction>,
+pub fn verify_bidder_has_sufficient_token_balance(
+    bidder_token_account: AccountInfo,
+    bidder: Pubkey,
+    mint: Pubkey,
     amount: u64,
 ) -> ProgramResult {
+    let token_account = verify_token_account(&bidder_token_account, mint, bidder)?;
+
+    if token_account.amount < amount {
+        return Err(ErrorCode::InsufficientAccountBalance.into());
+    }
+
+    Ok(())
+}
+
+pub fn verify_auction_is_live(auction: &Account<Auction>) -> ProgramResult {
     let current_timestamp: u64 = get_current_timestamp().unwrap();
 
     let auction_has_not_started = current_timestamp < auction.start_time;
     let auction_past_end_time = current_timestamp > auction.end_time;
 
-    if auction.settled || auction_has_not_started || auction_past_end_time {
+    if auction.has_closed() || auction_has_not_started || auction_past_end_time {
         return Err(ErrorCode::InactiveAuction.into());
     }
 
-    verify_bid_amount(
-        amount,
-        auction.amount,
-        auction_factory.data.min_bid_percentage_increase,
-        auction_factory.data.min_reserve_price,
-    )?;
+    Ok(())
+}
+
+pub fn verify_auction_type(auction: &Account<Auction>, auction_type: AuctionType) -> ProgramResult {
+    if auction.auction_type != auction_type {
+        return Err(ErrorCode::WrongAuctionType.into());
+    }
+
+    Ok(())
+}
+
+// sealed bids are revealed after bidding ends, until the reveal phase ends
+pub fn verify_sealed_bid_reveal_phase(auction: &Account<Auction>) -> ProgramResult {
+    verify_auction_type(auction, AuctionType::SealedBid)?;
+
+    let current_timestamp: u64 = get_current_timestamp().unwrap();
+
+    let reveal_has_not_started = current_timestamp <= auction.end_time;
+    let reveal_has_ended = current_timestamp > auction.reveal_end_time;
+
+    if auction.has_closed() || reveal_has_not_started || reveal_has_ended {
+        return Err(ErrorCode::InactiveAuction.into());
+    }
+
+    Ok(())
+}
+
+// commitment = sha256(amount as little endian bytes || salt || bidder)
+pub fn verify_sealed_bid_commitment(
+    commitment: [u8; 32],
+    amount: u64,
+    salt: [u8; 32],
+    bidder: Pubkey,
+) -> ProgramResult {
+    let computed_commitment = hashv(&[&amount.to_le_bytes(), &salt, bidder.as_ref()]);
+
+    if computed_commitment.to_bytes() != commitment {
+        return Err(ErrorCode::SealedBidCommitmentMismatch.into());
+    }
+
+    Ok(())
+}
+
+// looks at both the amount and auction state
+pub fn verify_bid_for_auction(
+    auction_factory: &Account<AuctionFactory>,
+    auction: &Account<Auction>,
+    amount: u64,
+) -> ProgramResult {
+    // english & candle auctions accept open bids
+    if auction.auction_type != AuctionType::English && auction.auction_type != AuctionType::Candle {
+        return Err(ErrorCode::WrongAuctionType.into());
+    }
+    verify_auction_is_live(auction)?;
+
+    verify_bid_amount(amount, auction.amount_to_beat(), &auction_factory.data)?;
 
     Ok(())
 }
 
 pub fn verify_bidder_not_already_winning(
-    winning_bidder: Pubkey,
+    auction: &Account<Auction>,
     new_bidder: Pubkey,
 ) -> ProgramResult {
-    if winning_bidder == new_bidder {
+    if auction.is_winning_bidder(new_bidder) {
         return Err(ErrorCode::BidderAlreadyWinning.into());
     }
 
     Ok(())
 }
 
-pub fn get_token_mint_account(
-    owner: Pubkey,
-    mint: Pubkey,
-    bump: u8,
-) -> Pubkey {
+// raises & max bids only make sense against a single leading bid
+pub fn verify_single_winner_auction(auction: &Account<Auction>) -> ProgramResult {
+    if auction.is_multi_winner() {
+        return Err(ErrorCode::MultiWinnerAuctionNotSupported.into());
+    }
+
+    Ok(())
+}
+
+pub fn verify_multi_winner_auction(auction: &Account<Auction>) -> ProgramResult {
+    if !auction.is_multi_winner() {
+        return Err(ErrorCode::SingleWinnerAuction.into());
+    }
+
+    Ok(())
+}
+
+pub fn verify_unsold_resource_recipient(
+    auction_factory: &Account<AuctionFactory>,
+    recipient: Pubkey,
+) -> ProgramResult {
+    if auction_factory.get_unsold_resource_recipient() != recipient {
+        return Err(ErrorCode::UnsoldResourceRecipientMismatch.into());
+    }
+
+    Ok(())
+}
+
+// a bid mint treasury is a token account, which cannot hold unsold resources
+pub fn verify_unsold_resource_recipient_for_bid_mint(
+    data: &AuctionFactoryData,
+    bid_mint: Option<Pubkey>,
+) -> ProgramResult {
+    if bid_mint.is_some()
+        && data.unsold_resource_policy == UnsoldResourcePolicy::Transfer
+        && data.unsold_resource_recipient.is_none()
+    {
+        return Err(ErrorCode::UnsoldResourceRecipientRequired.into());
+    }
+
+    Ok(())
+}
+
+pub fn verify_bidder_is_winning(winning_bidder: Pubkey, bidder: Pubkey) -> ProgramResult {
+    if winning_bidder != bidder {
+        return Err(ErrorCode::BidderNotWinning.into());
+    }
+
+    Ok(())
+}
+
+pub fn get_associated_token_address(owner: Pubkey, mint: Pubkey) -> Pubkey {
     let associated_token_program_id =
         Pubkey::from_str(SPL_ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID).unwrap();
     let spl_token_address = spl_token::id();
 
-    let seeds = &[
-        owner.as_ref(),
-        spl_token_address.as_ref(),
-        mint.as_ref(),
-        &[bump],
-    ];
+    let seeds = &[owner.as_ref(), spl_token_address.as_ref(), mint.as_ref()];
+
+    let (address, _bump) = Pubkey::find_program_address(seeds, &associated_token_program_id);
+    address
+}
+
+// assert that token account
+// > is the owner's associated token account for the mint
+// > is initialized & owned by the spl_token program
+pub fn verify_associated_token_account(
+    token_account: &AccountInfo,
+    owner: Pubkey,
+    mint: Pubkey,
+) -> Result<spl_token::state::Account, ProgramError> {
+    if token_account.key() != get_associated_token_address(owner, mint) {
+        return Err(ErrorCode::TokenAccountOwnerMismatch.into());
+    }
+
+    verify_token_account(token_account, mint, owner)
+}
 
-    return Pubkey::create_program_address(seeds, &associated_token_program_id).unwrap();
+// assert that the auction's bid escrow
+// > is the auction's associated token account for the bid mint
+// > is initialized, owned by the spl_token program & the auction
+pub fn verify_auction_bid_token_account(
+    auction_bid_token_account: &AccountInfo,
+    auction: &Account<Auction>,
+    token_mint: Pubkey,
+) -> Result<spl_token::state::Account, ProgramError> {
+    if auction_bid_token_account.key() != get_associated_token_address(auction.key(), token_mint) {
+        return Err(ErrorCode::AuctionBidTokenAccountMismatch.into());
+    }
+
+    verify_token_account(auction_bid_token_account, token_mint, auction.key())
 }
 
-// assert that bidder token account
+// assert that token account
 // > is initialized
 // > is owned by the spl_token program
-// > mint matches the auction's resource
-// > address matches the expected address
-pub fn verify_bidder_token_account(
-    bidder_token_account: AccountInfo,
-    auction: &Account<Auction>,
-    token_account_bump: u8,
-) -> ProgramResult {
-    let spl_token_address: Pubkey = spl_token::id();
+// > mint matches the expected mint
+// > token account owner matches the expected owner
+pub fn verify_token_account(
+    token_account_info: &AccountInfo,
+    mint: Pubkey,
+    owner: Pubkey,
+) -> Result<spl_token::state::Account, ProgramError> {
+    assert_owned_by(token_account_info, &spl_token::id())?;
+
+    let token_account: spl_token::state::Account = assert_initialized(token_account_info)?;
+    if token_account.mint != mint {
+        return Err(ErrorCode::MintMismatch.into());
+    }
+
+    if token_account.owner != owner {
+        return Err(ErrorCode::TokenAccountOwnerMismatch.into());
+    }
 
-    assert_owned_by(&bidder_token_account, &spl_token_address)?;
+    Ok(token_account)
+}
 
-    let token_account: spl_token::state::Account = assert_initialized(&bidder_token_account)?;
-    if let Some(auction_resource) = auction.resource {
-        if token_account.mint != auction_resource {
+// treasury of an auction factory with a bid mint must be a token account of that mint
+pub fn verify_treasury_for_bid_mint(
+    treasury: &AccountInfo,
+    bid_mint: Option<Pubkey>,
+) -> ProgramResult {
+    if let Some(mint) = bid_mint {
+        assert_owned_by(treasury, &spl_token::id())?;
+
+        let token_account: spl_token::state::Account = assert_initialized(treasury)?;
+        if token_account.mint != mint {
             return Err(ErrorCode::MintMismatch.into());
         }
+    }
 
-        let computed_token_account_pubkey = get_token_mint_account(
-            auction.bidder,
-            auction_resource,
-            token_account_bump
-        );
+    Ok(())
+}
 
-        if bidder_token_account.key() != computed_token_account_pubkey {
-            return Err(ErrorCode::TokenAccountNotOwnedByWinningBidder.into());
+pub fn verify_auction_factory_data(data: &AuctionFactoryData) -> ProgramResult {
+    if data.auction_type == AuctionType::Dutch {
+        // a sale at the floor price must still be a valid winning bid, an auction sold for 0 would
+        // be treated as unsold
+        let dutch_auction = &data.dutch_auction;
+        let invalid_price_range = dutch_auction.floor_price == 0
+            || dutch_auction.floor_price < data.min_reserve_price
+            || dutch_auction.start_price < dutch_auction.floor_price;
+        if invalid_price_range {
+            return Err(ErrorCode::InvalidAuctionFactoryData.into());
+        }
+
+        let invalid_exponential_curve = dutch_auction.decay_interval == 0
+            || dutch_auction.decay_rate > BASIS_POINTS_DENOMINATOR;
+        if dutch_auction.curve == PriceCurve::Exponential && invalid_exponential_curve {
+            return Err(ErrorCode::InvalidAuctionFactoryData.into());
         }
     }
 
+    if data.auction_type == AuctionType::SealedBid {
+        let sealed_bid = &data.sealed_bid;
+        let invalid_sealed_bid = sealed_bid.reveal_duration == 0
+            || sealed_bid.non_reveal_penalty > BASIS_POINTS_DENOMINATOR;
+        if invalid_sealed_bid {
+            return Err(ErrorCode::InvalidAuctionFactoryData.into());
+        }
+    }
+
+    if data.auction_type == AuctionType::Candle {
+        let ending_period = data.candle.ending_period;
+        if ending_period == 0 || ending_period > data.duration {
+            return Err(ErrorCode::InvalidAuctionFactoryData.into());
+        }
+
+        // the leading amount is picked retroactively, there is no reliable second highest bid
+        if data.second_price_settlement {
+            return Err(ErrorCode::InvalidAuctionFactoryData.into());
+        }
+    }
+
+    if data.crank_reward.winning_bid_share > BASIS_POINTS_DENOMINATOR {
+        return Err(ErrorCode::InvalidAuctionFactoryData.into());
+    }
+
+    if let Some(schedule) = data.schedule {
+        if schedule.interval == 0 || schedule.offset >= schedule.interval {
+            return Err(ErrorCode::InvalidAuctionFactoryData.into());
+        }
+    }
+
+    if let Some(buy_now_price) = data.buy_now_price {
+        // the buy it now bid has to be a valid bid, and wins against a single leading bid. it
+        // settles at the buy it now price, so it cannot be combined with second price settlement.
+        let invalid_buy_now_auction = data.auction_type != AuctionType::English
+            || data.num_winners > 1
+            || data.second_price_settlement;
+        if buy_now_price == 0 || buy_now_price < data.min_reserve_price || invalid_buy_now_auction {
+            return Err(ErrorCode::InvalidAuctionFactoryData.into());
+        }
+    }
+
+    if data.num_winners as usize > MAX_WINNERS_PER_AUCTION {
+        return Err(ErrorCode::InvalidAuctionFactoryData.into());
+    }
+
+    // multi-winner auctions rank open bids, and every winner pays their own bid
+    let invalid_multi_winner_auction =
+        data.auction_type != AuctionType::English || data.second_price_settlement;
+    if data.num_winners > 1 && invalid_multi_winner_auction {
+        return Err(ErrorCode::InvalidAuctionFactoryData.into());
+    }
+
     Ok(())
 }
 
-pub fn verify_auction_factory_seed(
-    seed: &str
+// an empty revenue split sends all revenue to the treasury
+pub fn verify_revenue_split(revenue_split: &[RevenueShare]) -> ProgramResult {
+    if revenue_split.len() > MAX_REVENUE_RECIPIENTS {
+        return Err(ErrorCode::InvalidRevenueSplit.into());
+    }
+
+    if revenue_split.is_empty() {
+        return Ok(());
+    }
+
+    let mut total_share: u64 = 0;
+    for revenue_share in revenue_split.iter() {
+        if revenue_share.share == 0 {
+            return Err(ErrorCode::InvalidRevenueSplit.into());
+        }
+
+        total_share = total_share
+            .checked_add(revenue_share.share)
+            .ok_or(ErrorCode::NumericalOverflowError)?;
+    }
+
+    if total_share != BASIS_POINTS_DENOMINATOR {
+        return Err(ErrorCode::InvalidRevenueSplit.into());
+    }
+
+    Ok(())
+}
+
+// recipient accounts are passed in revenue split order. like the treasury, a recipient must be a
+// token account for the bid mint if the auction factory takes bids in an SPL token.
+pub fn verify_revenue_recipients(
+    revenue_split: &[RevenueShare],
+    recipient_accounts: &[AccountInfo],
+    bid_mint: Option<Pubkey>,
 ) -> ProgramResult {
+    if recipient_accounts.len() < revenue_split.len() {
+        return Err(ErrorCode::RevenueRecipientMismatch.into());
+    }
+
+    for (revenue_share, recipient_account) in revenue_split.iter().zip(recipient_accounts.iter()) {
+        if recipient_account.key() != revenue_share.recipient {
+            return Err(ErrorCode::RevenueRecipientMismatch.into());
+        }
+        verify_treasury_for_bid_mint(recipient_account, bid_mint)?;
+    }
+
+    Ok(())
+}
+
+pub fn verify_auction_factory_seed(seed: &str) -> ProgramResult {
     if seed.len() != AUCTION_FACTORY_SEED_LEN {
         return Err(ErrorCode::AuctionFactoryUuidInvalidLengthError.into());
     }
@@ -261,12 +748,99 @@ pub fn verify_auction_factory_seed(
     Ok(())
 }
 
-pub fn verify_config_seed(
-    seed: &str
-) -> ProgramResult {
+pub fn verify_config_seed(seed: &str) -> ProgramResult {
     if seed.len() != CONFIG_SEED_LEN {
         return Err(ErrorCode::ConfigUuidInvalidLengthError.into());
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::structs::auction_factory::{BidIncrementTier, DutchAuctionData},
+    };
+
+    #[test]
+    fn first_bid_must_meet_reserve_price() {
+        let mut factory_data = AuctionFactoryData::default();
+        assert_eq!(get_minimum_bid_amount(0, &factory_data).unwrap(), 1);
+
+        factory_data.min_reserve_price = 50;
+        assert_eq!(get_minimum_bid_amount(0, &factory_data).unwrap(), 50);
+    }
+
+    #[test]
+    fn minimum_bid_uses_largest_increment() {
+        let mut factory_data = AuctionFactoryData {
+            min_bid_percentage_increase: 500,
+            ..AuctionFactoryData::default()
+        };
+        assert_eq!(get_minimum_bid_amount(1_000, &factory_data).unwrap(), 1_050);
+        // never less than 1 unit
+        assert_eq!(get_minimum_bid_amount(10, &factory_data).unwrap(), 11);
+
+        factory_data.min_bid_increment = 100;
+        assert_eq!(get_minimum_bid_amount(1_000, &factory_data).unwrap(), 1_100);
+
+        factory_data.bid_increment_tiers[0] = BidIncrementTier {
+            threshold: 0,
+            increment: 10,
+        };
+        factory_data.bid_increment_tiers[1] = BidIncrementTier {
+            threshold: 1_000,
+            increment: 200,
+        };
+        assert_eq!(get_minimum_bid_amount(999, &factory_data).unwrap(), 1_099);
+        assert_eq!(get_minimum_bid_amount(1_000, &factory_data).unwrap(), 1_200);
+    }
+
+    #[test]
+    fn minimum_bid_is_at_least_reserve_price() {
+        let factory_data = AuctionFactoryData {
+            min_reserve_price: 100,
+            ..AuctionFactoryData::default()
+        };
+
+        assert_eq!(get_minimum_bid_amount(10, &factory_data).unwrap(), 100);
+    }
+
+    #[test]
+    fn dutch_floor_price_must_be_a_valid_bid() {
+        let mut factory_data = AuctionFactoryData {
+            auction_type: AuctionType::Dutch,
+            min_reserve_price: 100,
+            dutch_auction: DutchAuctionData {
+                start_price: 1_000,
+                floor_price: 100,
+                ..DutchAuctionData::default()
+            },
+            ..AuctionFactoryData::default()
+        };
+        assert!(verify_auction_factory_data(&factory_data).is_ok());
+
+        factory_data.dutch_auction.floor_price = 99;
+        assert!(verify_auction_factory_data(&factory_data).is_err());
+
+        factory_data.min_reserve_price = 0;
+        factory_data.dutch_auction.floor_price = 0;
+        assert!(verify_auction_factory_data(&factory_data).is_err());
+
+        factory_data.dutch_auction.floor_price = 1_001;
+        assert!(verify_auction_factory_data(&factory_data).is_err());
+    }
+
+    #[test]
+    fn buy_now_price_excludes_second_price_settlement() {
+        let mut factory_data = AuctionFactoryData {
+            buy_now_price: Some(1_000),
+            ..AuctionFactoryData::default()
+        };
+        assert!(verify_auction_factory_data(&factory_data).is_ok());
+
+        factory_data.second_price_settlement = true;
+        assert!(verify_auction_factory_data(&factory_data).is_err());
+    }
+}
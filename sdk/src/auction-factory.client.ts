@@ -1,14 +1,22 @@
 import * as anchor from "@project-serum/anchor";
 import { Idl, Program, Provider, Wallet, BN } from "@project-serum/anchor";
 import {
+    AccountMeta,
     Connection,
     Keypair,
     PublicKey,
     SystemProgram,
     SYSVAR_RENT_PUBKEY,
+    SYSVAR_SLOT_HASHES_PUBKEY,
+    TransactionInstruction,
 } from "@solana/web3.js";
 import * as lodash from "lodash";
-import { TOKEN_PROGRAM_ID, Token, MintLayout } from "@solana/spl-token";
+import {
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+    TOKEN_PROGRAM_ID,
+    Token,
+    MintLayout,
+} from "@solana/spl-token";
 
 import { isBlank, getSignersFromPayer } from "./common/util";
 import { AuctionFactory as AuctionFactoryProgram } from "./types/auction_factory";
@@ -17,16 +25,30 @@ import {
     AUX_FAX_SEED,
     AUX_SEED,
     URI_CONFIG_SEED,
+    BID_REFUND_SEED,
+    BID_RECEIPT_SEED,
+    AUCTION_SUMMARY_SEED,
     TOKEN_METADATA_PROGRAM_ID,
 } from "./common";
 import {
     AuctionFactoryConfig,
     Config,
     AuctionFactoryData,
-    TokenAccount,
+    AuctionFactoryChange,
+    AuctionFactoryRole,
     AuctionPdaData,
+    RevenueShare,
 } from "./common/types";
-import { BN_ZERO, BN_ONE } from "./common/constant";
+import {
+    BN_ZERO,
+    BN_ONE,
+    MAX_BIDS_RECORDED_PER_INSTRUCTION,
+} from "./common/constant";
+
+interface BidTokenAccounts {
+    bidderTokenAccount: PublicKey;
+    auctionBidTokenAccount: PublicKey;
+}
 
 export class AuctionFactoryClient extends AccountUtils {
     wallet: anchor.Wallet;
@@ -102,6 +124,18 @@ export class AuctionFactoryClient extends AccountUtils {
         return this.program.account.config.fetch(config);
     };
 
+    fetchBidRefund = async (bidRefund: PublicKey) => {
+        return this.program.account.bidRefund.fetch(bidRefund);
+    };
+
+    fetchBidReceipt = async (bidReceipt: PublicKey) => {
+        return this.program.account.bidReceipt.fetch(bidReceipt);
+    };
+
+    fetchAuctionSummary = async (auctionSummary: PublicKey) => {
+        return this.program.account.auctionSummary.fetch(auctionSummary);
+    };
+
     // ============================================================================
     // find PDA accounts
     // ============================================================================
@@ -128,6 +162,29 @@ export class AuctionFactoryClient extends AccountUtils {
         ]);
     };
 
+    findBidRefundPda = async (auction: PublicKey, bidder: PublicKey) => {
+        return this.findProgramAddress(this.program.programId, [
+            BID_REFUND_SEED,
+            auction,
+            bidder,
+        ]);
+    };
+
+    findBidReceiptPda = async (auction: PublicKey, index: BN) => {
+        return this.findProgramAddress(this.program.programId, [
+            BID_RECEIPT_SEED,
+            auction,
+            index.toString(),
+        ]);
+    };
+
+    findAuctionSummaryPda = async (auction: PublicKey) => {
+        return this.findProgramAddress(this.program.programId, [
+            AUCTION_SUMMARY_SEED,
+            auction,
+        ]);
+    };
+
     fetchAuctionPdaData = async (sequence: BN): Promise<AuctionPdaData> => {
         const [addr, bump] = await this.findAuctionPda(
             sequence,
@@ -149,6 +206,27 @@ export class AuctionFactoryClient extends AccountUtils {
         return addr;
     };
 
+    // a resource rolled over from an unsold auction stays in the token account it was first
+    // supplied to, whose owner is handed over to each following auction
+    getAuctionTokenAccount = async (auction: PublicKey): Promise<PublicKey> => {
+        const auctionAccount = await this.fetchAuction(auction);
+        if (!auctionAccount.resourceRolledOver) {
+            const [auctionTokenAccount, _auctionTokenAccountBump] =
+                await this.getAssociatedTokenAccountAddress(
+                    auction,
+                    auctionAccount.resource
+                );
+            return auctionTokenAccount;
+        }
+
+        const tokenAccounts =
+            await this.program.provider.connection.getTokenAccountsByOwner(
+                auction,
+                { mint: auctionAccount.resource }
+            );
+        return tokenAccounts.value[0].pubkey;
+    };
+
     // ============================================================================
     // account balances
     // ============================================================================
@@ -163,6 +241,76 @@ export class AuctionFactoryClient extends AccountUtils {
         return await this.getBalance(this.auctionFactory.config.address);
     };
 
+    // ============================================================================
+    // bid accounts
+    // ============================================================================
+
+    // bids denominated in SOL move lamports directly, so the token accounts are unused. the owner
+    // is passed in their place since it is already writable in the transaction.
+    getBidTokenAccounts = async (
+        auction: PublicKey,
+        tokenMint: PublicKey | null,
+        owner: PublicKey
+    ): Promise<BidTokenAccounts> => {
+        if (!tokenMint) {
+            return {
+                bidderTokenAccount: owner,
+                auctionBidTokenAccount: owner,
+            };
+        }
+
+        const [bidderTokenAccount, _bidderTokenAccountBump] =
+            await this.getAssociatedTokenAccountAddress(owner, tokenMint);
+        const [auctionBidTokenAccount, _auctionBidTokenAccountBump] =
+            await this.getAssociatedTokenAccountAddress(auction, tokenMint);
+
+        return {
+            bidderTokenAccount,
+            auctionBidTokenAccount,
+        };
+    };
+
+    // the auction escrows SPL token bids in its associated token account, created by the first bidder
+    buildAuctionBidTokenAccountInstructions = async (
+        auction: PublicKey,
+        tokenMint: PublicKey | null,
+        auctionBidTokenAccount: PublicKey,
+        payer: PublicKey
+    ): Promise<TransactionInstruction[]> => {
+        if (!tokenMint) {
+            return [];
+        }
+
+        const accountInfo = await this.connection.getAccountInfo(
+            auctionBidTokenAccount
+        );
+        if (accountInfo) {
+            return [];
+        }
+
+        return [
+            this.createAssociatedTokenAccount(
+                tokenMint,
+                auctionBidTokenAccount,
+                auction, // owner
+                payer // payer
+            ),
+        ];
+    };
+
+    // revenue split recipients, in order, passed as remaining accounts to settle & buy
+    getRevenueRecipientAccounts = async (): Promise<AccountMeta[]> => {
+        const auctionFactory = await this.fetchAuctionFactory(
+            this.auctionFactory.config.address
+        );
+
+        return (auctionFactory.revenueSplit as any[]).map((revenueShare) => ({
+            pubkey: revenueShare.recipient,
+            isWritable: true,
+            isSigner: false,
+        }));
+    };
+
     // getAuctionBalance();
     // getAuctionFactoryBalance();
     // ============================================================================
@@ -220,7 +368,8 @@ export class AuctionFactoryClient extends AccountUtils {
         seed: string,
         config: AuctionFactoryData,
         treasury: PublicKey,
-        payer: PublicKey | Keypair
+        payer: PublicKey | Keypair,
+        bidMint?: PublicKey // bids are denominated in SOL if not provided
     ) => {
         const signerInfo = getSignersFromPayer(payer);
 
@@ -230,12 +379,8 @@ export class AuctionFactoryClient extends AccountUtils {
             seed,
             this.config.bump,
             this.config.seed,
-            {
-                duration: config.duration,
-                timeBuffer: config.timeBuffer,
-                minBidPercentageIncrease: config.minBidPercentageIncrease,
-                minReservePrice: config.minReservePrice,
-            },
+            bidMint ? bidMint : null,
+            config,
             {
                 accounts: {
                     auctionFactory,
@@ -268,6 +413,7 @@ export class AuctionFactoryClient extends AccountUtils {
         );
     };
 
+    // queued until the auction factory's change delay has passed, see executePendingChange
     modify = async (config: AuctionFactoryData, payer: PublicKey | Keypair) => {
         const signerInfo = getSignersFromPayer(payer);
 
@@ -286,6 +432,7 @@ export class AuctionFactoryClient extends AccountUtils {
         );
     };
 
+    // the treasury used by the client only changes once the pending change is executed
     updateTreasury = async (
         treasury: PublicKey,
         payer: PublicKey | Keypair
@@ -305,31 +452,188 @@ export class AuctionFactoryClient extends AccountUtils {
                 signers: signerInfo.signers,
             }
         );
+    };
+
+    // queued like other treasury changes. an empty split sends all revenue to the treasury.
+    // for auction factories with a bid mint, each recipient must be a token account for that mint.
+    updateRevenueSplit = async (
+        revenueSplit: RevenueShare[],
+        payer: PublicKey | Keypair
+    ) => {
+        const signerInfo = getSignersFromPayer(payer);
 
-        // update state config
-        this.updateAuctionFactoryDetails(
-            this.auctionFactory.config.address,
+        this.validateAuctionFactory();
+        await this.program.rpc.updateRevenueSplit(
             this.auctionFactory.config.bump,
             this.auctionFactory.config.seed,
-            treasury
+            revenueSplit,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                },
+                remainingAccounts: revenueSplit.map((revenueShare) => ({
+                    pubkey: revenueShare.recipient,
+                    isWritable: false,
+                    isSigner: false,
+                })),
+                signers: signerInfo.signers,
+            }
         );
     };
 
-    updateAuthority = async (
-        authority: PublicKey,
+    // permissionless, once the pending change's delay has passed
+    executePendingChange = async (
+        change: AuctionFactoryChange,
+        payer: PublicKey | Keypair
+    ) => {
+        const signerInfo = getSignersFromPayer(payer);
+
+        this.validateAuctionFactory();
+        await this.program.rpc.executePendingChange(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            change,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                },
+                signers: signerInfo.signers,
+            }
+        );
+
+        if ("treasury" in change) {
+            const auctionFactoryAccount = await this.fetchAuctionFactory(
+                this.auctionFactory.config.address
+            );
+
+            // update state config
+            this.updateAuctionFactoryDetails(
+                this.auctionFactory.config.address,
+                this.auctionFactory.config.bump,
+                this.auctionFactory.config.seed,
+                auctionFactoryAccount.treasury
+            );
+        }
+    };
+
+    // pending changes can be cancelled by the authority, or the role that queued them
+    cancelPendingChange = async (
+        change: AuctionFactoryChange,
         payer: PublicKey | Keypair
     ) => {
         const signerInfo = getSignersFromPayer(payer);
 
         this.validateAuctionFactory();
-        await this.program.rpc.updateAuthority(
+        await this.program.rpc.cancelPendingChange(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            change,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                },
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    // authority only. a role holder can take that role's admin actions alongside the authority.
+    grantRole = async (
+        role: AuctionFactoryRole,
+        holder: PublicKey,
+        payer: PublicKey | Keypair
+    ) => {
+        const signerInfo = getSignersFromPayer(payer);
+
+        this.validateAuctionFactory();
+        await this.program.rpc.grantRole(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            role,
+            holder,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                },
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    revokeRole = async (role: AuctionFactoryRole, payer: PublicKey | Keypair) => {
+        const signerInfo = getSignersFromPayer(payer);
+
+        this.validateAuctionFactory();
+        await this.program.rpc.revokeRole(
             this.auctionFactory.config.bump,
             this.auctionFactory.config.seed,
+            role,
             {
                 accounts: {
                     payer: signerInfo.payer,
                     auctionFactory: this.auctionFactory.config.address,
+                },
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    // authority changes are two-step: the current authority proposes, the new authority accepts
+    proposeAuthority = async (
+        authority: PublicKey,
+        payer: PublicKey | Keypair
+    ) => {
+        const signerInfo = getSignersFromPayer(payer);
+
+        this.validateAuctionFactory();
+        await this.program.rpc.proposeAuthority(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
                     newAuthority: authority,
+                    auctionFactory: this.auctionFactory.config.address,
+                },
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    acceptAuthority = async (
+        payer: PublicKey | Keypair // payer is the pending authority
+    ) => {
+        const signerInfo = getSignersFromPayer(payer);
+
+        this.validateAuctionFactory();
+        await this.program.rpc.acceptAuthority(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                },
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    cancelAuthorityTransfer = async (payer: PublicKey | Keypair) => {
+        const signerInfo = getSignersFromPayer(payer);
+
+        this.validateAuctionFactory();
+        await this.program.rpc.cancelAuthorityTransfer(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
                 },
                 signers: signerInfo.signers,
             }
@@ -546,81 +850,71 @@ export class AuctionFactoryClient extends AccountUtils {
     placeBid = async (
         sequence: BN,
         amount: BN,
-        payer: PublicKey | Keypair // payer is bidder
+        payer: PublicKey | Keypair, // payer is bidder
+        recordReceipts: boolean = false
     ) => {
         this.validateAuctionFactory();
 
         const signerInfo = getSignersFromPayer(payer);
-
-        const pdaData = await this.fetchAuctionPdaData(sequence);
-        const auction = await this.fetchAuction(pdaData.addr);
-        const auctionAmount: BN = auction.amount ? auction.amount : BN_ZERO;
-        const leadingBidder =
-            auction.bidder !== undefined && auctionAmount.gt(BN_ZERO)
-                ? auction.bidder
-                : signerInfo.payer;
+        const bidAccounts = await this.buildBidAccounts(
+            sequence,
+            signerInfo.payer,
+            recordReceipts
+        );
 
         await this.program.rpc.placeBid(
             this.auctionFactory.config.bump,
             this.auctionFactory.config.seed,
-            pdaData.bump,
+            bidAccounts.auctionBump,
             sequence,
             new anchor.BN(amount),
+            bidAccounts.bidRefundBump,
+            {
+                accounts: bidAccounts.accounts,
+                remainingAccounts: bidAccounts.remainingAccounts,
+                instructions: bidAccounts.instructions,
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    // view: simulates the instruction and reads the minimum bid from its emitted event
+    getMinimumBid = async (sequence: BN): Promise<BN> => {
+        this.validateAuctionFactory();
+
+        const pdaData = await this.fetchAuctionPdaData(sequence);
+        const simulation = await this.program.simulate.getMinimumBid(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            pdaData.bump,
+            sequence,
             {
                 accounts: {
-                    bidder: signerInfo.payer,
-                    leadingBidder,
                     auctionFactory: this.auctionFactory.config.address,
                     auction: pdaData.addr,
-                    systemProgram: SystemProgram.programId,
                 },
-                signers: signerInfo.signers,
             }
         );
+
+        const minimumBidEvent = simulation.events.find(
+            (event) => event.name === "MinimumBidAmount"
+        );
+        if (!minimumBidEvent) {
+            throw new Error("Minimum bid event not found");
+        }
+
+        return minimumBidEvent.data.amount as BN;
     };
 
-    settleAuction = async (
-        sequence: BN,
-        bidderTokenAccount: TokenAccount | undefined,
-        mint: PublicKey,
-        payer: PublicKey | Keypair
-    ) => {
+    // candle auctions only. once bidding is over, picks the upcoming slot whose hash closes the
+    // auction. the auction can be settled once that slot has passed.
+    requestCandleClose = async (sequence: BN, payer: PublicKey | Keypair) => {
         this.validateAuctionFactory();
 
         const signerInfo = getSignersFromPayer(payer);
         const pdaData = await this.fetchAuctionPdaData(sequence);
-        const metadata = await this.getMetadata(mint);
-        const [auctionTokenAccount, _auctionTokenAccountBump] =
-            await this.getAssociatedTokenAccountAddress(pdaData.addr, mint);
-
-        const auctionAccount = await this.fetchAuction(pdaData.addr);
-        const randomKeypair = Keypair.generate();
-        const [randomBidderAccount, _randomBidderAccountBump] =
-            await this.getAssociatedTokenAccountAddress(
-                randomKeypair.publicKey,
-                mint
-            );
-        const bidderTokenAccountData = {
-            address: bidderTokenAccount
-                ? bidderTokenAccount.address
-                : randomBidderAccount,
-            bump: bidderTokenAccount ? bidderTokenAccount.bump : 0,
-        };
-
-        // make sure to add ixn to create bidder ATA account before attempting to transfer token
-        const preInstructions = bidderTokenAccount // if not init, don't use it
-            ? [
-                  this.createAssociatedTokenAccount(
-                      mint,
-                      bidderTokenAccountData.address,
-                      auctionAccount.bidder, // owner
-                      signerInfo.payer // payer
-                  ),
-              ]
-            : [];
 
-        await this.program.rpc.settleAuction(
-            bidderTokenAccountData.bump,
+        await this.program.rpc.requestCandleClose(
             this.auctionFactory.config.bump,
             this.auctionFactory.config.seed,
             pdaData.bump,
@@ -628,44 +922,796 @@ export class AuctionFactoryClient extends AccountUtils {
             {
                 accounts: {
                     payer: signerInfo.payer,
-                    treasury: this.auctionFactory.treasury,
-                    metadata,
                     auctionFactory: this.auctionFactory.config.address,
                     auction: pdaData.addr,
-                    mint,
-                    bidderTokenAccount: bidderTokenAccountData.address,
-                    auctionTokenAccount,
-                    tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
-                    tokenProgram: TOKEN_PROGRAM_ID,
-                    systemProgram: SystemProgram.programId,
+                    slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
                 },
-                instructions: preInstructions,
                 signers: signerInfo.signers,
             }
         );
+    };
 
-        // no need to update auction config
+    // leading bidder raises their own bid, paying only the difference from their current bid
+    raiseBid = async (
+        sequence: BN,
+        amount: BN,
+        payer: PublicKey | Keypair, // payer is bidder
+        recordReceipts: boolean = false
+    ) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const bidAccounts = await this.buildBidAccounts(
+            sequence,
+            signerInfo.payer,
+            recordReceipts
+        );
+
+        await this.program.rpc.raiseBid(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            bidAccounts.auctionBump,
+            sequence,
+            new anchor.BN(amount),
+            bidAccounts.bidRefundBump,
+            {
+                accounts: bidAccounts.accounts,
+                remainingAccounts: bidAccounts.remainingAccounts,
+                instructions: bidAccounts.instructions,
+                signers: signerInfo.signers,
+            }
+        );
     };
 
-    // warn: do not rely on client state for this function. caller can close any auction's state at any time.
-    closeAuctionTokenAccount = async (
-        auction: PublicKey,
-        bump: number,
+    // escrows max amount up front. the program counters other bids on the bidder's behalf at the
+    // minimum increment until max amount is reached.
+    placeMaxBid = async (
         sequence: BN,
-        auctionTokenAccount: PublicKey,
-        payer: PublicKey | Keypair
+        maxAmount: BN,
+        payer: PublicKey | Keypair, // payer is bidder
+        recordReceipts: boolean = false
     ) => {
         this.validateAuctionFactory();
 
         const signerInfo = getSignersFromPayer(payer);
+        const bidAccounts = await this.buildBidAccounts(
+            sequence,
+            signerInfo.payer,
+            recordReceipts
+        );
 
-        await this.program.rpc.closeAuctionTokenAccount(
+        await this.program.rpc.placeMaxBid(
             this.auctionFactory.config.bump,
             this.auctionFactory.config.seed,
-            bump,
+            bidAccounts.auctionBump,
             sequence,
+            new anchor.BN(maxAmount),
+            bidAccounts.bidRefundBump,
             {
-                accounts: {
+                accounts: bidAccounts.accounts,
+                remainingAccounts: bidAccounts.remainingAccounts,
+                instructions: bidAccounts.instructions,
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    // accounts shared by every instruction that escrows a bid
+    buildBidAccounts = async (
+        sequence: BN,
+        bidder: PublicKey,
+        recordReceipts: boolean = false
+    ) => {
+        const pdaData = await this.fetchAuctionPdaData(sequence);
+        const auction = await this.fetchAuction(pdaData.addr);
+
+        const [bidRefund, bidRefundBump] = await this.findBidRefundPda(
+            pdaData.addr,
+            bidder
+        );
+        // only read when the leading bid is a max bid, otherwise any account will do
+        const [leadingBidRefund, _leadingBidRefundBump] = auction.proxyBidder
+            ? await this.findBidRefundPda(pdaData.addr, auction.proxyBidder)
+            : [bidRefund, bidRefundBump];
+
+        const bidTokenAccounts = await this.getBidTokenAccounts(
+            pdaData.addr,
+            auction.tokenMint,
+            bidder
+        );
+        const instructions = await this.buildAuctionBidTokenAccountInstructions(
+            pdaData.addr,
+            auction.tokenMint,
+            bidTokenAccounts.auctionBidTokenAccount,
+            bidder
+        );
+
+        return {
+            auctionBump: pdaData.bump,
+            bidRefundBump,
+            accounts: {
+                bidder,
+                bidderTokenAccount: bidTokenAccounts.bidderTokenAccount,
+                auctionBidTokenAccount: bidTokenAccounts.auctionBidTokenAccount,
+                auctionFactory: this.auctionFactory.config.address,
+                auction: pdaData.addr,
+                bidRefund,
+                leadingBidRefund,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            },
+            instructions,
+            remainingAccounts: recordReceipts
+                ? await this.buildBidReceiptAccounts(
+                      pdaData.addr,
+                      auction.bidCount
+                  )
+                : [],
+        };
+    };
+
+    // receipts for every bid the instruction could record, starting at the auction's next bid index.
+    // receipt accounts beyond the bids actually recorded are ignored.
+    buildBidReceiptAccounts = async (
+        auction: PublicKey,
+        bidCount: BN
+    ): Promise<AccountMeta[]> => {
+        const receiptAccounts: AccountMeta[] = [];
+        for (let i = 0; i < MAX_BIDS_RECORDED_PER_INSTRUCTION; i++) {
+            const [bidReceipt, _bidReceiptBump] = await this.findBidReceiptPda(
+                auction,
+                bidCount.add(new BN(i))
+            );
+            receiptAccounts.push({
+                pubkey: bidReceipt,
+                isWritable: true,
+                isSigner: false,
+            });
+        }
+
+        return receiptAccounts;
+    };
+
+    // permissionless once the auction is settled or void. rent goes back to whoever paid for the receipt.
+    closeBidReceipt = async (
+        sequence: BN,
+        index: BN,
+        payer: PublicKey | Keypair
+    ) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const pdaData = await this.fetchAuctionPdaData(sequence);
+        const [bidReceipt, _bidReceiptBump] = await this.findBidReceiptPda(
+            pdaData.addr,
+            index
+        );
+        const bidReceiptAccount = await this.fetchBidReceipt(bidReceipt);
+
+        await this.program.rpc.closeBidReceipt(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            pdaData.bump,
+            sequence,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                    auction: pdaData.addr,
+                    bidReceipt,
+                    receiptPayer: bidReceiptAccount.payer,
+                },
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    // permissionless; returns an emptied bid refund account's rent to its bidder once the auction
+    // is settled or void. every refund account must be closed before the auction can be.
+    closeBidRefund = async (
+        sequence: BN,
+        bidder: PublicKey,
+        payer: PublicKey | Keypair
+    ) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const pdaData = await this.fetchAuctionPdaData(sequence);
+        const [bidRefund, _bidRefundBump] = await this.findBidRefundPda(
+            pdaData.addr,
+            bidder
+        );
+
+        await this.program.rpc.closeBidRefund(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            pdaData.bump,
+            sequence,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                    auction: pdaData.addr,
+                    bidRefund,
+                    bidder,
+                },
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    // sealed bid auctions only. escrows a deposit that must cover the hidden bid.
+    // see computeSealedBidCommitment for the commitment.
+    commitSealedBid = async (
+        sequence: BN,
+        deposit: BN,
+        commitment: number[],
+        payer: PublicKey | Keypair // payer is bidder
+    ) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const bidAccounts = await this.buildBidAccounts(
+            sequence,
+            signerInfo.payer
+        );
+
+        await this.program.rpc.commitSealedBid(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            bidAccounts.auctionBump,
+            sequence,
+            new anchor.BN(deposit),
+            bidAccounts.bidRefundBump,
+            commitment,
+            {
+                accounts: bidAccounts.accounts,
+                instructions: bidAccounts.instructions,
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    // sealed bid auctions only. reveals are accepted after the auction's end time, until its reveal end time.
+    revealSealedBid = async (
+        sequence: BN,
+        amount: BN,
+        salt: number[],
+        payer: PublicKey | Keypair // payer is bidder
+    ) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const pdaData = await this.fetchAuctionPdaData(sequence);
+        const [bidRefund, bidRefundBump] = await this.findBidRefundPda(
+            pdaData.addr,
+            signerInfo.payer
+        );
+
+        await this.program.rpc.revealSealedBid(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            pdaData.bump,
+            sequence,
+            bidRefundBump,
+            new anchor.BN(amount),
+            salt,
+            {
+                accounts: {
+                    bidder: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                    auction: pdaData.addr,
+                    bidRefund,
+                },
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    // dutch auctions only. buys the resource at the current price, as long as it does not exceed
+    // max price. the auction is settled in the same instruction.
+    buy = async (
+        sequence: BN,
+        maxPrice: BN,
+        payer: PublicKey | Keypair // payer is buyer
+    ) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const pdaData = await this.fetchAuctionPdaData(sequence);
+        const auction = await this.fetchAuction(pdaData.addr);
+        const mint = auction.resource;
+
+        const auctionTokenAccount = await this.getAuctionTokenAccount(
+            pdaData.addr
+        );
+        // the buyer's token account for the resource is created by the program if missing
+        const [buyerTokenAccount, _buyerTokenAccountBump] =
+            await this.getAssociatedTokenAccountAddress(
+                signerInfo.payer,
+                mint
+            );
+        const bidTokenAccounts = await this.getBidTokenAccounts(
+            pdaData.addr,
+            auction.tokenMint,
+            signerInfo.payer
+        );
+
+        await this.program.rpc.buy(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            pdaData.bump,
+            sequence,
+            new anchor.BN(maxPrice),
+            {
+                accounts: {
+                    buyer: signerInfo.payer,
+                    buyerPaymentTokenAccount:
+                        bidTokenAccounts.bidderTokenAccount,
+                    auctionFactory: this.auctionFactory.config.address,
+                    auction: pdaData.addr,
+                    treasury: this.auctionFactory.treasury,
+                    mint,
+                    buyerTokenAccount,
+                    auctionTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                    rent: SYSVAR_RENT_PUBKEY,
+                },
+                remainingAccounts: await this.getRevenueRecipientAccounts(),
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    // withdraws the bidder's escrow once outbid, or once the auction is over
+    claimRefund = async (
+        sequence: BN,
+        payer: PublicKey | Keypair // payer is bidder
+    ) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const refundAccounts = await this.buildRefundAccounts(
+            sequence,
+            signerInfo.payer
+        );
+
+        await this.program.rpc.claimRefund(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            refundAccounts.auctionBump,
+            sequence,
+            refundAccounts.bidRefundBump,
+            {
+                accounts: refundAccounts.accounts,
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    // voids an auction left unsettled past the settlement grace period, then withdraws the
+    // bidder's full escrow. later reclaims on a voided auction only withdraw.
+    reclaimStuckBid = async (
+        sequence: BN,
+        payer: PublicKey | Keypair // payer is bidder
+    ) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const refundAccounts = await this.buildRefundAccounts(
+            sequence,
+            signerInfo.payer
+        );
+
+        await this.program.rpc.reclaimStuckBid(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            refundAccounts.auctionBump,
+            sequence,
+            refundAccounts.bidRefundBump,
+            {
+                accounts: refundAccounts.accounts,
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    // accounts shared by every instruction that withdraws a bidder's escrow
+    buildRefundAccounts = async (sequence: BN, bidder: PublicKey) => {
+        const pdaData = await this.fetchAuctionPdaData(sequence);
+        const auction = await this.fetchAuction(pdaData.addr);
+
+        const [bidRefund, bidRefundBump] = await this.findBidRefundPda(
+            pdaData.addr,
+            bidder
+        );
+        const bidTokenAccounts = await this.getBidTokenAccounts(
+            pdaData.addr,
+            auction.tokenMint,
+            bidder
+        );
+
+        return {
+            auctionBump: pdaData.bump,
+            bidRefundBump,
+            accounts: {
+                bidder,
+                bidderTokenAccount: bidTokenAccounts.bidderTokenAccount,
+                auctionFactory: this.auctionFactory.config.address,
+                auction: pdaData.addr,
+                auctionBidTokenAccount: bidTokenAccounts.auctionBidTokenAccount,
+                bidRefund,
+                treasury: this.auctionFactory.treasury,
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            },
+        };
+    };
+
+    // the winner, or the unsold resource recipient of an auction without any bids
+    getResourceRecipient = async (sequence: BN): Promise<PublicKey> => {
+        const auction = await this.fetchAuctionWithSequence(sequence);
+        if (auction.amount.gt(BN_ZERO)) {
+            return auction.bidder;
+        }
+
+        const auctionFactory = await this.fetchAuctionFactory(
+            this.auctionFactory.config.address
+        );
+        return auctionFactory.data.unsoldResourceRecipient
+            ? auctionFactory.data.unsoldResourceRecipient
+            : auctionFactory.treasury;
+    };
+
+    // the program creates the bidder's token account for the resource if it does not exist yet
+    settleAuction = async (
+        sequence: BN,
+        mint: PublicKey,
+        payer: PublicKey | Keypair,
+        bidder?: PublicKey
+    ) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const pdaData = await this.fetchAuctionPdaData(sequence);
+        const auctionAccount = await this.fetchAuction(pdaData.addr);
+        const metadata = await this.getMetadata(mint);
+        const auctionTokenAccount = await this.getAuctionTokenAccount(
+            pdaData.addr
+        );
+
+        const resourceRecipient = bidder
+            ? bidder
+            : await this.getResourceRecipient(sequence);
+        const [bidderTokenAccount, _bidderTokenAccountBump] =
+            await this.getAssociatedTokenAccountAddress(
+                resourceRecipient,
+                mint
+            );
+
+        // the settler's crank reward is paid into their own bid token account
+        const bidTokenAccounts = await this.getBidTokenAccounts(
+            pdaData.addr,
+            auctionAccount.tokenMint,
+            signerInfo.payer
+        );
+
+        await this.program.rpc.settleAuction(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            pdaData.bump,
+            sequence,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                    auction: pdaData.addr,
+                    mint,
+                    treasury: this.auctionFactory.treasury,
+                    metadata,
+                    bidder: resourceRecipient,
+                    bidderTokenAccount,
+                    auctionTokenAccount,
+                    auctionBidTokenAccount:
+                        bidTokenAccounts.auctionBidTokenAccount,
+                    payerBidTokenAccount: bidTokenAccounts.bidderTokenAccount,
+                    slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+                    tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                    rent: SYSVAR_RENT_PUBKEY,
+                },
+                remainingAccounts: await this.getRevenueRecipientAccounts(),
+                signers: signerInfo.signers,
+            }
+        );
+
+        // no need to update auction config
+    };
+
+    // settles the current auction, then creates & supplies the next one in a single transaction.
+    // the next auction's mint & token account are created ahead of time, with the next auction as
+    // their authority. returns the next auction's sequence & mint.
+    settleAndAdvance = async (
+        currentSequence: BN,
+        payer: PublicKey | Keypair,
+        bidder?: PublicKey
+    ) => {
+        this.validateAuctionFactory();
+        this.validateConfig();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const currentPdaData = await this.fetchAuctionPdaData(currentSequence);
+        const currentAuctionAccount = await this.fetchAuction(
+            currentPdaData.addr
+        );
+        const currentMint: PublicKey = currentAuctionAccount.resource;
+        const currentAuctionTokenAccount = await this.getAuctionTokenAccount(
+            currentPdaData.addr
+        );
+
+        const resourceRecipient = bidder
+            ? bidder
+            : await this.getResourceRecipient(currentSequence);
+        const [bidderTokenAccount, _bidderTokenAccountBump] =
+            await this.getAssociatedTokenAccountAddress(
+                resourceRecipient,
+                currentMint
+            );
+
+        // the settler's crank reward is paid into their own bid token account
+        const bidTokenAccounts = await this.getBidTokenAccounts(
+            currentPdaData.addr,
+            currentAuctionAccount.tokenMint,
+            signerInfo.payer
+        );
+
+        const nextSequence = currentSequence.add(BN_ONE);
+        const [nextAuction, nextAuctionBump] = await this.findAuctionPda(
+            nextSequence,
+            this.auctionFactory.config.address
+        );
+        const nextMint = Keypair.generate();
+        const [nextAuctionTokenAccount, _nextAuctionTokenAccountBump] =
+            await this.getAssociatedTokenAccountAddress(
+                nextAuction,
+                nextMint.publicKey
+            );
+
+        await this.program.rpc.settleAndAdvance(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            currentPdaData.bump,
+            nextAuctionBump,
+            this.config.bump,
+            this.config.seed,
+            currentSequence,
+            nextSequence,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    config: this.config.address,
+                    auctionFactory: this.auctionFactory.config.address,
+                    currentAuction: currentPdaData.addr,
+                    currentMint,
+                    treasury: this.auctionFactory.treasury,
+                    currentMetadata: await this.getMetadata(currentMint),
+                    bidder: resourceRecipient,
+                    bidderTokenAccount,
+                    currentAuctionTokenAccount,
+                    auctionBidTokenAccount:
+                        bidTokenAccounts.auctionBidTokenAccount,
+                    payerBidTokenAccount: bidTokenAccounts.bidderTokenAccount,
+                    slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+                    nextAuction,
+                    nextMint: nextMint.publicKey,
+                    nextAuctionTokenAccount,
+                    nextMetadata: await this.getMetadata(nextMint.publicKey),
+                    nextMasterEdition: await this.getMasterEdition(
+                        nextMint.publicKey
+                    ),
+                    tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                    rent: SYSVAR_RENT_PUBKEY,
+                },
+                remainingAccounts: await this.getRevenueRecipientAccounts(),
+                instructions: [
+                    SystemProgram.createAccount({
+                        fromPubkey: signerInfo.payer,
+                        newAccountPubkey: nextMint.publicKey,
+                        space: MintLayout.span,
+                        lamports:
+                            await this.program.provider.connection.getMinimumBalanceForRentExemption(
+                                MintLayout.span
+                            ),
+                        programId: TOKEN_PROGRAM_ID,
+                    }),
+                    Token.createInitMintInstruction(
+                        TOKEN_PROGRAM_ID,
+                        nextMint.publicKey,
+                        0,
+                        nextAuction,
+                        nextAuction
+                    ),
+                    this.createAssociatedTokenAccount(
+                        nextMint.publicKey,
+                        nextAuctionTokenAccount,
+                        nextAuction, // owner
+                        signerInfo.payer // payer
+                    ),
+                ],
+                signers: [...signerInfo.signers, nextMint],
+            }
+        );
+
+        return {
+            nextSequence,
+            nextMint: nextMint.publicKey,
+        };
+    };
+
+    // multi-winner auctions only. prints the edition numbered by the winner's rank into a new mint,
+    // whose single token is minted to the winner ahead of time. returns the new mint.
+    claimPrintEdition = async (
+        sequence: BN,
+        winnerIndex: number,
+        payer: PublicKey | Keypair
+    ): Promise<PublicKey> => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const pdaData = await this.fetchAuctionPdaData(sequence);
+        const auctionAccount = await this.fetchAuction(pdaData.addr);
+        const masterMint: PublicKey = auctionAccount.resource;
+        const winner: PublicKey = auctionAccount.winningBids[winnerIndex].bidder;
+
+        const auctionTokenAccount = await this.getAuctionTokenAccount(
+            pdaData.addr
+        );
+
+        const newMint = Keypair.generate();
+        const [winnerTokenAccount, _winnerTokenAccountBump] =
+            await this.getAssociatedTokenAccountAddress(
+                winner,
+                newMint.publicKey
+            );
+
+        await this.program.rpc.claimPrintEdition(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            pdaData.bump,
+            sequence,
+            winnerIndex,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                    auction: pdaData.addr,
+                    masterMint,
+                    masterMetadata: await this.getMetadata(masterMint),
+                    masterEdition: await this.getMasterEdition(masterMint),
+                    auctionTokenAccount,
+                    newMint: newMint.publicKey,
+                    newMetadata: await this.getMetadata(newMint.publicKey),
+                    newEdition: await this.getMasterEdition(newMint.publicKey),
+                    editionMarkPda: await this.getEditionMarkPda(
+                        masterMint,
+                        winnerIndex
+                    ),
+                    winnerTokenAccount,
+                    tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                    rent: SYSVAR_RENT_PUBKEY,
+                },
+                instructions: [
+                    SystemProgram.createAccount({
+                        fromPubkey: signerInfo.payer,
+                        newAccountPubkey: newMint.publicKey,
+                        space: MintLayout.span,
+                        lamports:
+                            await this.program.provider.connection.getMinimumBalanceForRentExemption(
+                                MintLayout.span
+                            ),
+                        programId: TOKEN_PROGRAM_ID,
+                    }),
+                    Token.createInitMintInstruction(
+                        TOKEN_PROGRAM_ID,
+                        newMint.publicKey,
+                        0,
+                        signerInfo.payer,
+                        signerInfo.payer
+                    ),
+                    this.createAssociatedTokenAccount(
+                        newMint.publicKey,
+                        winnerTokenAccount,
+                        winner, // owner
+                        signerInfo.payer // payer
+                    ),
+                    Token.createMintToInstruction(
+                        TOKEN_PROGRAM_ID,
+                        newMint.publicKey,
+                        winnerTokenAccount,
+                        signerInfo.payer,
+                        [],
+                        1
+                    ),
+                ],
+                signers: [...signerInfo.signers, newMint],
+            }
+        );
+
+        return newMint.publicKey;
+    };
+
+    // multi-winner auctions only. sends the master edition to the top bidder, once every print
+    // edition has been claimed.
+    claimMasterEdition = async (sequence: BN, payer: PublicKey | Keypair) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const pdaData = await this.fetchAuctionPdaData(sequence);
+        const auctionAccount = await this.fetchAuction(pdaData.addr);
+        const masterMint: PublicKey = auctionAccount.resource;
+
+        const auctionTokenAccount = await this.getAuctionTokenAccount(
+            pdaData.addr
+        );
+        // the top bidder's token account for the resource is created by the program if missing
+        const [bidderTokenAccount, _bidderTokenAccountBump] =
+            await this.getAssociatedTokenAccountAddress(
+                auctionAccount.bidder,
+                masterMint
+            );
+
+        await this.program.rpc.claimMasterEdition(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            pdaData.bump,
+            sequence,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                    auction: pdaData.addr,
+                    bidder: auctionAccount.bidder,
+                    mint: masterMint,
+                    bidderTokenAccount,
+                    auctionTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                    rent: SYSVAR_RENT_PUBKEY,
+                },
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
+    // warn: do not rely on client state for this function. caller can close any auction's state at any time.
+    closeAuctionTokenAccount = async (
+        auction: PublicKey,
+        bump: number,
+        sequence: BN,
+        auctionTokenAccount: PublicKey,
+        payer: PublicKey | Keypair
+    ) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+
+        await this.program.rpc.closeAuctionTokenAccount(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            bump,
+            sequence,
+            {
+                accounts: {
                     payer: signerInfo.payer,
                     treasury: this.auctionFactory.treasury,
                     auctionFactory: this.auctionFactory.config.address,
@@ -679,6 +1725,117 @@ export class AuctionFactoryClient extends AccountUtils {
         );
     };
 
+    // permissionless. the auction's rent goes to the treasury, less the rent of the summary left
+    // in its place, which is refunded to the payer. any surplus funds go to the treasury, and the
+    // bid token account of SPL auctions is closed. every bid refund account must be closed first.
+    closeAuction = async (sequence: BN, payer: PublicKey | Keypair) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const pdaData = await this.fetchAuctionPdaData(sequence);
+        const auctionAccount = await this.fetchAuction(pdaData.addr);
+
+        const [auctionSummary, auctionSummaryBump] =
+            await this.findAuctionSummaryPda(pdaData.addr);
+        const bidTokenAccounts = await this.getBidTokenAccounts(
+            pdaData.addr,
+            auctionAccount.tokenMint,
+            signerInfo.payer
+        );
+
+        await this.program.rpc.closeAuction(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            pdaData.bump,
+            sequence,
+            auctionSummaryBump,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                    auction: pdaData.addr,
+                    auctionSummary,
+                    treasury: this.auctionFactory.treasury,
+                    auctionBidTokenAccount:
+                        bidTokenAccounts.auctionBidTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                },
+                signers: signerInfo.signers,
+            }
+        );
+
+        return auctionSummary;
+    };
+
+    // authority only. the resource is burned, or returned to the authority's token account.
+    // every bid stays escrowed for its bidder to withdraw via claimRefund.
+    cancelAuction = async (
+        sequence: BN,
+        burnResource: boolean,
+        payer: PublicKey | Keypair
+    ) => {
+        this.validateAuctionFactory();
+
+        const signerInfo = getSignersFromPayer(payer);
+        const pdaData = await this.fetchAuctionPdaData(sequence);
+        const auctionAccount = await this.fetchAuction(pdaData.addr);
+
+        // token accounts are not used if the auction has no resource
+        let mint = signerInfo.payer;
+        let auctionTokenAccount = signerInfo.payer;
+        let authorityTokenAccount = signerInfo.payer;
+        const instructions = [];
+        if (auctionAccount.resource) {
+            mint = auctionAccount.resource;
+            auctionTokenAccount = await this.getAuctionTokenAccount(
+                pdaData.addr
+            );
+            // the resource is returned to the authority, i.e. the payer
+            [authorityTokenAccount] =
+                await this.getAssociatedTokenAccountAddress(
+                    signerInfo.payer,
+                    mint
+                );
+
+            const authorityTokenAccountInfo =
+                await this.program.provider.connection.getAccountInfo(
+                    authorityTokenAccount
+                );
+            if (!burnResource && !authorityTokenAccountInfo) {
+                instructions.push(
+                    this.createAssociatedTokenAccount(
+                        mint,
+                        authorityTokenAccount,
+                        signerInfo.payer, // owner
+                        signerInfo.payer // payer
+                    )
+                );
+            }
+        }
+
+        await this.program.rpc.cancelAuction(
+            this.auctionFactory.config.bump,
+            this.auctionFactory.config.seed,
+            pdaData.bump,
+            sequence,
+            burnResource,
+            {
+                accounts: {
+                    payer: signerInfo.payer,
+                    auctionFactory: this.auctionFactory.config.address,
+                    auction: pdaData.addr,
+                    mint,
+                    auctionTokenAccount,
+                    authorityTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                },
+                instructions,
+                signers: signerInfo.signers,
+            }
+        );
+    };
+
     // ============================================================================
     // config client
     // ============================================================================
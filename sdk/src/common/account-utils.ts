@@ -103,6 +103,22 @@ export class AccountUtils {
         )[0];
     };
 
+    // each edition marker tracks 248 edition numbers of a master edition
+    getEditionMarkPda = async (
+        mint: PublicKey,
+        edition: number
+    ): Promise<PublicKey> => {
+        return (
+            await this.findProgramAddress(TOKEN_METADATA_PROGRAM_ID, [
+                "metadata",
+                TOKEN_METADATA_PROGRAM_ID,
+                mint,
+                "edition",
+                Math.floor(edition / 248).toString(),
+            ])
+        )[0];
+    };
+
     createAssociatedTokenAccount = (
         mint: PublicKey,
         associatedAccount: PublicKey,
@@ -16,9 +16,15 @@ export const TOKEN_BURN_ADDRESS = new PublicKey(
 export const AUX_SEED = "aux";
 export const AUX_FAX_SEED = "aux_fax";
 export const URI_CONFIG_SEED = "config";
+export const BID_REFUND_SEED = "refund";
+export const BID_RECEIPT_SEED = "receipt";
+export const AUCTION_SUMMARY_SEED = "summary";
 
 export const AUCTION_FACTORY_SEED_LEN = 5;
 export const CONFIG_SEED_LEN = 5;
+export const MAX_BID_INCREMENT_TIERS = 4;
+// an incoming bid, the incoming max bid exhausting its ceiling, and the leading max bid's counter
+export const MAX_BIDS_RECORDED_PER_INSTRUCTION = 3;
 
 export const BN_ZERO = new BN(0);
 export const BN_ONE = new BN(1);
\ No newline at end of file
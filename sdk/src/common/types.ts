@@ -27,26 +27,136 @@ export interface Bid {
     amount: BN;
 }
 
+// anchor represents enum variants as objects keyed by the camel case variant name, e.g. { english: {} }
+export type AuctionType =
+    | { english: {} }
+    | { dutch: {} }
+    | { sealedBid: {} }
+    | { candle: {} };
+
+export type PriceCurve = { linear: {} } | { exponential: {} };
+
+export type UnsoldResourcePolicy =
+    | { burn: {} }
+    | { transfer: {} }
+    | { rollOver: {} };
+
+export type AuctionFactoryRole =
+    | { pauser: {} }
+    | { configManager: {} }
+    | { parameterManager: {} }
+    | { treasurer: {} };
+
+export type AuctionFactoryChange =
+    | { data: {} }
+    | { treasury: {} }
+    | { revenueSplit: {} };
+
 export interface Auction {
     bump: number;
     sequence: BN;
+    auctionType: AuctionType;
     authority: PublicKey;
     startTime: BN;
     endTime: BN;
     finalizedEndTime: BN;
+    dutchAuction: DutchAuctionData;
+    revealEndTime: BN;
+    nonRevealPenalty: BN;
+    candleStartTime: BN;
+    candleCheckpoints: Bid[];
+    candleCloseSlot: BN;
+    timeExtension: BN;
     settled: boolean;
     amount: BN;
+    secondHighestAmount: BN;
+    secondPriceSettlement: boolean;
     bidder: PublicKey;
+    proxyBidder?: PublicKey;
     bidTime: BN;
     resource?: PublicKey;
     bids: Bid[];
+    bidCount: BN;
+    escrowedAmount: BN;
+    openBidRefunds: BN;
+    tokenMint?: PublicKey;
+    numWinners: number;
+    winningBids: Bid[];
+    distributedEditions: BN;
+    buyNowPrice?: BN;
+    cancelled: boolean;
+    voided: boolean;
+    unsoldResourcePolicy: UnsoldResourcePolicy;
+    resourceRolledOver: boolean;
+    tokenAccountClosed: boolean;
+}
+
+export interface DutchAuctionData {
+    startPrice: BN;
+    floorPrice: BN;
+    curve: PriceCurve;
+    decayRate: BN;
+    decayInterval: BN;
+}
+
+export interface SealedBidData {
+    revealDuration: BN;
+    nonRevealPenalty: BN;
+}
+
+export interface CandleAuctionData {
+    endingPeriod: BN;
+}
+
+export interface AuctionSchedule {
+    interval: BN;
+    offset: BN;
+}
+
+export interface BidIncrementTier {
+    threshold: BN;
+    increment: BN;
+}
+
+export interface CrankRewardData {
+    fixedAmount: BN;
+    winningBidShare: BN;
+}
+
+export interface RevenueShare {
+    recipient: PublicKey;
+    share: BN;
 }
 
 export interface AuctionFactoryData {
-    duration: BN;
     timeBuffer: BN;
     minBidPercentageIncrease: BN;
+    minBidIncrement: BN;
+    bidIncrementTiers: BidIncrementTier[];
     minReservePrice: BN;
+    duration: BN;
+    maxTimeExtension: BN | null;
+    auctionType: AuctionType;
+    dutchAuction: DutchAuctionData;
+    sealedBid: SealedBidData;
+    candle: CandleAuctionData;
+    secondPriceSettlement: boolean;
+    numWinners: number;
+    buyNowPrice: BN | null;
+    cooldown: BN;
+    schedule: AuctionSchedule | null;
+    settlementGracePeriod: BN | null;
+    crankReward: CrankRewardData;
+    unsoldResourcePolicy: UnsoldResourcePolicy;
+    unsoldResourceRecipient: PublicKey | null;
+    changeDelay: BN;
+}
+
+export interface AuctionFactoryRoles {
+    pauser?: PublicKey;
+    configManager?: PublicKey;
+    parameterManager?: PublicKey;
+    treasurer?: PublicKey;
 }
 
 export interface AuctionFactory {
@@ -60,6 +170,43 @@ export interface AuctionFactory {
     activeSince: number;
     treasury: PublicKey;
     config: PublicKey;
+    bidMint?: PublicKey;
+    revenueSplit: RevenueShare[];
+    pendingAuthority?: PublicKey;
+    roles: AuctionFactoryRoles;
+    pendingData?: { data: AuctionFactoryData; executeAfter: BN };
+    pendingTreasury?: { treasury: PublicKey; executeAfter: BN };
+    pendingRevenueSplit?: { revenueSplit: RevenueShare[]; executeAfter: BN };
+}
+
+export interface BidRefund {
+    bump: number;
+    auction: PublicKey;
+    bidder: PublicKey;
+    amount: BN;
+    maxAmount: BN;
+    commitment: number[];
+    revealed: boolean;
+}
+
+export interface BidReceipt {
+    bump: number;
+    auction: PublicKey;
+    index: BN;
+    bidder: PublicKey;
+    amount: BN;
+    updatedAt: BN;
+    payer: PublicKey;
+}
+
+export interface AuctionSummary {
+    bump: number;
+    auctionFactory: PublicKey;
+    sequence: BN;
+    winner?: PublicKey;
+    amount: BN;
+    mint?: PublicKey;
+    finalizedEndTime: BN;
 }
 
 export interface SignerInfo {
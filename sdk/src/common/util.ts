@@ -1,8 +1,11 @@
 import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@project-serum/anchor";
 import * as lodash from "lodash";
+import { sha256 } from "js-sha256";
 
 import { isKp } from "../common";
-import { SignerInfo } from "../common/types";
+import { AuctionFactoryData, SignerInfo } from "../common/types";
+import { MAX_BID_INCREMENT_TIERS } from "../common/constant";
 
 export const sleep = async (ms: number) => {
     await new Promise((response) =>
@@ -40,4 +43,62 @@ export const getSignersFromPayer = (
         payer: _payer,
         signers
     } as SignerInfo;
+}
+
+// english auction with every optional feature turned off. callers override the fields they need.
+export const getDefaultAuctionFactoryData = (): AuctionFactoryData => {
+    return {
+        timeBuffer: new BN(0),
+        minBidPercentageIncrease: new BN(0),
+        minBidIncrement: new BN(0),
+        bidIncrementTiers: Array(MAX_BID_INCREMENT_TIERS)
+            .fill(0)
+            .map((_el, _idx) => ({ threshold: new BN(0), increment: new BN(0) })),
+        minReservePrice: new BN(0),
+        duration: new BN(0),
+        maxTimeExtension: null,
+        auctionType: { english: {} },
+        dutchAuction: {
+            startPrice: new BN(0),
+            floorPrice: new BN(0),
+            curve: { linear: {} },
+            decayRate: new BN(0),
+            decayInterval: new BN(0),
+        },
+        sealedBid: {
+            revealDuration: new BN(0),
+            nonRevealPenalty: new BN(0),
+        },
+        candle: {
+            endingPeriod: new BN(0),
+        },
+        secondPriceSettlement: false,
+        numWinners: 1,
+        buyNowPrice: null,
+        cooldown: new BN(0),
+        schedule: null,
+        settlementGracePeriod: null,
+        crankReward: {
+            fixedAmount: new BN(0),
+            winningBidShare: new BN(0),
+        },
+        unsoldResourcePolicy: { burn: {} },
+        unsoldResourceRecipient: null,
+        changeDelay: new BN(0),
+    };
+};
+
+// sealed bid commitment, sha256(amount as little endian bytes || salt || bidder)
+export const computeSealedBidCommitment = (
+    amount: BN,
+    salt: number[],
+    bidder: PublicKey
+): number[] => {
+    return sha256.array(
+        Buffer.concat([
+            amount.toArrayLike(Buffer, "le", 8),
+            Buffer.from(salt),
+            bidder.toBuffer(),
+        ])
+    );
 }
\ No newline at end of file
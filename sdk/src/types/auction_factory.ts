@@ -244,7 +244,12 @@ export type AuctionFactory = {
           "isSigner": true
         },
         {
-          "name": "leadingBidder",
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
           "isMut": true,
           "isSigner": false
         },
@@ -258,6 +263,21 @@ export type AuctionFactory = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leadingBidRefund",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -284,54 +304,48 @@ export type AuctionFactory = {
         {
           "name": "amount",
           "type": "u64"
+        },
+        {
+          "name": "bidRefundBump",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "settleAuction",
+      "name": "raiseBid",
       "accounts": [
         {
-          "name": "payer",
+          "name": "bidder",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "auctionFactory",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "auction",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "mint",
+          "name": "bidderTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasury",
+          "name": "auctionBidTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "auctionFactory",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bidderTokenAccount",
+          "name": "auction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionTokenAccount",
+          "name": "bidRefund",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMetadataProgram",
+          "name": "leadingBidRefund",
           "isMut": false,
           "isSigner": false
         },
@@ -347,10 +361,6 @@ export type AuctionFactory = {
         }
       ],
       "args": [
-        {
-          "name": "bidderAccountBump",
-          "type": "u8"
-        },
         {
           "name": "auctionFactoryBump",
           "type": "u8"
@@ -366,17 +376,35 @@ export type AuctionFactory = {
         {
           "name": "sequence",
           "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "bidRefundBump",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "closeAuctionTokenAccount",
+      "name": "placeMaxBid",
       "accounts": [
         {
-          "name": "payer",
-          "isMut": false,
+          "name": "bidder",
+          "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "auctionFactory",
           "isMut": true,
@@ -388,13 +416,13 @@ export type AuctionFactory = {
           "isSigner": false
         },
         {
-          "name": "treasury",
+          "name": "bidRefund",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionTokenAccount",
-          "isMut": true,
+          "name": "leadingBidRefund",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -424,41 +452,84 @@ export type AuctionFactory = {
         {
           "name": "sequence",
           "type": "u64"
+        },
+        {
+          "name": "maxAmount",
+          "type": "u64"
+        },
+        {
+          "name": "bidRefundBump",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "initializeAuctionFactory",
+      "name": "buy",
       "accounts": [
         {
-          "name": "payer",
+          "name": "buyer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "treasury",
+          "name": "buyerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "config",
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionFactory",
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenAccount",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "auctionFactoryBump",
           "type": "u8"
         },
         {
@@ -466,180 +537,207 @@ export type AuctionFactory = {
           "type": "string"
         },
         {
-          "name": "configBump",
+          "name": "auctionBump",
           "type": "u8"
         },
         {
-          "name": "configSeed",
-          "type": "string"
+          "name": "sequence",
+          "type": "u64"
         },
         {
-          "name": "data",
-          "type": {
-            "defined": "AuctionFactoryData"
-          }
+          "name": "maxPrice",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "toggleAuctionFactoryStatus",
+      "name": "commitSealedBid",
       "accounts": [
         {
-          "name": "payer",
-          "isMut": false,
+          "name": "bidder",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "auctionFactory",
+          "name": "bidderTokenAccount",
           "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bump",
-          "type": "u8"
         },
         {
-          "name": "seed",
-          "type": "string"
-        }
-      ]
-    },
-    {
-      "name": "modifyAuctionFactoryData",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": false,
-          "isSigner": true
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
         },
         {
           "name": "auctionFactory",
           "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bump",
-          "type": "u8"
         },
         {
-          "name": "seed",
-          "type": "string"
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "data",
-          "type": {
-            "defined": "AuctionFactoryData"
-          }
-        }
-      ]
-    },
-    {
-      "name": "updateAuthority",
-      "accounts": [
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "payer",
+          "name": "leadingBidRefund",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "newAuthority",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionFactory",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "auctionFactoryBump",
           "type": "u8"
         },
         {
           "name": "seed",
           "type": "string"
-        }
-      ]
-    },
-    {
-      "name": "updateTreasury",
-      "accounts": [
+        },
         {
-          "name": "payer",
-          "isMut": false,
-          "isSigner": true
+          "name": "auctionBump",
+          "type": "u8"
         },
         {
-          "name": "auctionFactory",
-          "isMut": true,
-          "isSigner": false
+          "name": "sequence",
+          "type": "u64"
         },
         {
-          "name": "treasury",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "deposit",
+          "type": "u64"
+        },
         {
-          "name": "bump",
+          "name": "bidRefundBump",
           "type": "u8"
         },
         {
-          "name": "seed",
-          "type": "string"
+          "name": "commitment",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
         }
       ]
     },
     {
-      "name": "transferLamportsToTreasury",
+      "name": "revealSealedBid",
       "accounts": [
         {
-          "name": "payer",
+          "name": "bidder",
           "isMut": false,
           "isSigner": true
         },
         {
           "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasury",
+          "name": "bidRefund",
           "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "auctionFactoryBump",
           "type": "u8"
         },
         {
           "name": "seed",
           "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "bidRefundBump",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
         }
       ]
     },
     {
-      "name": "initializeConfig",
+      "name": "claimRefund",
       "accounts": [
         {
-          "name": "payer",
+          "name": "bidder",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "config",
+          "name": "bidderTokenAccount",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -648,7 +746,7 @@ export type AuctionFactory = {
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "auctionFactoryBump",
           "type": "u8"
         },
         {
@@ -656,21 +754,29 @@ export type AuctionFactory = {
           "type": "string"
         },
         {
-          "name": "maxSupply",
-          "type": "u32"
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "bidRefundBump",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "addUrisToConfig",
+      "name": "reclaimStuckBid",
       "accounts": [
         {
-          "name": "payer",
-          "isMut": false,
+          "name": "bidder",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "config",
+          "name": "bidderTokenAccount",
           "isMut": true,
           "isSigner": false
         },
@@ -679,6 +785,31 @@ export type AuctionFactory = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -695,397 +826,70 @@ export type AuctionFactory = {
           "type": "string"
         },
         {
-          "name": "configBump",
+          "name": "auctionBump",
           "type": "u8"
         },
         {
-          "name": "configSeed",
-          "type": "string"
+          "name": "sequence",
+          "type": "u64"
         },
         {
-          "name": "configData",
-          "type": {
-            "vec": "string"
-          }
+          "name": "bidRefundBump",
+          "type": "u8"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "auction",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "sequence",
-            "type": "u64"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "startTime",
-            "type": "u64"
-          },
-          {
-            "name": "endTime",
-            "type": "u64"
-          },
-          {
-            "name": "finalizedEndTime",
-            "type": "u64"
-          },
-          {
-            "name": "settled",
-            "type": "bool"
-          },
-          {
-            "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "bidder",
-            "type": "publicKey"
-          },
-          {
-            "name": "bidTime",
-            "type": "u64"
-          },
-          {
-            "name": "resource",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "bids",
-            "type": {
-              "vec": {
-                "defined": "Bid"
-              }
-            }
-          }
-        ]
-      }
     },
     {
-      "name": "auctionFactory",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "seed",
-            "type": "string"
-          },
-          {
-            "name": "sequence",
-            "type": "u64"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "isActive",
-            "type": "bool"
-          },
-          {
-            "name": "data",
-            "type": {
-              "defined": "AuctionFactoryData"
-            }
-          },
-          {
-            "name": "initializedAt",
-            "type": "u64"
-          },
-          {
-            "name": "activeSince",
-            "type": "u64"
-          },
-          {
-            "name": "treasury",
-            "type": "publicKey"
-          },
-          {
-            "name": "config",
-            "type": "publicKey"
-          }
-        ]
-      }
+      "name": "closeBidRefund",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "config",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "seed",
-            "type": "string"
-          },
-          {
-            "name": "maxSupply",
-            "type": "u32"
-          },
-          {
-            "name": "updateIdx",
-            "type": "u32"
-          },
-          {
-            "name": "isUpdated",
-            "type": "bool"
-          },
-          {
-            "name": "buffer",
-            "type": {
-              "vec": "string"
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "Bid",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bidder",
-            "type": "publicKey"
-          },
-          {
-            "name": "updatedAt",
-            "type": "u64"
-          },
-          {
-            "name": "amount",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "AuctionFactoryData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "timeBuffer",
-            "type": "u64"
-          },
-          {
-            "name": "minBidPercentageIncrease",
-            "type": "u64"
-          },
-          {
-            "name": "minReservePrice",
-            "type": "u64"
-          },
-          {
-            "name": "duration",
-            "type": "u64"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "IncorrectOwner",
-      "msg": "Account does not have correct owner!"
-    },
-    {
-      "code": 6001,
-      "name": "Uninitialized",
-      "msg": "Account is not initialized!"
-    },
-    {
-      "code": 6002,
-      "name": "TokenAccountNotOwnedByWinningBidder",
-      "msg": "Token account not owned by winning bidder"
-    },
-    {
-      "code": 6003,
-      "name": "MintMismatch",
-      "msg": "Mint Mismatch!"
-    },
-    {
-      "code": 6004,
-      "name": "InactiveAuctionFactory",
-      "msg": "Activate auction factory before performing such action."
-    },
-    {
-      "code": 6005,
-      "name": "TreasuryMismatch",
-      "msg": "Treasury mismatch!"
-    },
-    {
-      "code": 6006,
-      "name": "AuctionFactoryUuidInvalidLengthError",
-      "msg": "Uuid must be length 5"
-    },
-    {
-      "code": 6007,
-      "name": "ConfigUuidInvalidLengthError",
-      "msg": "Uuid must be length 5"
-    },
-    {
-      "code": 6008,
-      "name": "ConfigElementTooShortError",
-      "msg": "Config element too short. Config data elements must be at least 1 char in length."
-    },
-    {
-      "code": 6009,
-      "name": "ConfigElementTooLongError",
-      "msg": "Config element too long. Must be less than max length!"
-    },
-    {
-      "code": 6010,
-      "name": "InsufficientConfigError",
-      "msg": "Insufficient config error!"
-    },
-    {
-      "code": 6011,
-      "name": "InactiveAuction",
-      "msg": "Auction is not in a state to perform such action."
-    },
-    {
-      "code": 6012,
-      "name": "AuctionResourceAlreadyExists",
-      "msg": "Auction resource can only be generated once."
-    },
-    {
-      "code": 6013,
-      "name": "UnsettledAuction",
-      "msg": "Must settle any ongoing auction before creating a new auction."
-    },
-    {
-      "code": 6014,
-      "name": "AuctionAlreadySettled",
-      "msg": "Auction is already settled."
-    },
-    {
-      "code": 6015,
-      "name": "AuctionIsLive",
-      "msg": "Auction is live and cannot be settled."
-    },
-    {
-      "code": 6016,
-      "name": "InvalidBidAmount",
-      "msg": "Bid must be a non-negative, non-zero amount. Bid must also beat previous bid by some percent."
-    },
-    {
-      "code": 6017,
-      "name": "NoActiveAuction",
-      "msg": "Cannot modify an auction that does not exist."
-    },
-    {
-      "code": 6018,
-      "name": "AuctionAddressMismatch",
-      "msg": "Auction address mismatch."
-    },
-    {
-      "code": 6019,
-      "name": "AuctionsAlreadyInitialized",
-      "msg": "Initialize auctions can only be called once."
-    },
-    {
-      "code": 6020,
-      "name": "BidderAlreadyWinning",
-      "msg": "Bidder is already winning the auction"
-    },
-    {
-      "code": 6021,
-      "name": "WrongSettleAuctionEndpoint",
-      "msg": "Wrong settle auction endpoint!"
-    },
-    {
-      "code": 6022,
-      "name": "AuctionHasNoResourceAvailable",
-      "msg": "Must supply resource to auction before settling!"
-    },
-    {
-      "code": 6023,
-      "name": "NumericalOverflowError",
-      "msg": "Numerical overflow error!"
-    },
-    {
-      "code": 6024,
-      "name": "NumericalUnderflowError",
-      "msg": "Numerical underflow error!"
-    },
-    {
-      "code": 6025,
-      "name": "CheckedRemError",
-      "msg": "Checked REM error"
-    },
-    {
-      "code": 6026,
-      "name": "NumericalDivisionError",
-      "msg": "Numerical division error!"
-    },
-    {
-      "code": 6027,
-      "name": "NotAuthorized",
-      "msg": "Account is not authorized to take such action."
-    },
-    {
-      "code": 6028,
-      "name": "PublicKeyMismatch",
-      "msg": "Public key mismatch"
-    },
-    {
-      "code": 6029,
-      "name": "TokenTransferFailed",
-      "msg": "Token transfer failed"
-    },
-    {
-      "code": 6030,
-      "name": "InsufficientAccountBalance",
-      "msg": "Insufficient account balance!"
-    },
-    {
-      "code": 6031,
-      "name": "ForcedError",
-      "msg": "Forced error"
-    }
-  ]
-};
-
-export const IDL: AuctionFactory = {
-  "version": "0.1.0",
-  "name": "auction_factory",
-  "instructions": [
-    {
-      "name": "mintToAuction",
+      "name": "getMinimumBid",
       "accounts": [
-        {
-          "name": "mint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenMintAccount",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "auctionFactory",
           "isMut": false,
@@ -1095,11 +899,6 @@ export const IDL: AuctionFactory = {
           "name": "auction",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
@@ -1122,16 +921,16 @@ export const IDL: AuctionFactory = {
       ]
     },
     {
-      "name": "createFirstAuction",
+      "name": "requestCandleClose",
       "accounts": [
         {
           "name": "payer",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
           "name": "auctionFactory",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1140,7 +939,7 @@ export const IDL: AuctionFactory = {
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "slotHashes",
           "isMut": false,
           "isSigner": false
         }
@@ -1165,31 +964,31 @@ export const IDL: AuctionFactory = {
       ]
     },
     {
-      "name": "createNextAuction",
+      "name": "closeBidReceipt",
       "accounts": [
         {
           "name": "payer",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
           "name": "auctionFactory",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "currentAuction",
-          "isMut": true,
+          "name": "auction",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "nextAuction",
+          "name": "bidReceipt",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "receiptPayer",
+          "isMut": true,
           "isSigner": false
         }
       ],
@@ -1203,25 +1002,17 @@ export const IDL: AuctionFactory = {
           "type": "string"
         },
         {
-          "name": "currentAuctionBump",
-          "type": "u8"
-        },
-        {
-          "name": "nextAuctionBump",
+          "name": "auctionBump",
           "type": "u8"
         },
         {
-          "name": "currentSeq",
-          "type": "u64"
-        },
-        {
-          "name": "nextSeq",
+          "name": "sequence",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "supplyResourceToAuction",
+      "name": "settleAuction",
       "accounts": [
         {
           "name": "payer",
@@ -1229,22 +1020,22 @@ export const IDL: AuctionFactory = {
           "isSigner": true
         },
         {
-          "name": "config",
+          "name": "auctionFactory",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionFactory",
-          "isMut": false,
+          "name": "auction",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auction",
+          "name": "mint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "treasury",
           "isMut": true,
           "isSigner": false
         },
@@ -1254,10 +1045,35 @@ export const IDL: AuctionFactory = {
           "isSigner": false
         },
         {
-          "name": "masterEdition",
+          "name": "bidder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerBidTokenAccount",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "tokenMetadataProgram",
           "isMut": false,
@@ -1268,6 +1084,11 @@ export const IDL: AuctionFactory = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -1292,14 +1113,6 @@ export const IDL: AuctionFactory = {
           "name": "auctionBump",
           "type": "u8"
         },
-        {
-          "name": "configBump",
-          "type": "u8"
-        },
-        {
-          "name": "configSeed",
-          "type": "string"
-        },
         {
           "name": "sequence",
           "type": "u64"
@@ -1307,15 +1120,15 @@ export const IDL: AuctionFactory = {
       ]
     },
     {
-      "name": "placeBid",
+      "name": "settleAndAdvance",
       "accounts": [
         {
-          "name": "bidder",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "leadingBidder",
+          "name": "config",
           "isMut": true,
           "isSigner": false
         },
@@ -1325,79 +1138,77 @@ export const IDL: AuctionFactory = {
           "isSigner": false
         },
         {
-          "name": "auction",
+          "name": "currentAuction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "currentMint",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "auctionFactoryBump",
-          "type": "u8"
         },
         {
-          "name": "seed",
-          "type": "string"
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "auctionBump",
-          "type": "u8"
+          "name": "currentMetadata",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "sequence",
-          "type": "u64"
+          "name": "bidder",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "settleAuction",
-      "accounts": [
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "payer",
+          "name": "currentAuctionTokenAccount",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "auctionFactory",
+          "name": "auctionBidTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auction",
+          "name": "payerBidTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "mint",
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nextAuction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasury",
+          "name": "nextMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "metadata",
+          "name": "nextAuctionTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bidderTokenAccount",
+          "name": "nextMetadata",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "auctionTokenAccount",
+          "name": "nextMasterEdition",
           "isMut": true,
           "isSigner": false
         },
@@ -1411,17 +1222,23 @@ export const IDL: AuctionFactory = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
-        {
-          "name": "bidderAccountBump",
-          "type": "u8"
-        },
         {
           "name": "auctionFactoryBump",
           "type": "u8"
@@ -1431,26 +1248,42 @@ export const IDL: AuctionFactory = {
           "type": "string"
         },
         {
-          "name": "auctionBump",
+          "name": "currentAuctionBump",
           "type": "u8"
         },
         {
-          "name": "sequence",
+          "name": "nextAuctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "configBump",
+          "type": "u8"
+        },
+        {
+          "name": "configSeed",
+          "type": "string"
+        },
+        {
+          "name": "currentSeq",
+          "type": "u64"
+        },
+        {
+          "name": "nextSeq",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "closeAuctionTokenAccount",
+      "name": "claimPrintEdition",
       "accounts": [
         {
           "name": "payer",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
           "name": "auctionFactory",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1459,15 +1292,55 @@ export const IDL: AuctionFactory = {
           "isSigner": false
         },
         {
-          "name": "treasury",
+          "name": "masterMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterEdition",
           "isMut": true,
           "isSigner": false
         },
         {
           "name": "auctionTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "editionMarkPda",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -1477,6 +1350,11 @@ export const IDL: AuctionFactory = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -1495,11 +1373,15 @@ export const IDL: AuctionFactory = {
         {
           "name": "sequence",
           "type": "u64"
+        },
+        {
+          "name": "winnerIndex",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "initializeAuctionFactory",
+      "name": "claimMasterEdition",
       "accounts": [
         {
           "name": "payer",
@@ -1507,29 +1389,59 @@ export const IDL: AuctionFactory = {
           "isSigner": true
         },
         {
-          "name": "treasury",
+          "name": "auctionFactory",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "config",
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidder",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "auctionFactory",
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenAccount",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "auctionFactoryBump",
           "type": "u8"
         },
         {
@@ -1537,23 +1449,17 @@ export const IDL: AuctionFactory = {
           "type": "string"
         },
         {
-          "name": "configBump",
+          "name": "auctionBump",
           "type": "u8"
         },
         {
-          "name": "configSeed",
-          "type": "string"
-        },
-        {
-          "name": "data",
-          "type": {
-            "defined": "AuctionFactoryData"
-          }
+          "name": "sequence",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "toggleAuctionFactoryStatus",
+      "name": "closeAuctionTokenAccount",
       "accounts": [
         {
           "name": "payer",
@@ -1564,36 +1470,99 @@ export const IDL: AuctionFactory = {
           "name": "auctionFactory",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "auctionFactoryBump",
           "type": "u8"
         },
         {
           "name": "seed",
           "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "modifyAuctionFactoryData",
+      "name": "closeAuction",
       "accounts": [
         {
           "name": "payer",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
           "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionSummary",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "auctionFactoryBump",
           "type": "u8"
         },
         {
@@ -1601,23 +1570,34 @@ export const IDL: AuctionFactory = {
           "type": "string"
         },
         {
-          "name": "data",
-          "type": {
-            "defined": "AuctionFactoryData"
-          }
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "auctionSummaryBump",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "updateAuthority",
+      "name": "initializeAuctionFactory",
       "accounts": [
         {
           "name": "payer",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "newAuthority",
+          "name": "treasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
           "isMut": false,
           "isSigner": false
         },
@@ -1625,6 +1605,11 @@ export const IDL: AuctionFactory = {
           "name": "auctionFactory",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -1635,11 +1620,31 @@ export const IDL: AuctionFactory = {
         {
           "name": "seed",
           "type": "string"
+        },
+        {
+          "name": "configBump",
+          "type": "u8"
+        },
+        {
+          "name": "configSeed",
+          "type": "string"
+        },
+        {
+          "name": "bidMint",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "data",
+          "type": {
+            "defined": "AuctionFactoryData"
+          }
         }
       ]
     },
     {
-      "name": "updateTreasury",
+      "name": "toggleAuctionFactoryStatus",
       "accounts": [
         {
           "name": "payer",
@@ -1650,11 +1655,6 @@ export const IDL: AuctionFactory = {
           "name": "auctionFactory",
           "isMut": true,
           "isSigner": false
-        },
-        {
-          "name": "treasury",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
@@ -1669,7 +1669,7 @@ export const IDL: AuctionFactory = {
       ]
     },
     {
-      "name": "transferLamportsToTreasury",
+      "name": "cancelAuction",
       "accounts": [
         {
           "name": "payer",
@@ -1678,42 +1678,100 @@ export const IDL: AuctionFactory = {
         },
         {
           "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "treasury",
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenAccount",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "authorityTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "auctionFactoryBump",
           "type": "u8"
         },
         {
           "name": "seed",
           "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "burnResource",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "initializeConfig",
+      "name": "modifyAuctionFactoryData",
       "accounts": [
         {
           "name": "payer",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "config",
+          "name": "auctionFactory",
           "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
         },
         {
-          "name": "systemProgram",
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "data",
+          "type": {
+            "defined": "AuctionFactoryData"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateRevenueSplit",
+      "accounts": [
+        {
+          "name": "payer",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
           "isSigner": false
         }
       ],
@@ -1727,13 +1785,17 @@ export const IDL: AuctionFactory = {
           "type": "string"
         },
         {
-          "name": "maxSupply",
-          "type": "u32"
+          "name": "revenueSplit",
+          "type": {
+            "vec": {
+              "defined": "RevenueShare"
+            }
+          }
         }
       ]
     },
     {
-      "name": "addUrisToConfig",
+      "name": "proposeAuthority",
       "accounts": [
         {
           "name": "payer",
@@ -1741,50 +1803,4096 @@ export const IDL: AuctionFactory = {
           "isSigner": true
         },
         {
-          "name": "config",
-          "isMut": true,
+          "name": "newAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "auctionFactory",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
         },
         {
-          "name": "systemProgram",
+          "name": "seed",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "acceptAuthority",
+      "accounts": [
+        {
+          "name": "payer",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "auctionFactoryBump",
+          "name": "bump",
           "type": "u8"
         },
         {
           "name": "seed",
           "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "cancelAuthorityTransfer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "configBump",
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
           "type": "u8"
         },
         {
-          "name": "configSeed",
+          "name": "seed",
           "type": "string"
-        },
-        {
-          "name": "configData",
-          "type": {
-            "vec": "string"
-          }
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "auction",
+      "name": "grantRole",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "role",
+          "type": {
+            "defined": "AuctionFactoryRole"
+          }
+        },
+        {
+          "name": "holder",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "revokeRole",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "role",
+          "type": {
+            "defined": "AuctionFactoryRole"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTreasury",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "cancelPendingChange",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "change",
+          "type": {
+            "defined": "AuctionFactoryChange"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executePendingChange",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "change",
+          "type": {
+            "defined": "AuctionFactoryChange"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLamportsToTreasury",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "initializeConfig",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "maxSupply",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "addUrisToConfig",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "configBump",
+          "type": "u8"
+        },
+        {
+          "name": "configSeed",
+          "type": "string"
+        },
+        {
+          "name": "configData",
+          "type": {
+            "vec": "string"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "auction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "sequence",
+            "type": "u64"
+          },
+          {
+            "name": "auctionType",
+            "type": {
+              "defined": "AuctionType"
+            }
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "startTime",
+            "type": "u64"
+          },
+          {
+            "name": "endTime",
+            "type": "u64"
+          },
+          {
+            "name": "finalizedEndTime",
+            "type": "u64"
+          },
+          {
+            "name": "dutchAuction",
+            "type": {
+              "defined": "DutchAuctionData"
+            }
+          },
+          {
+            "name": "revealEndTime",
+            "type": "u64"
+          },
+          {
+            "name": "nonRevealPenalty",
+            "type": "u64"
+          },
+          {
+            "name": "candleStartTime",
+            "type": "u64"
+          },
+          {
+            "name": "candleCheckpoints",
+            "type": {
+              "vec": {
+                "defined": "Bid"
+              }
+            }
+          },
+          {
+            "name": "candleCloseSlot",
+            "type": "u64"
+          },
+          {
+            "name": "timeExtension",
+            "type": "u64"
+          },
+          {
+            "name": "settled",
+            "type": "bool"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "secondHighestAmount",
+            "type": "u64"
+          },
+          {
+            "name": "secondPriceSettlement",
+            "type": "bool"
+          },
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "proxyBidder",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "bidTime",
+            "type": "u64"
+          },
+          {
+            "name": "resource",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "bids",
+            "type": {
+              "vec": {
+                "defined": "Bid"
+              }
+            }
+          },
+          {
+            "name": "bidCount",
+            "type": "u64"
+          },
+          {
+            "name": "escrowedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "openBidRefunds",
+            "type": "u64"
+          },
+          {
+            "name": "tokenMint",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "numWinners",
+            "type": "u8"
+          },
+          {
+            "name": "winningBids",
+            "type": {
+              "vec": {
+                "defined": "Bid"
+              }
+            }
+          },
+          {
+            "name": "distributedEditions",
+            "type": "u64"
+          },
+          {
+            "name": "buyNowPrice",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "cancelled",
+            "type": "bool"
+          },
+          {
+            "name": "voided",
+            "type": "bool"
+          },
+          {
+            "name": "unsoldResourcePolicy",
+            "type": {
+              "defined": "UnsoldResourcePolicy"
+            }
+          },
+          {
+            "name": "resourceRolledOver",
+            "type": "bool"
+          },
+          {
+            "name": "tokenAccountClosed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionFactory",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "seed",
+            "type": "string"
+          },
+          {
+            "name": "sequence",
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          },
+          {
+            "name": "data",
+            "type": {
+              "defined": "AuctionFactoryData"
+            }
+          },
+          {
+            "name": "initializedAt",
+            "type": "u64"
+          },
+          {
+            "name": "activeSince",
+            "type": "u64"
+          },
+          {
+            "name": "treasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "config",
+            "type": "publicKey"
+          },
+          {
+            "name": "bidMint",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "revenueSplit",
+            "type": {
+              "vec": {
+                "defined": "RevenueShare"
+              }
+            }
+          },
+          {
+            "name": "pendingAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "roles",
+            "type": {
+              "defined": "AuctionFactoryRoles"
+            }
+          },
+          {
+            "name": "pendingData",
+            "type": {
+              "option": {
+                "defined": "PendingDataChange"
+              }
+            }
+          },
+          {
+            "name": "pendingTreasury",
+            "type": {
+              "option": {
+                "defined": "PendingTreasuryChange"
+              }
+            }
+          },
+          {
+            "name": "pendingRevenueSplit",
+            "type": {
+              "option": {
+                "defined": "PendingRevenueSplitChange"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionSummary",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "auctionFactory",
+            "type": "publicKey"
+          },
+          {
+            "name": "sequence",
+            "type": "u64"
+          },
+          {
+            "name": "winner",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "finalizedEndTime",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bidRefund",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "auction",
+            "type": "publicKey"
+          },
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "maxAmount",
+            "type": "u64"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "revealed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "seed",
+            "type": "string"
+          },
+          {
+            "name": "maxSupply",
+            "type": "u32"
+          },
+          {
+            "name": "updateIdx",
+            "type": "u32"
+          },
+          {
+            "name": "isUpdated",
+            "type": "bool"
+          },
+          {
+            "name": "buffer",
+            "type": {
+              "vec": "string"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "updatedAt",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DutchAuctionData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "floorPrice",
+            "type": "u64"
+          },
+          {
+            "name": "curve",
+            "type": {
+              "defined": "PriceCurve"
+            }
+          },
+          {
+            "name": "decayRate",
+            "type": "u64"
+          },
+          {
+            "name": "decayInterval",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedBidData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revealDuration",
+            "type": "u64"
+          },
+          {
+            "name": "nonRevealPenalty",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CandleAuctionData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "endingPeriod",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "interval",
+            "type": "u64"
+          },
+          {
+            "name": "offset",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidIncrementTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "type": "u64"
+          },
+          {
+            "name": "increment",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CrankRewardData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fixedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "winningBidShare",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RevenueShare",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "type": "publicKey"
+          },
+          {
+            "name": "share",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionFactoryRoles",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pauser",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "configManager",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "parameterManager",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "treasurer",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingDataChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "data",
+            "type": {
+              "defined": "AuctionFactoryData"
+            }
+          },
+          {
+            "name": "executeAfter",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingTreasuryChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "treasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "executeAfter",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingRevenueSplitChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revenueSplit",
+            "type": {
+              "vec": {
+                "defined": "RevenueShare"
+              }
+            }
+          },
+          {
+            "name": "executeAfter",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionFactoryData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timeBuffer",
+            "type": "u64"
+          },
+          {
+            "name": "minBidPercentageIncrease",
+            "type": "u64"
+          },
+          {
+            "name": "minBidIncrement",
+            "type": "u64"
+          },
+          {
+            "name": "bidIncrementTiers",
+            "type": {
+              "array": [
+                {
+                  "defined": "BidIncrementTier"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "minReservePrice",
+            "type": "u64"
+          },
+          {
+            "name": "duration",
+            "type": "u64"
+          },
+          {
+            "name": "maxTimeExtension",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "auctionType",
+            "type": {
+              "defined": "AuctionType"
+            }
+          },
+          {
+            "name": "dutchAuction",
+            "type": {
+              "defined": "DutchAuctionData"
+            }
+          },
+          {
+            "name": "sealedBid",
+            "type": {
+              "defined": "SealedBidData"
+            }
+          },
+          {
+            "name": "candle",
+            "type": {
+              "defined": "CandleAuctionData"
+            }
+          },
+          {
+            "name": "secondPriceSettlement",
+            "type": "bool"
+          },
+          {
+            "name": "numWinners",
+            "type": "u8"
+          },
+          {
+            "name": "buyNowPrice",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "cooldown",
+            "type": "u64"
+          },
+          {
+            "name": "schedule",
+            "type": {
+              "option": {
+                "defined": "AuctionSchedule"
+              }
+            }
+          },
+          {
+            "name": "settlementGracePeriod",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "crankReward",
+            "type": {
+              "defined": "CrankRewardData"
+            }
+          },
+          {
+            "name": "unsoldResourcePolicy",
+            "type": {
+              "defined": "UnsoldResourcePolicy"
+            }
+          },
+          {
+            "name": "unsoldResourceRecipient",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "changeDelay",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "English"
+          },
+          {
+            "name": "Dutch"
+          },
+          {
+            "name": "SealedBid"
+          },
+          {
+            "name": "Candle"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceCurve",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Linear"
+          },
+          {
+            "name": "Exponential"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UnsoldResourcePolicy",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "Transfer"
+          },
+          {
+            "name": "RollOver"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionFactoryRole",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pauser"
+          },
+          {
+            "name": "ConfigManager"
+          },
+          {
+            "name": "ParameterManager"
+          },
+          {
+            "name": "Treasurer"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionFactoryChange",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Data"
+          },
+          {
+            "name": "Treasury"
+          },
+          {
+            "name": "RevenueSplit"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "MinimumBidAmount",
+      "fields": [
+        {
+          "name": "auction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "IncorrectOwner",
+      "msg": "Account does not have correct owner!"
+    },
+    {
+      "code": 6001,
+      "name": "Uninitialized",
+      "msg": "Account is not initialized!"
+    },
+    {
+      "code": 6002,
+      "name": "TokenAccountNotOwnedByWinningBidder",
+      "msg": "Token account not owned by winning bidder"
+    },
+    {
+      "code": 6003,
+      "name": "TokenAccountOwnerMismatch",
+      "msg": "Token account owner mismatch"
+    },
+    {
+      "code": 6004,
+      "name": "AuctionBidTokenAccountMismatch",
+      "msg": "Token account is not the auction's associated token account for the bid mint"
+    },
+    {
+      "code": 6005,
+      "name": "MintMismatch",
+      "msg": "Mint Mismatch!"
+    },
+    {
+      "code": 6006,
+      "name": "InactiveAuctionFactory",
+      "msg": "Activate auction factory before performing such action."
+    },
+    {
+      "code": 6007,
+      "name": "TreasuryMismatch",
+      "msg": "Treasury mismatch!"
+    },
+    {
+      "code": 6008,
+      "name": "AuctionFactoryUuidInvalidLengthError",
+      "msg": "Uuid must be length 5"
+    },
+    {
+      "code": 6009,
+      "name": "ConfigUuidInvalidLengthError",
+      "msg": "Uuid must be length 5"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidAuctionFactoryData",
+      "msg": "Invalid auction factory data."
+    },
+    {
+      "code": 6011,
+      "name": "InvalidRevenueSplit",
+      "msg": "Revenue shares must be non-zero and total 10,000 basis points."
+    },
+    {
+      "code": 6012,
+      "name": "RevenueRecipientMismatch",
+      "msg": "Revenue recipient accounts do not match the revenue split."
+    },
+    {
+      "code": 6013,
+      "name": "UnsoldResourceRecipientRequired",
+      "msg": "Auction factories with a bid mint must set an unsold resource recipient."
+    },
+    {
+      "code": 6014,
+      "name": "UnsoldResourceRecipientMismatch",
+      "msg": "Unsold resource recipient mismatch."
+    },
+    {
+      "code": 6015,
+      "name": "NoPendingAuthority",
+      "msg": "Auction factory has no pending authority."
+    },
+    {
+      "code": 6016,
+      "name": "PendingAuthorityMismatch",
+      "msg": "Signer is not the auction factory's pending authority."
+    },
+    {
+      "code": 6017,
+      "name": "NoPendingChange",
+      "msg": "Auction factory has no pending change of this kind."
+    },
+    {
+      "code": 6018,
+      "name": "PendingChangeTimelocked",
+      "msg": "Pending change cannot be executed until its delay has passed."
+    },
+    {
+      "code": 6019,
+      "name": "ConfigElementTooShortError",
+      "msg": "Config element too short. Config data elements must be at least 1 char in length."
+    },
+    {
+      "code": 6020,
+      "name": "ConfigElementTooLongError",
+      "msg": "Config element too long. Must be less than max length!"
+    },
+    {
+      "code": 6021,
+      "name": "InsufficientConfigError",
+      "msg": "Insufficient config error!"
+    },
+    {
+      "code": 6022,
+      "name": "InactiveAuction",
+      "msg": "Auction is not in a state to perform such action."
+    },
+    {
+      "code": 6023,
+      "name": "AuctionResourceAlreadyExists",
+      "msg": "Auction resource can only be generated once."
+    },
+    {
+      "code": 6024,
+      "name": "AuctionResourceRolledOver",
+      "msg": "Auction resource was carried over from the previous auction."
+    },
+    {
+      "code": 6025,
+      "name": "UnsettledAuction",
+      "msg": "Must settle any ongoing auction before creating a new auction."
+    },
+    {
+      "code": 6026,
+      "name": "AuctionAlreadySettled",
+      "msg": "Auction is already settled."
+    },
+    {
+      "code": 6027,
+      "name": "AuctionIsLive",
+      "msg": "Auction is live and cannot be settled."
+    },
+    {
+      "code": 6028,
+      "name": "InvalidBidAmount",
+      "msg": "Bid must be a non-negative, non-zero amount. Bid must also beat previous bid by some percent."
+    },
+    {
+      "code": 6029,
+      "name": "NoActiveAuction",
+      "msg": "Cannot modify an auction that does not exist."
+    },
+    {
+      "code": 6030,
+      "name": "AuctionAddressMismatch",
+      "msg": "Auction address mismatch."
+    },
+    {
+      "code": 6031,
+      "name": "AuctionsAlreadyInitialized",
+      "msg": "Initialize auctions can only be called once."
+    },
+    {
+      "code": 6032,
+      "name": "BidderAlreadyWinning",
+      "msg": "Bidder is already winning the auction"
+    },
+    {
+      "code": 6033,
+      "name": "BidderNotWinning",
+      "msg": "Bidder is not the winning bidder."
+    },
+    {
+      "code": 6034,
+      "name": "WrongSettleAuctionEndpoint",
+      "msg": "Wrong settle auction endpoint!"
+    },
+    {
+      "code": 6035,
+      "name": "AuctionHasNoResourceAvailable",
+      "msg": "Must supply resource to auction before settling!"
+    },
+    {
+      "code": 6036,
+      "name": "AuctionNotSettled",
+      "msg": "Auction must be settled before performing such action."
+    },
+    {
+      "code": 6037,
+      "name": "WrongAuctionType",
+      "msg": "Instruction does not support this auction type."
+    },
+    {
+      "code": 6038,
+      "name": "PriceExceedsMaxPrice",
+      "msg": "Current price exceeds the max price the buyer is willing to pay."
+    },
+    {
+      "code": 6039,
+      "name": "AuctionCancelled",
+      "msg": "Auction has been cancelled."
+    },
+    {
+      "code": 6040,
+      "name": "AuctionVoid",
+      "msg": "Auction has been cancelled or voided."
+    },
+    {
+      "code": 6041,
+      "name": "AuctionEndedCannotBeCancelled",
+      "msg": "Auction has ended and can no longer be cancelled."
+    },
+    {
+      "code": 6042,
+      "name": "NoSettlementGracePeriod",
+      "msg": "Auction factory has no settlement grace period."
+    },
+    {
+      "code": 6043,
+      "name": "SettlementGracePeriodActive",
+      "msg": "Auction can still be settled."
+    },
+    {
+      "code": 6044,
+      "name": "CurrentAuctionCannotBeClosed",
+      "msg": "The auction factory's current auction cannot be closed."
+    },
+    {
+      "code": 6045,
+      "name": "AuctionTokenAccountNotClosed",
+      "msg": "Auction token account must be closed first."
+    },
+    {
+      "code": 6046,
+      "name": "AuctionHasEscrowedFunds",
+      "msg": "Auction still holds escrowed bids."
+    },
+    {
+      "code": 6047,
+      "name": "AuctionHasOpenBidRefunds",
+      "msg": "Auction still has open bid refund accounts."
+    },
+    {
+      "code": 6048,
+      "name": "NoRefundAvailable",
+      "msg": "Bidder has no funds available to claim from this auction."
+    },
+    {
+      "code": 6049,
+      "name": "BidRefundNotEmpty",
+      "msg": "Bid refund still holds funds to claim."
+    },
+    {
+      "code": 6050,
+      "name": "ProxyBidMismatch",
+      "msg": "Leading proxy bid account does not match the auction's proxy bidder."
+    },
+    {
+      "code": 6051,
+      "name": "SealedBidAlreadyCommitted",
+      "msg": "Bidder has already committed a sealed bid to this auction."
+    },
+    {
+      "code": 6052,
+      "name": "SealedBidNotCommitted",
+      "msg": "Bidder has no sealed bid to reveal."
+    },
+    {
+      "code": 6053,
+      "name": "SealedBidAlreadyRevealed",
+      "msg": "Sealed bid has already been revealed."
+    },
+    {
+      "code": 6054,
+      "name": "SealedBidCommitmentMismatch",
+      "msg": "Revealed bid does not match commitment."
+    },
+    {
+      "code": 6055,
+      "name": "SealedBidExceedsDeposit",
+      "msg": "Revealed bid exceeds deposit."
+    },
+    {
+      "code": 6056,
+      "name": "MultiWinnerAuctionNotSupported",
+      "msg": "Instruction does not support multi-winner auctions."
+    },
+    {
+      "code": 6057,
+      "name": "SingleWinnerAuction",
+      "msg": "Instruction only supports multi-winner auctions."
+    },
+    {
+      "code": 6058,
+      "name": "InvalidWinnerIndex",
+      "msg": "No winning bid at this index."
+    },
+    {
+      "code": 6059,
+      "name": "EditionAlreadyDistributed",
+      "msg": "Winner has already received their edition."
+    },
+    {
+      "code": 6060,
+      "name": "PrintEditionsNotDistributed",
+      "msg": "All print editions must be distributed before the master edition."
+    },
+    {
+      "code": 6061,
+      "name": "BidReceiptAddressMismatch",
+      "msg": "Bid receipt address mismatch."
+    },
+    {
+      "code": 6062,
+      "name": "NumericalOverflowError",
+      "msg": "Numerical overflow error!"
+    },
+    {
+      "code": 6063,
+      "name": "NumericalUnderflowError",
+      "msg": "Numerical underflow error!"
+    },
+    {
+      "code": 6064,
+      "name": "CheckedRemError",
+      "msg": "Checked REM error"
+    },
+    {
+      "code": 6065,
+      "name": "NumericalDivisionError",
+      "msg": "Numerical division error!"
+    },
+    {
+      "code": 6066,
+      "name": "NotAuthorized",
+      "msg": "Account is not authorized to take such action."
+    },
+    {
+      "code": 6067,
+      "name": "PublicKeyMismatch",
+      "msg": "Public key mismatch"
+    },
+    {
+      "code": 6068,
+      "name": "TokenTransferFailed",
+      "msg": "Token transfer failed"
+    },
+    {
+      "code": 6069,
+      "name": "InsufficientAccountBalance",
+      "msg": "Insufficient account balance!"
+    },
+    {
+      "code": 6070,
+      "name": "ForcedError",
+      "msg": "Forced error"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidSlotHashes",
+      "msg": "Unable to read slot hashes"
+    },
+    {
+      "code": 6072,
+      "name": "CandleCloseNotRequested",
+      "msg": "Candle auction close must be requested before settling."
+    },
+    {
+      "code": 6073,
+      "name": "CandleCloseAlreadyRequested",
+      "msg": "Candle auction close has already been requested."
+    },
+    {
+      "code": 6074,
+      "name": "CandleCloseSlotNotReached",
+      "msg": "Candle auction close slot has not been reached yet."
+    },
+    {
+      "code": 6075,
+      "name": "CandleCloseSlotExpired",
+      "msg": "Candle auction close slot is no longer available, request the close again."
+    }
+  ]
+};
+
+export const IDL: AuctionFactory = {
+  "version": "0.1.0",
+  "name": "auction_factory",
+  "instructions": [
+    {
+      "name": "mintToAuction",
+      "accounts": [
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMintAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createFirstAuction",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createNextAuction",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "currentAuction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nextAuction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "currentAuctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "nextAuctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "currentSeq",
+          "type": "u64"
+        },
+        {
+          "name": "nextSeq",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "supplyResourceToAuction",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "masterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "configBump",
+          "type": "u8"
+        },
+        {
+          "name": "configSeed",
+          "type": "string"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "placeBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leadingBidRefund",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "bidRefundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "raiseBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leadingBidRefund",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "bidRefundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "placeMaxBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leadingBidRefund",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "maxAmount",
+          "type": "u64"
+        },
+        {
+          "name": "bidRefundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "buy",
+      "accounts": [
+        {
+          "name": "buyer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "buyerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buyerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "maxPrice",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "commitSealedBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leadingBidRefund",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "deposit",
+          "type": "u64"
+        },
+        {
+          "name": "bidRefundBump",
+          "type": "u8"
+        },
+        {
+          "name": "commitment",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "revealSealedBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "bidRefundBump",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimRefund",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "bidRefundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimStuckBid",
+      "accounts": [
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "bidRefundBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeBidRefund",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidRefund",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidder",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "getMinimumBid",
+      "accounts": [
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "requestCandleClose",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeBidReceipt",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bidReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiptPayer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleAuction",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "settleAndAdvance",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "currentAuction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "currentMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "currentMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "currentAuctionTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "slotHashes",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nextAuction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nextMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nextAuctionTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nextMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nextMasterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "currentAuctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "nextAuctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "configBump",
+          "type": "u8"
+        },
+        {
+          "name": "configSeed",
+          "type": "string"
+        },
+        {
+          "name": "currentSeq",
+          "type": "u64"
+        },
+        {
+          "name": "nextSeq",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "claimPrintEdition",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "masterMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "masterEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newEdition",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "editionMarkPda",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "winnerTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "winnerIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimMasterEdition",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bidder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bidderTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeAuctionTokenAccount",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeAuction",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionSummary",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionBidTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "auctionSummaryBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "initializeAuctionFactory",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "treasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "configBump",
+          "type": "u8"
+        },
+        {
+          "name": "configSeed",
+          "type": "string"
+        },
+        {
+          "name": "bidMint",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "data",
+          "type": {
+            "defined": "AuctionFactoryData"
+          }
+        }
+      ]
+    },
+    {
+      "name": "toggleAuctionFactoryStatus",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "cancelAuction",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorityTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "auctionBump",
+          "type": "u8"
+        },
+        {
+          "name": "sequence",
+          "type": "u64"
+        },
+        {
+          "name": "burnResource",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "modifyAuctionFactoryData",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "data",
+          "type": {
+            "defined": "AuctionFactoryData"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateRevenueSplit",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "revenueSplit",
+          "type": {
+            "vec": {
+              "defined": "RevenueShare"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAuthority",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "acceptAuthority",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "cancelAuthorityTransfer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "grantRole",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "role",
+          "type": {
+            "defined": "AuctionFactoryRole"
+          }
+        },
+        {
+          "name": "holder",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "revokeRole",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "role",
+          "type": {
+            "defined": "AuctionFactoryRole"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTreasury",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "cancelPendingChange",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "change",
+          "type": {
+            "defined": "AuctionFactoryChange"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executePendingChange",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "change",
+          "type": {
+            "defined": "AuctionFactoryChange"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLamportsToTreasury",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "initializeConfig",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "maxSupply",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "addUrisToConfig",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "auctionFactory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionFactoryBump",
+          "type": "u8"
+        },
+        {
+          "name": "seed",
+          "type": "string"
+        },
+        {
+          "name": "configBump",
+          "type": "u8"
+        },
+        {
+          "name": "configSeed",
+          "type": "string"
+        },
+        {
+          "name": "configData",
+          "type": {
+            "vec": "string"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "auction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "sequence",
+            "type": "u64"
+          },
+          {
+            "name": "auctionType",
+            "type": {
+              "defined": "AuctionType"
+            }
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "startTime",
+            "type": "u64"
+          },
+          {
+            "name": "endTime",
+            "type": "u64"
+          },
+          {
+            "name": "finalizedEndTime",
+            "type": "u64"
+          },
+          {
+            "name": "dutchAuction",
+            "type": {
+              "defined": "DutchAuctionData"
+            }
+          },
+          {
+            "name": "revealEndTime",
+            "type": "u64"
+          },
+          {
+            "name": "nonRevealPenalty",
+            "type": "u64"
+          },
+          {
+            "name": "candleStartTime",
+            "type": "u64"
+          },
+          {
+            "name": "candleCheckpoints",
+            "type": {
+              "vec": {
+                "defined": "Bid"
+              }
+            }
+          },
+          {
+            "name": "candleCloseSlot",
+            "type": "u64"
+          },
+          {
+            "name": "timeExtension",
+            "type": "u64"
+          },
+          {
+            "name": "settled",
+            "type": "bool"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "secondHighestAmount",
+            "type": "u64"
+          },
+          {
+            "name": "secondPriceSettlement",
+            "type": "bool"
+          },
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "proxyBidder",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "bidTime",
+            "type": "u64"
+          },
+          {
+            "name": "resource",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "bids",
+            "type": {
+              "vec": {
+                "defined": "Bid"
+              }
+            }
+          },
+          {
+            "name": "bidCount",
+            "type": "u64"
+          },
+          {
+            "name": "escrowedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "openBidRefunds",
+            "type": "u64"
+          },
+          {
+            "name": "tokenMint",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "numWinners",
+            "type": "u8"
+          },
+          {
+            "name": "winningBids",
+            "type": {
+              "vec": {
+                "defined": "Bid"
+              }
+            }
+          },
+          {
+            "name": "distributedEditions",
+            "type": "u64"
+          },
+          {
+            "name": "buyNowPrice",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "cancelled",
+            "type": "bool"
+          },
+          {
+            "name": "voided",
+            "type": "bool"
+          },
+          {
+            "name": "unsoldResourcePolicy",
+            "type": {
+              "defined": "UnsoldResourcePolicy"
+            }
+          },
+          {
+            "name": "resourceRolledOver",
+            "type": "bool"
+          },
+          {
+            "name": "tokenAccountClosed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionFactory",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "seed",
+            "type": "string"
+          },
+          {
+            "name": "sequence",
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          },
+          {
+            "name": "data",
+            "type": {
+              "defined": "AuctionFactoryData"
+            }
+          },
+          {
+            "name": "initializedAt",
+            "type": "u64"
+          },
+          {
+            "name": "activeSince",
+            "type": "u64"
+          },
+          {
+            "name": "treasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "config",
+            "type": "publicKey"
+          },
+          {
+            "name": "bidMint",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "revenueSplit",
+            "type": {
+              "vec": {
+                "defined": "RevenueShare"
+              }
+            }
+          },
+          {
+            "name": "pendingAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "roles",
+            "type": {
+              "defined": "AuctionFactoryRoles"
+            }
+          },
+          {
+            "name": "pendingData",
+            "type": {
+              "option": {
+                "defined": "PendingDataChange"
+              }
+            }
+          },
+          {
+            "name": "pendingTreasury",
+            "type": {
+              "option": {
+                "defined": "PendingTreasuryChange"
+              }
+            }
+          },
+          {
+            "name": "pendingRevenueSplit",
+            "type": {
+              "option": {
+                "defined": "PendingRevenueSplitChange"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionSummary",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "auctionFactory",
+            "type": "publicKey"
+          },
+          {
+            "name": "sequence",
+            "type": "u64"
+          },
+          {
+            "name": "winner",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "finalizedEndTime",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bidRefund",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "auction",
+            "type": "publicKey"
+          },
+          {
+            "name": "bidder",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "maxAmount",
+            "type": "u64"
+          },
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "revealed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "config",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1793,189 +5901,489 @@ export const IDL: AuctionFactory = {
             "type": "u8"
           },
           {
-            "name": "sequence",
-            "type": "u64"
+            "name": "seed",
+            "type": "string"
           },
           {
-            "name": "authority",
+            "name": "maxSupply",
+            "type": "u32"
+          },
+          {
+            "name": "updateIdx",
+            "type": "u32"
+          },
+          {
+            "name": "isUpdated",
+            "type": "bool"
+          },
+          {
+            "name": "buffer",
+            "type": {
+              "vec": "string"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bid",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidder",
             "type": "publicKey"
           },
           {
-            "name": "startTime",
+            "name": "updatedAt",
             "type": "u64"
           },
           {
-            "name": "endTime",
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DutchAuctionData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startPrice",
             "type": "u64"
           },
           {
-            "name": "finalizedEndTime",
+            "name": "floorPrice",
             "type": "u64"
           },
           {
-            "name": "settled",
-            "type": "bool"
+            "name": "curve",
+            "type": {
+              "defined": "PriceCurve"
+            }
           },
           {
-            "name": "amount",
+            "name": "decayRate",
             "type": "u64"
           },
           {
-            "name": "bidder",
+            "name": "decayInterval",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SealedBidData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revealDuration",
+            "type": "u64"
+          },
+          {
+            "name": "nonRevealPenalty",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CandleAuctionData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "endingPeriod",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "interval",
+            "type": "u64"
+          },
+          {
+            "name": "offset",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BidIncrementTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "type": "u64"
+          },
+          {
+            "name": "increment",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CrankRewardData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fixedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "winningBidShare",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RevenueShare",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
             "type": "publicKey"
           },
           {
-            "name": "bidTime",
+            "name": "share",
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionFactoryRoles",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pauser",
+            "type": {
+              "option": "publicKey"
+            }
           },
           {
-            "name": "resource",
+            "name": "configManager",
             "type": {
               "option": "publicKey"
             }
           },
           {
-            "name": "bids",
+            "name": "parameterManager",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "treasurer",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingDataChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "data",
+            "type": {
+              "defined": "AuctionFactoryData"
+            }
+          },
+          {
+            "name": "executeAfter",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingTreasuryChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "treasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "executeAfter",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingRevenueSplitChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revenueSplit",
             "type": {
               "vec": {
-                "defined": "Bid"
+                "defined": "RevenueShare"
               }
             }
+          },
+          {
+            "name": "executeAfter",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "auctionFactory",
+      "name": "AuctionFactoryData",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "timeBuffer",
+            "type": "u64"
+          },
+          {
+            "name": "minBidPercentageIncrease",
+            "type": "u64"
+          },
+          {
+            "name": "minBidIncrement",
+            "type": "u64"
+          },
+          {
+            "name": "bidIncrementTiers",
+            "type": {
+              "array": [
+                {
+                  "defined": "BidIncrementTier"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "minReservePrice",
+            "type": "u64"
+          },
+          {
+            "name": "duration",
+            "type": "u64"
+          },
+          {
+            "name": "maxTimeExtension",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "auctionType",
+            "type": {
+              "defined": "AuctionType"
+            }
+          },
+          {
+            "name": "dutchAuction",
+            "type": {
+              "defined": "DutchAuctionData"
+            }
           },
           {
-            "name": "seed",
-            "type": "string"
+            "name": "sealedBid",
+            "type": {
+              "defined": "SealedBidData"
+            }
           },
           {
-            "name": "sequence",
-            "type": "u64"
+            "name": "candle",
+            "type": {
+              "defined": "CandleAuctionData"
+            }
           },
           {
-            "name": "authority",
-            "type": "publicKey"
+            "name": "secondPriceSettlement",
+            "type": "bool"
           },
           {
-            "name": "isActive",
-            "type": "bool"
+            "name": "numWinners",
+            "type": "u8"
           },
           {
-            "name": "data",
+            "name": "buyNowPrice",
             "type": {
-              "defined": "AuctionFactoryData"
+              "option": "u64"
             }
           },
           {
-            "name": "initializedAt",
+            "name": "cooldown",
             "type": "u64"
           },
           {
-            "name": "activeSince",
-            "type": "u64"
+            "name": "schedule",
+            "type": {
+              "option": {
+                "defined": "AuctionSchedule"
+              }
+            }
           },
           {
-            "name": "treasury",
-            "type": "publicKey"
+            "name": "settlementGracePeriod",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "config",
-            "type": "publicKey"
+            "name": "crankReward",
+            "type": {
+              "defined": "CrankRewardData"
+            }
+          },
+          {
+            "name": "unsoldResourcePolicy",
+            "type": {
+              "defined": "UnsoldResourcePolicy"
+            }
+          },
+          {
+            "name": "unsoldResourceRecipient",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "changeDelay",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "config",
+      "name": "AuctionType",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "English"
           },
           {
-            "name": "seed",
-            "type": "string"
+            "name": "Dutch"
           },
           {
-            "name": "maxSupply",
-            "type": "u32"
+            "name": "SealedBid"
           },
           {
-            "name": "updateIdx",
-            "type": "u32"
-          },
+            "name": "Candle"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceCurve",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "isUpdated",
-            "type": "bool"
+            "name": "Linear"
           },
           {
-            "name": "buffer",
-            "type": {
-              "vec": "string"
-            }
+            "name": "Exponential"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "Bid",
+      "name": "UnsoldResourcePolicy",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "bidder",
-            "type": "publicKey"
+            "name": "Burn"
           },
           {
-            "name": "updatedAt",
-            "type": "u64"
+            "name": "Transfer"
           },
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "RollOver"
           }
         ]
       }
     },
     {
-      "name": "AuctionFactoryData",
+      "name": "AuctionFactoryRole",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "timeBuffer",
-            "type": "u64"
+            "name": "Pauser"
           },
           {
-            "name": "minBidPercentageIncrease",
-            "type": "u64"
+            "name": "ConfigManager"
           },
           {
-            "name": "minReservePrice",
-            "type": "u64"
+            "name": "ParameterManager"
           },
           {
-            "name": "duration",
-            "type": "u64"
+            "name": "Treasurer"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuctionFactoryChange",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Data"
+          },
+          {
+            "name": "Treasury"
+          },
+          {
+            "name": "RevenueSplit"
           }
         ]
       }
     }
   ],
+  "events": [
+    {
+      "name": "MinimumBidAmount",
+      "fields": [
+        {
+          "name": "auction",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    }
+  ],
   "errors": [
     {
       "code": 6000,
@@ -1994,148 +6402,368 @@ export const IDL: AuctionFactory = {
     },
     {
       "code": 6003,
+      "name": "TokenAccountOwnerMismatch",
+      "msg": "Token account owner mismatch"
+    },
+    {
+      "code": 6004,
+      "name": "AuctionBidTokenAccountMismatch",
+      "msg": "Token account is not the auction's associated token account for the bid mint"
+    },
+    {
+      "code": 6005,
       "name": "MintMismatch",
       "msg": "Mint Mismatch!"
     },
     {
-      "code": 6004,
+      "code": 6006,
       "name": "InactiveAuctionFactory",
       "msg": "Activate auction factory before performing such action."
     },
     {
-      "code": 6005,
+      "code": 6007,
       "name": "TreasuryMismatch",
       "msg": "Treasury mismatch!"
     },
     {
-      "code": 6006,
+      "code": 6008,
       "name": "AuctionFactoryUuidInvalidLengthError",
       "msg": "Uuid must be length 5"
     },
     {
-      "code": 6007,
+      "code": 6009,
       "name": "ConfigUuidInvalidLengthError",
       "msg": "Uuid must be length 5"
     },
     {
-      "code": 6008,
+      "code": 6010,
+      "name": "InvalidAuctionFactoryData",
+      "msg": "Invalid auction factory data."
+    },
+    {
+      "code": 6011,
+      "name": "InvalidRevenueSplit",
+      "msg": "Revenue shares must be non-zero and total 10,000 basis points."
+    },
+    {
+      "code": 6012,
+      "name": "RevenueRecipientMismatch",
+      "msg": "Revenue recipient accounts do not match the revenue split."
+    },
+    {
+      "code": 6013,
+      "name": "UnsoldResourceRecipientRequired",
+      "msg": "Auction factories with a bid mint must set an unsold resource recipient."
+    },
+    {
+      "code": 6014,
+      "name": "UnsoldResourceRecipientMismatch",
+      "msg": "Unsold resource recipient mismatch."
+    },
+    {
+      "code": 6015,
+      "name": "NoPendingAuthority",
+      "msg": "Auction factory has no pending authority."
+    },
+    {
+      "code": 6016,
+      "name": "PendingAuthorityMismatch",
+      "msg": "Signer is not the auction factory's pending authority."
+    },
+    {
+      "code": 6017,
+      "name": "NoPendingChange",
+      "msg": "Auction factory has no pending change of this kind."
+    },
+    {
+      "code": 6018,
+      "name": "PendingChangeTimelocked",
+      "msg": "Pending change cannot be executed until its delay has passed."
+    },
+    {
+      "code": 6019,
       "name": "ConfigElementTooShortError",
       "msg": "Config element too short. Config data elements must be at least 1 char in length."
     },
     {
-      "code": 6009,
+      "code": 6020,
       "name": "ConfigElementTooLongError",
       "msg": "Config element too long. Must be less than max length!"
     },
     {
-      "code": 6010,
+      "code": 6021,
       "name": "InsufficientConfigError",
       "msg": "Insufficient config error!"
     },
     {
-      "code": 6011,
+      "code": 6022,
       "name": "InactiveAuction",
       "msg": "Auction is not in a state to perform such action."
     },
     {
-      "code": 6012,
+      "code": 6023,
       "name": "AuctionResourceAlreadyExists",
       "msg": "Auction resource can only be generated once."
     },
     {
-      "code": 6013,
+      "code": 6024,
+      "name": "AuctionResourceRolledOver",
+      "msg": "Auction resource was carried over from the previous auction."
+    },
+    {
+      "code": 6025,
       "name": "UnsettledAuction",
       "msg": "Must settle any ongoing auction before creating a new auction."
     },
     {
-      "code": 6014,
+      "code": 6026,
       "name": "AuctionAlreadySettled",
       "msg": "Auction is already settled."
     },
     {
-      "code": 6015,
+      "code": 6027,
       "name": "AuctionIsLive",
       "msg": "Auction is live and cannot be settled."
     },
     {
-      "code": 6016,
+      "code": 6028,
       "name": "InvalidBidAmount",
       "msg": "Bid must be a non-negative, non-zero amount. Bid must also beat previous bid by some percent."
     },
     {
-      "code": 6017,
+      "code": 6029,
       "name": "NoActiveAuction",
       "msg": "Cannot modify an auction that does not exist."
     },
     {
-      "code": 6018,
+      "code": 6030,
       "name": "AuctionAddressMismatch",
       "msg": "Auction address mismatch."
     },
     {
-      "code": 6019,
+      "code": 6031,
       "name": "AuctionsAlreadyInitialized",
       "msg": "Initialize auctions can only be called once."
     },
     {
-      "code": 6020,
+      "code": 6032,
       "name": "BidderAlreadyWinning",
       "msg": "Bidder is already winning the auction"
     },
     {
-      "code": 6021,
+      "code": 6033,
+      "name": "BidderNotWinning",
+      "msg": "Bidder is not the winning bidder."
+    },
+    {
+      "code": 6034,
       "name": "WrongSettleAuctionEndpoint",
       "msg": "Wrong settle auction endpoint!"
     },
     {
-      "code": 6022,
+      "code": 6035,
       "name": "AuctionHasNoResourceAvailable",
       "msg": "Must supply resource to auction before settling!"
     },
     {
-      "code": 6023,
+      "code": 6036,
+      "name": "AuctionNotSettled",
+      "msg": "Auction must be settled before performing such action."
+    },
+    {
+      "code": 6037,
+      "name": "WrongAuctionType",
+      "msg": "Instruction does not support this auction type."
+    },
+    {
+      "code": 6038,
+      "name": "PriceExceedsMaxPrice",
+      "msg": "Current price exceeds the max price the buyer is willing to pay."
+    },
+    {
+      "code": 6039,
+      "name": "AuctionCancelled",
+      "msg": "Auction has been cancelled."
+    },
+    {
+      "code": 6040,
+      "name": "AuctionVoid",
+      "msg": "Auction has been cancelled or voided."
+    },
+    {
+      "code": 6041,
+      "name": "AuctionEndedCannotBeCancelled",
+      "msg": "Auction has ended and can no longer be cancelled."
+    },
+    {
+      "code": 6042,
+      "name": "NoSettlementGracePeriod",
+      "msg": "Auction factory has no settlement grace period."
+    },
+    {
+      "code": 6043,
+      "name": "SettlementGracePeriodActive",
+      "msg": "Auction can still be settled."
+    },
+    {
+      "code": 6044,
+      "name": "CurrentAuctionCannotBeClosed",
+      "msg": "The auction factory's current auction cannot be closed."
+    },
+    {
+      "code": 6045,
+      "name": "AuctionTokenAccountNotClosed",
+      "msg": "Auction token account must be closed first."
+    },
+    {
+      "code": 6046,
+      "name": "AuctionHasEscrowedFunds",
+      "msg": "Auction still holds escrowed bids."
+    },
+    {
+      "code": 6047,
+      "name": "AuctionHasOpenBidRefunds",
+      "msg": "Auction still has open bid refund accounts."
+    },
+    {
+      "code": 6048,
+      "name": "NoRefundAvailable",
+      "msg": "Bidder has no funds available to claim from this auction."
+    },
+    {
+      "code": 6049,
+      "name": "BidRefundNotEmpty",
+      "msg": "Bid refund still holds funds to claim."
+    },
+    {
+      "code": 6050,
+      "name": "ProxyBidMismatch",
+      "msg": "Leading proxy bid account does not match the auction's proxy bidder."
+    },
+    {
+      "code": 6051,
+      "name": "SealedBidAlreadyCommitted",
+      "msg": "Bidder has already committed a sealed bid to this auction."
+    },
+    {
+      "code": 6052,
+      "name": "SealedBidNotCommitted",
+      "msg": "Bidder has no sealed bid to reveal."
+    },
+    {
+      "code": 6053,
+      "name": "SealedBidAlreadyRevealed",
+      "msg": "Sealed bid has already been revealed."
+    },
+    {
+      "code": 6054,
+      "name": "SealedBidCommitmentMismatch",
+      "msg": "Revealed bid does not match commitment."
+    },
+    {
+      "code": 6055,
+      "name": "SealedBidExceedsDeposit",
+      "msg": "Revealed bid exceeds deposit."
+    },
+    {
+      "code": 6056,
+      "name": "MultiWinnerAuctionNotSupported",
+      "msg": "Instruction does not support multi-winner auctions."
+    },
+    {
+      "code": 6057,
+      "name": "SingleWinnerAuction",
+      "msg": "Instruction only supports multi-winner auctions."
+    },
+    {
+      "code": 6058,
+      "name": "InvalidWinnerIndex",
+      "msg": "No winning bid at this index."
+    },
+    {
+      "code": 6059,
+      "name": "EditionAlreadyDistributed",
+      "msg": "Winner has already received their edition."
+    },
+    {
+      "code": 6060,
+      "name": "PrintEditionsNotDistributed",
+      "msg": "All print editions must be distributed before the master edition."
+    },
+    {
+      "code": 6061,
+      "name": "BidReceiptAddressMismatch",
+      "msg": "Bid receipt address mismatch."
+    },
+    {
+      "code": 6062,
       "name": "NumericalOverflowError",
       "msg": "Numerical overflow error!"
     },
     {
-      "code": 6024,
+      "code": 6063,
       "name": "NumericalUnderflowError",
       "msg": "Numerical underflow error!"
     },
     {
-      "code": 6025,
+      "code": 6064,
       "name": "CheckedRemError",
       "msg": "Checked REM error"
     },
     {
-      "code": 6026,
+      "code": 6065,
       "name": "NumericalDivisionError",
       "msg": "Numerical division error!"
     },
     {
-      "code": 6027,
+      "code": 6066,
       "name": "NotAuthorized",
       "msg": "Account is not authorized to take such action."
     },
     {
-      "code": 6028,
+      "code": 6067,
       "name": "PublicKeyMismatch",
       "msg": "Public key mismatch"
     },
     {
-      "code": 6029,
+      "code": 6068,
       "name": "TokenTransferFailed",
       "msg": "Token transfer failed"
     },
     {
-      "code": 6030,
+      "code": 6069,
       "name": "InsufficientAccountBalance",
       "msg": "Insufficient account balance!"
     },
     {
-      "code": 6031,
+      "code": 6070,
       "name": "ForcedError",
       "msg": "Forced error"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidSlotHashes",
+      "msg": "Unable to read slot hashes"
+    },
+    {
+      "code": 6072,
+      "name": "CandleCloseNotRequested",
+      "msg": "Candle auction close must be requested before settling."
+    },
+    {
+      "code": 6073,
+      "name": "CandleCloseAlreadyRequested",
+      "msg": "Candle auction close has already been requested."
+    },
+    {
+      "code": 6074,
+      "name": "CandleCloseSlotNotReached",
+      "msg": "Candle auction close slot has not been reached yet."
+    },
+    {
+      "code": 6075,
+      "name": "CandleCloseSlotExpired",
+      "msg": "Candle auction close slot is no longer available, request the close again."
     }
   ]
 };
@@ -1,11 +1,13 @@
 import * as anchor from "@project-serum/anchor";
 import * as lodash from "lodash";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import {
     PublicKey,
     SystemProgram,
     Keypair,
     LAMPORTS_PER_SOL,
+    SYSVAR_RENT_PUBKEY,
+    SYSVAR_SLOT_HASHES_PUBKEY,
 } from "@solana/web3.js";
 import * as assert from "assert";
 
@@ -112,7 +114,18 @@ describe("execute basic auction factory functions", async () => {
             updatedMinReservePrice
         );
 
+        // data change is queued until executed
         auctionFactoryAccount = await client.getAuctionFactory();
+        assert.ok(auctionFactoryAccount.pendingData);
+        assert.ok(
+            auctionFactoryAccount.data.minReservePrice.toNumber() !==
+                updatedMinReservePrice
+        );
+
+        await client.executePendingAuctionFactoryChange({ data: {} });
+
+        auctionFactoryAccount = await client.getAuctionFactory();
+        assert.ok(!auctionFactoryAccount.pendingData);
         assert.ok(
             auctionFactoryAccount.data.minReservePrice.toNumber() ===
                 updatedMinReservePrice
@@ -130,14 +143,110 @@ describe("execute basic auction factory functions", async () => {
         );
         let auctionFactoryAccount = await client.getAuctionFactory();
 
+        const treasuryBeforeUpdate = auctionFactoryAccount.treasury;
+
         await client.changeTreasury(updatedTreasury.publicKey);
 
+        // treasury change is queued until executed
         auctionFactoryAccount = await client.getAuctionFactory();
+        assert.ok(auctionFactoryAccount.pendingTreasury);
+        assert.ok(
+            auctionFactoryAccount.treasury.toString() ===
+                treasuryBeforeUpdate.toString()
+        );
+        assert.ok(
+            client.auctionFactory.treasury.toString() ===
+                treasuryBeforeUpdate.toString()
+        );
+
+        await client.executePendingAuctionFactoryChange({ treasury: {} });
 
+        auctionFactoryAccount = await client.getAuctionFactory();
+        assert.ok(!auctionFactoryAccount.pendingTreasury);
         assert.ok(
             auctionFactoryAccount.treasury.toString() ===
                 updatedTreasury.publicKey.toString()
         );
+        assert.ok(
+            client.auctionFactory.treasury.toString() ===
+                updatedTreasury.publicKey.toString()
+        );
+    });
+
+    it("propose a new authority and cancel the transfer", async () => {
+        const authorityBefore = client.auctionFactoryAuthority.publicKey;
+        const proposedAuthority = await client.nodeWallet.createFundedWallet(
+            0.1 * LAMPORTS_PER_SOL
+        );
+
+        await client.proposeNewAuthority(proposedAuthority.publicKey);
+
+        let auctionFactoryAccount = await client.getAuctionFactory();
+        assert.ok(
+            auctionFactoryAccount.pendingAuthority.toString() ===
+                proposedAuthority.publicKey.toString()
+        );
+        assert.ok(
+            auctionFactoryAccount.authority.toString() ===
+                authorityBefore.toString()
+        );
+
+        await client.cancelProposedAuthority();
+
+        auctionFactoryAccount = await client.getAuctionFactory();
+        assert.ok(!auctionFactoryAccount.pendingAuthority);
+
+        expectThrowsAsync(async () => {
+            await client.acceptAuthority(proposedAuthority);
+        }, "Auction factory has no pending authority.");
+
+        auctionFactoryAccount = await client.getAuctionFactory();
+        assert.ok(
+            auctionFactoryAccount.authority.toString() ===
+                authorityBefore.toString()
+        );
+    });
+
+    it("hand off auction factory authority in two steps", async () => {
+        const previousAuthority = client.auctionFactoryAuthority;
+        const newAuthority = await client.nodeWallet.createFundedWallet(
+            0.1 * LAMPORTS_PER_SOL
+        );
+        const fakeAuthority = await client.nodeWallet.createFundedWallet(
+            0.1 * LAMPORTS_PER_SOL
+        );
+
+        // only the current authority can propose
+        expectThrowsAsync(async () => {
+            await client.proposeNewAuthority(
+                fakeAuthority.publicKey,
+                fakeAuthority
+            );
+        }, "Account is not authorized to take such action.");
+
+        await client.proposeNewAuthority(newAuthority.publicKey);
+
+        // only the proposed authority can accept
+        expectThrowsAsync(async () => {
+            await client.acceptAuthority(fakeAuthority);
+        }, "Signer is not the auction factory's pending authority.");
+
+        await client.changeAuthority(newAuthority);
+
+        const auctionFactoryAccount = await client.getAuctionFactory();
+        assert.ok(
+            auctionFactoryAccount.authority.toString() ===
+                newAuthority.publicKey.toString()
+        );
+        assert.ok(!auctionFactoryAccount.pendingAuthority);
+
+        // previous authority no longer has control of the auction factory
+        expectThrowsAsync(async () => {
+            await client.proposeNewAuthority(
+                previousAuthority.publicKey,
+                previousAuthority
+            );
+        }, "Account is not authorized to take such action.");
     });
 
     it("Transfer excess auction factory lamports to treasury", async () => {
@@ -308,18 +417,9 @@ describe("execute basic auction factory functions", async () => {
         const auction = await client.getCurrentAuctionAddress();
         let auctionAccount = await client.fetchAuction(auction);
         const mint = new PublicKey(auctionAccount.resource);
-        const [bidderTokenAccount, bidderTokenAccountBump] =
-            await client.getAssociatedTokenAccountAddress(
-                auctionAccount.bidder,
-                mint
-            );
 
         await client.settleCurrentAuction(
             auctionFactoryAccount.sequence,
-            {
-                address: bidderTokenAccount,
-                bump: bidderTokenAccountBump,
-            },
             mint
         );
 
@@ -346,21 +446,12 @@ describe("execute basic auction factory functions", async () => {
         const auction = await client.getCurrentAuctionAddress();
         let auctionAccount = await client.fetchAuction(auction);
         const mint = new PublicKey(auctionAccount.resource.toString());
-        const [bidderTokenAccount, bidderTokenAccountBump] =
-            await client.getAssociatedTokenAccountAddress(
-                auctionAccount.bidder,
-                mint
-            );
 
         // no custom error here because anchor macros should prevent call from even executing.
         // e.g. Error: failed to send transaction: Transaction simulation failed: Error processing Instruction 0: custom program error: 0x7d3
         expectThrowsAsync(async () => {
             await client.settleCurrentAuction(
                 auctionFactoryAccount.sequence,
-                {
-                    address: bidderTokenAccount,
-                    bump: bidderTokenAccountBump,
-                },
                 mint
             );
         });
@@ -489,8 +580,9 @@ describe("execute basic auction factory functions", async () => {
 
         const auctionBalanceAfter = await client.getBalance(auction);
         assert.ok(auctionBalanceAfter > auctionBalanceBefore);
+        // outbid funds stay escrowed in the auction until the previous bidder claims their refund
         assert.ok(
-            new BN(auctionBalanceAfter - auctionBalanceBefore).eq(bidAmountInLamports.sub(auctionAccount.amount))
+            new BN(auctionBalanceAfter - auctionBalanceBefore).eq(bidAmountInLamports)
         );
 
         auctionAccount = await client.fetchAuction(auction);
@@ -536,6 +628,44 @@ describe("execute basic auction factory functions", async () => {
         assert.ok(auctionAccount.amount.eq(auctionAccount.amount));
     });
 
+    it("outbid bidder claims their refund, and fails to claim it twice", async () => {
+        let auctionFactoryAccount = await client.getAuctionFactory();
+        const seq = auctionFactoryAccount.sequence;
+        const auction = await client.getAuctionAddressWithSequence(seq);
+
+        const outbidBidder = await client.nodeWallet.createFundedWallet(
+            0.1 * LAMPORTS_PER_SOL
+        );
+        const winningBidder = await client.nodeWallet.createFundedWallet(
+            0.1 * LAMPORTS_PER_SOL
+        );
+        const outbidAmount = new BN(200);
+        await client.placeBidOnAuction(seq, outbidAmount, outbidBidder);
+        await client.placeBidOnAuction(seq, new BN(300), winningBidder);
+
+        let bidRefund = await client.getBidRefund(seq, outbidBidder.publicKey);
+        assert.ok(bidRefund.amount.eq(outbidAmount));
+
+        // the leading bid is not refundable
+        expectThrowsAsync(async () => {
+            await client.claimBidRefund(seq, winningBidder);
+        }, "Bidder has no funds available to claim from this auction.");
+
+        const auctionBalanceBefore = await client.getBalance(auction);
+        await client.claimBidRefund(seq, outbidBidder);
+        const auctionBalanceAfter = await client.getBalance(auction);
+        assert.ok(
+            new BN(auctionBalanceBefore - auctionBalanceAfter).eq(outbidAmount)
+        );
+
+        bidRefund = await client.getBidRefund(seq, outbidBidder.publicKey);
+        assert.ok(bidRefund.amount.eq(BN_ZERO));
+
+        expectThrowsAsync(async () => {
+            await client.claimBidRefund(seq, outbidBidder);
+        }, "Bidder has no funds available to claim from this auction.");
+    });
+
     it("spin until auction can be settled", async () => {
         await waitForAuctionToEnd(client, 3, true);
     });
@@ -550,16 +680,13 @@ describe("execute basic auction factory functions", async () => {
         const bidder = await client.nodeWallet.createFundedWallet(
             0.1 * LAMPORTS_PER_SOL
         );
-        const newValidBidAmount = Math.round(
-            auctionAccount.amount.toNumber() *
-                (1 +
-                    auctionFactoryAccount.data.minBidPercentageIncrease.toNumber() /
-                        100)
+        const newValidBidAmount = await client.getMinimumBid(
+            auctionFactoryAccount.sequence
         );
         expectThrowsAsync(async () => {
             await client.placeBidOnAuction(
                 auctionFactoryAccount.sequence,
-                new BN(newValidBidAmount),
+                newValidBidAmount,
                 bidder
             );
         }, "Auction is not in a state to perform such action.");
@@ -581,7 +708,7 @@ describe("execute basic auction factory functions", async () => {
 
         let auctionAccount = await client.fetchCurrentAuction();
         const mint = new PublicKey(auctionAccount.resource);
-        const [bidderTokenAccount, bidderTokenAccountBump] =
+        const [bidderTokenAccount, _bidderTokenAccountBump] =
             await client.getAssociatedTokenAccountAddress(
                 auctionAccount.bidder,
                 mint
@@ -600,7 +727,6 @@ describe("execute basic auction factory functions", async () => {
         // unable to get custom error here. ixn wrong or anchor macro?
         expectThrowsAsync(async () => {
             await client.program.rpc.settleAuction(
-                bidderTokenAccountBump,
                 client.auctionFactory.config.bump,
                 client.auctionFactory.config.seed,
                 auctionBump,
@@ -608,16 +734,22 @@ describe("execute basic auction factory functions", async () => {
                 {
                     accounts: {
                         payer: settler.publicKey,
-                        treasury: client.auctionFactory.treasury,
-                        metadata,
                         auctionFactory: client.auctionFactory.config.address,
                         auction,
                         mint,
+                        treasury: client.auctionFactory.treasury,
+                        metadata,
+                        bidder: auctionAccount.bidder,
                         bidderTokenAccount: bidderTokenAccount,
                         auctionTokenAccount,
+                        auctionBidTokenAccount: settler.publicKey,
+                        payerBidTokenAccount: settler.publicKey,
+                        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
                         tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
                         tokenProgram: TOKEN_PROGRAM_ID,
+                        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
                         systemProgram: SystemProgram.programId,
+                        rent: SYSVAR_RENT_PUBKEY,
                     },
                     signers: [settler],
                 }
@@ -638,7 +770,7 @@ describe("execute basic auction factory functions", async () => {
             auctionAccount.sequence
         );
         const mint = new PublicKey(auctionAccount.resource);
-        const [bidderTokenAccount, bidderTokenAccountBump] =
+        const [bidderTokenAccount, _bidderTokenAccountBump] =
             await client.getAssociatedTokenAccountAddress(
                 auctionAccount.bidder,
                 mint
@@ -652,7 +784,6 @@ describe("execute basic auction factory functions", async () => {
         const seq = new anchor.BN(auctionAccount.sequence);
         expectThrowsAsync(async () => {
             await client.program.rpc.settleAuction(
-                bidderTokenAccountBump,
                 client.auctionFactory.config.bump,
                 client.auctionFactory.config.seed,
                 auctionPda.bump,
@@ -660,16 +791,22 @@ describe("execute basic auction factory functions", async () => {
                 {
                     accounts: {
                         payer: settler.publicKey,
-                        treasury: wrongTreasury.publicKey,
-                        metadata,
                         auctionFactory: client.auctionFactory.config.address,
                         auction: auctionPda.addr,
                         mint,
+                        treasury: wrongTreasury.publicKey,
+                        metadata,
+                        bidder: auctionAccount.bidder,
                         bidderTokenAccount: bidderTokenAccount,
                         auctionTokenAccount,
+                        auctionBidTokenAccount: settler.publicKey,
+                        payerBidTokenAccount: settler.publicKey,
+                        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
                         tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
                         tokenProgram: TOKEN_PROGRAM_ID,
+                        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
                         systemProgram: SystemProgram.programId,
+                        rent: SYSVAR_RENT_PUBKEY,
                     },
                     signers: [settler],
                 }
@@ -677,26 +814,18 @@ describe("execute basic auction factory functions", async () => {
         });
     });
 
-    it("attempt to settle auction with invalid bidder token account", async () => {
+    it("attempt to settle auction for a bidder that did not win", async () => {
         const auctionAccount = await client.fetchCurrentAuction();
         const mint = new PublicKey(auctionAccount.resource);
         const fakeAuctionWinner = Keypair.generate();
-        const [bidderTokenAccount, bidderTokenAccountBump] =
-            await client.getAssociatedTokenAccountAddress(
-                fakeAuctionWinner.publicKey,
-                mint
-            );
 
         expectThrowsAsync(async () => {
             await client.settleCurrentAuction(
                 auctionAccount.sequence,
-                {
-                    address: bidderTokenAccount,
-                    bump: bidderTokenAccountBump,
-                },
-                mint
+                mint,
+                fakeAuctionWinner.publicKey
             );
-        }, "Account does not have correct owner!");
+        }, "Bidder is not the winning bidder.");
     });
 
     it("settle auction", async () => {
@@ -704,7 +833,7 @@ describe("execute basic auction factory functions", async () => {
         const auction = await client.getCurrentAuctionAddress();
         let auctionAccount = await client.fetchCurrentAuction();
         const mint = new PublicKey(auctionAccount.resource);
-        const [bidderTokenAccount, bidderTokenAccountBump] =
+        const [bidderTokenAccount, _bidderTokenAccountBump] =
             await client.getAssociatedTokenAccountAddress(
                 auctionAccount.bidder,
                 mint
@@ -712,10 +841,6 @@ describe("execute basic auction factory functions", async () => {
 
         await client.settleCurrentAuction(
             auctionAccount.sequence,
-            {
-                address: bidderTokenAccount,
-                bump: bidderTokenAccountBump,
-            },
             mint
         );
 
@@ -0,0 +1,181 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv, sleep } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test auction start times
+    //
+    // auctions start no sooner than cooldown seconds after the previous auction ended, and
+    // on the next scheduled start time if the factory has a schedule. bids are rejected
+    // until the start time.
+    // ============================================================================
+    const waitForAuctionToStart = async (auctionAccount: any) => {
+        while (
+            new Date().getTime() / 1000 <=
+            auctionAccount.startTime.toNumber()
+        ) {
+            await sleep(1000);
+        }
+        // give the validator clock a moment to catch up
+        await sleep(2 * 1000);
+    };
+
+    describe("delay the next auction by the cooldown", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 5;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+        const cooldownInSeconds = 15;
+
+        it("initialize auction factory with a cooldown & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                { cooldown: new BN(cooldownInSeconds) }
+            );
+            await client.createNextAuctionWithResource();
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(
+                auctionFactoryAccount.sequence,
+                new BN(100),
+                bidder
+            );
+        });
+
+        it("next auction starts once the cooldown has passed", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const previousAuctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            await client.settleCurrentAuction(
+                sequence,
+                new PublicKey(previousAuctionAccount.resource)
+            );
+            const settledAuctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+
+            const { auction } = await client.createNextAuctionWithResource();
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(
+                auctionAccount.startTime.toNumber() ===
+                    settledAuctionAccount.finalizedEndTime.toNumber() +
+                        cooldownInSeconds
+            );
+            assert.ok(
+                auctionAccount.endTime.toNumber() ===
+                    auctionAccount.startTime.toNumber() + durationInSeconds
+            );
+        });
+
+        it("attempt to bid before the auction starts, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.placeBidOnAuction(
+                    auctionFactoryAccount.sequence,
+                    new BN(100),
+                    bidder
+                );
+            }, "Auction is not in a state to perform such action.");
+        });
+
+        it("bid once the auction has started", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            await waitForAuctionToStart(
+                await client.fetchAuctionWithSequence(sequence)
+            );
+
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(sequence, new BN(100), bidder);
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(
+                auctionAccount.bidder.toString() === bidder.publicKey.toString()
+            );
+        });
+    });
+
+    describe("start auctions on schedule", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 10;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+        const intervalInSeconds = 20;
+        const offsetInSeconds = 5;
+
+        it("initialize scheduled auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                {
+                    schedule: {
+                        interval: new BN(intervalInSeconds),
+                        offset: new BN(offsetInSeconds),
+                    },
+                }
+            );
+            const createdAt = Math.floor(new Date().getTime() / 1000);
+            const { auction } = await client.createNextAuctionWithResource();
+
+            // the first scheduled start time after the auction was created
+            const auctionAccount = await client.fetchAuction(auction);
+            const startTime = auctionAccount.startTime.toNumber();
+            assert.ok(startTime % intervalInSeconds === offsetInSeconds);
+            assert.ok(startTime < createdAt + 2 * intervalInSeconds);
+        });
+
+        it("bid once the scheduled start time has passed", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            await waitForAuctionToStart(
+                await client.fetchAuctionWithSequence(sequence)
+            );
+
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(sequence, new BN(100), bidder);
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(auctionAccount.amount.toNumber() === 100);
+        });
+    });
+}
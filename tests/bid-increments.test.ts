@@ -0,0 +1,111 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test minimum bid increments
+    //
+    // the required step over the current bid is the largest of the basis point increase,
+    // the absolute minimum increment, and the increment of the highest tier reached.
+    // ============================================================================
+    describe("enforce minimum bid increments", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 60;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 500; // 5%
+        const minReservePrice = 50;
+        const minBidIncrement = 20;
+        const tierThreshold = 1000;
+        const tierIncrement = 100;
+
+        const placeBid = async (amount: number) => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(
+                auctionFactoryAccount.sequence,
+                new BN(amount),
+                bidder
+            );
+        };
+
+        const getMinimumBid = async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const minimumBid = await client.getMinimumBid(
+                auctionFactoryAccount.sequence
+            );
+
+            return minimumBid.toNumber();
+        };
+
+        it("initialize auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                {
+                    minBidIncrement: new BN(minBidIncrement),
+                    bidIncrementTiers: [
+                        {
+                            threshold: new BN(tierThreshold),
+                            increment: new BN(tierIncrement),
+                        },
+                        { threshold: new BN(0), increment: new BN(0) },
+                        { threshold: new BN(0), increment: new BN(0) },
+                        { threshold: new BN(0), increment: new BN(0) },
+                    ],
+                }
+            );
+            await client.createNextAuctionWithResource();
+        });
+
+        it("opening bid must meet the reserve price", async () => {
+            assert.ok((await getMinimumBid()) === minReservePrice);
+
+            expectThrowsAsync(async () => {
+                await placeBid(minReservePrice - 1);
+            }, "Bid must be a non-negative, non-zero amount. Bid must also beat previous bid by some percent.");
+        });
+
+        it("absolute minimum increment applies when it exceeds the basis point increase", async () => {
+            await placeBid(100);
+
+            // 5% of 100 is 5, less than the minimum increment
+            assert.ok((await getMinimumBid()) === 100 + minBidIncrement);
+
+            expectThrowsAsync(async () => {
+                await placeBid(100 + minBidIncrement - 1);
+            }, "Bid must be a non-negative, non-zero amount. Bid must also beat previous bid by some percent.");
+        });
+
+        it("tier increment applies once the current bid reaches its threshold", async () => {
+            await placeBid(tierThreshold);
+
+            // 5% of 1000 is 50, less than the tier increment
+            assert.ok((await getMinimumBid()) === tierThreshold + tierIncrement);
+        });
+
+        it("basis point increase applies when it is the largest step", async () => {
+            await placeBid(10000);
+
+            // 5% of 10000 is 500
+            assert.ok((await getMinimumBid()) === 10500);
+
+            await placeBid(10500);
+            const auctionAccount = await client.fetchCurrentAuction();
+            assert.ok(auctionAccount.amount.toNumber() === 10500);
+        });
+    });
+}
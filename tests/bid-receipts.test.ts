@@ -0,0 +1,142 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test bid receipts
+    //
+    // bidders can optionally record a receipt account per bid, keyed by the auction and the
+    // bid's index. receipts can be closed by anyone after settlement, returning rent to the
+    // account that paid for them.
+    // ============================================================================
+    describe("record and close bid receipts", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 10;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+
+        let firstBidder;
+        let secondBidder;
+
+        it("initialize auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice
+            );
+            await client.createNextAuctionWithResource();
+        });
+
+        it("record a receipt for each bid", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            firstBidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            secondBidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(
+                sequence,
+                new BN(100),
+                firstBidder,
+                true
+            );
+            await client.placeBidOnAuction(
+                sequence,
+                new BN(200),
+                secondBidder,
+                true
+            );
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(auctionAccount.bidCount.toNumber() === 2);
+
+            const firstReceipt = await client.getBidReceipt(
+                sequence,
+                new BN(0)
+            );
+            assert.ok(
+                firstReceipt.bidder.toString() ===
+                    firstBidder.publicKey.toString()
+            );
+            assert.ok(firstReceipt.amount.toNumber() === 100);
+            assert.ok(
+                firstReceipt.payer.toString() ===
+                    firstBidder.publicKey.toString()
+            );
+
+            const secondReceipt = await client.getBidReceipt(
+                sequence,
+                new BN(1)
+            );
+            assert.ok(
+                secondReceipt.bidder.toString() ===
+                    secondBidder.publicKey.toString()
+            );
+            assert.ok(secondReceipt.amount.toNumber() === 200);
+            assert.ok(secondReceipt.index.toNumber() === 1);
+        });
+
+        it("attempt to close a receipt before settlement, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const payer = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.closeBidReceipt(
+                    auctionFactoryAccount.sequence,
+                    new BN(0),
+                    payer
+                );
+            }, "Auction must be settled before performing such action.");
+        });
+
+        it("close a receipt after settlement and return rent to the bidder", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            let auctionAccount = await client.fetchCurrentAuction();
+            const sequence = auctionAccount.sequence;
+            await client.settleCurrentAuction(
+                sequence,
+                new PublicKey(auctionAccount.resource)
+            );
+
+            const auction = await client.getCurrentAuctionAddress();
+            const [bidReceipt, _bidReceiptBump] =
+                await client.findBidReceiptPda(auction, new BN(0));
+            const receiptRent = await client.getBalance(bidReceipt);
+            const bidderBalanceBefore = await client.getBalance(
+                firstBidder.publicKey
+            );
+
+            const payer = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.closeBidReceipt(sequence, new BN(0), payer);
+
+            const bidderBalanceAfter = await client.getBalance(
+                firstBidder.publicKey
+            );
+            assert.ok(bidderBalanceAfter - bidderBalanceBefore === receiptRent);
+            assert.ok((await client.getBalance(bidReceipt)) === 0);
+        });
+    });
+}
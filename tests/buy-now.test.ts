@@ -0,0 +1,136 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test buy it now
+    //
+    // a bid at or above the buy it now price wins outright. the auction stops accepting bids
+    // and can be settled right away. bids above the price only pay the price.
+    // ============================================================================
+    describe("win an auction at the buy it now price", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 600;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+        const buyNowPrice = new BN(500);
+
+        let buyer;
+        const buyerBid = new BN(600);
+
+        it("initialize buy it now auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                { buyNowPrice }
+            );
+            const { auction } = await client.createNextAuctionWithResource();
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.buyNowPrice.eq(buyNowPrice));
+        });
+
+        it("bid below the buy it now price leaves the auction open", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(sequence, new BN(100), bidder);
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(auctionAccount.finalizedEndTime.toNumber() === 0);
+        });
+
+        it("bid above the buy it now price wins at the buy it now price", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            buyer = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(sequence, buyerBid, buyer);
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(auctionAccount.amount.eq(buyNowPrice));
+            assert.ok(
+                auctionAccount.bidder.toString() === buyer.publicKey.toString()
+            );
+            assert.ok(auctionAccount.finalizedEndTime.toNumber() > 0);
+            assert.ok(
+                auctionAccount.finalizedEndTime.lt(auctionAccount.endTime)
+            );
+        });
+
+        it("attempt to bid after the buy it now price was met, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.placeBidOnAuction(
+                    auctionFactoryAccount.sequence,
+                    buyerBid.add(new BN(100)),
+                    bidder
+                );
+            });
+        });
+
+        it("settle the auction before its end time", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            let auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+
+            const treasuryBalanceBefore = await client.getTreasuryBalance();
+            await client.settleCurrentAuction(
+                sequence,
+                new PublicKey(auctionAccount.resource)
+            );
+            const treasuryBalanceAfter = await client.getTreasuryBalance();
+            assert.ok(
+                new BN(treasuryBalanceAfter - treasuryBalanceBefore).eq(
+                    buyNowPrice
+                )
+            );
+
+            auctionAccount = await client.fetchAuctionWithSequence(sequence);
+            assert.ok(auctionAccount.settled === true);
+        });
+
+        it("buyer claims the part of their bid above the buy it now price", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+
+            const auctionBalanceBefore = await client.getBalance(auction);
+            await client.claimBidRefund(sequence, buyer);
+            const auctionBalanceAfter = await client.getBalance(auction);
+            assert.ok(
+                new BN(auctionBalanceBefore - auctionBalanceAfter).eq(
+                    buyerBid.sub(buyNowPrice)
+                )
+            );
+        });
+    });
+}
@@ -0,0 +1,186 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test cancelling auctions
+    //
+    // the authority can cancel an auction that is still taking bids. its resource is burned or
+    // returned to the authority, and every bidder, including the leader, withdraws their full escrow.
+    // ============================================================================
+    describe("cancel an auction and refund every bidder", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 600;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+
+        const bids = [];
+
+        it("initialize auction factory & first auction with bids", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice
+            );
+            await client.createNextAuctionWithResource();
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            for (const amount of [100, 200]) {
+                const bidder = await client.nodeWallet.createFundedWallet(
+                    0.1 * LAMPORTS_PER_SOL
+                );
+                await client.placeBidOnAuction(
+                    auctionFactoryAccount.sequence,
+                    new BN(amount),
+                    bidder
+                );
+                bids.push({ bidder, amount: new BN(amount) });
+            }
+        });
+
+        it("non-authority attempts to cancel the auction, and fails 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const payer = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.cancelCurrentAuction(
+                    auctionFactoryAccount.sequence,
+                    false,
+                    payer
+                );
+            });
+        });
+
+        it("authority cancels the auction and the resource returns to the authority", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+
+            await client.cancelCurrentAuction(sequence);
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.cancelled === true);
+            assert.ok(auctionAccount.finalizedEndTime.toNumber() > 0);
+
+            const [authorityTokenAccount, _authorityTokenAccountBump] =
+                await client.getAssociatedTokenAccountAddress(
+                    client.auctionFactoryAuthority.publicKey,
+                    auctionAccount.resource
+                );
+            assert.ok(
+                (await client.getTokenAccountBalance(authorityTokenAccount)) ===
+                    1
+            );
+            assert.ok(
+                (await client.getAuctionTokenAccountBalance(
+                    auction,
+                    auctionAccount.resource
+                )) === 0
+            );
+        });
+
+        it("attempt to bid on or cancel a cancelled auction, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.placeBidOnAuction(sequence, new BN(300), bidder);
+            });
+
+            expectThrowsAsync(async () => {
+                await client.cancelCurrentAuction(sequence);
+            }, "Auction has been cancelled.");
+        });
+
+        it("every bidder, including the leader, claims their full bid", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+
+            for (const bid of bids) {
+                const auctionBalanceBefore = await client.getBalance(auction);
+                await client.claimBidRefund(sequence, bid.bidder);
+                const auctionBalanceAfter = await client.getBalance(auction);
+                assert.ok(
+                    new BN(auctionBalanceBefore - auctionBalanceAfter).eq(
+                        bid.amount
+                    )
+                );
+            }
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.escrowedAmount.toNumber() === 0);
+        });
+
+        it("create the next auction after cancellation", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const { sequence, auction } =
+                await client.createNextAuctionWithResource();
+            assert.ok(sequence.eq(auctionFactoryAccount.sequence.add(new BN(1))));
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.cancelled === false);
+            assert.ok(auctionAccount.resource);
+        });
+    });
+
+    describe("attempt to cancel an auction after it ended", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 5;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+
+        it("initialize auction factory & first auction with a bid", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice
+            );
+            const { sequence } = await client.createNextAuctionWithResource();
+
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(sequence, new BN(100), bidder);
+        });
+
+        it("authority attempts to cancel the ended auction, and fails 😈", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const auction = await client.getCurrentAuctionAddress();
+
+            expectThrowsAsync(async () => {
+                await client.cancelCurrentAuction(
+                    auctionFactoryAccount.sequence
+                );
+            }, "Auction has ended and can no longer be cancelled.");
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.cancelled === false);
+        });
+    });
+}
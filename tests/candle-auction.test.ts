@@ -0,0 +1,187 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test candle auctions
+    //
+    // the auction closes at a random moment of its ending period, picked from the hash of a slot
+    // requested once bidding is over. the leader at that moment wins, and every other bid is
+    // refundable.
+    // ============================================================================
+    describe("settle candle auctions", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 20;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+        const endingPeriodInSeconds = 20;
+
+        const bids = [];
+
+        it("initialize candle auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                {
+                    auctionType: { candle: {} },
+                    candle: { endingPeriod: new BN(endingPeriodInSeconds) },
+                }
+            );
+            const { auction } = await client.createNextAuctionWithResource();
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok("candle" in auctionAccount.auctionType);
+            assert.ok(
+                auctionAccount.candleStartTime.toNumber() ===
+                    auctionAccount.endTime.toNumber() - endingPeriodInSeconds
+            );
+        });
+
+        it("place bids during the ending period", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            for (const amount of [100, 200, 300]) {
+                const bidder = await client.nodeWallet.createFundedWallet(
+                    0.1 * LAMPORTS_PER_SOL
+                );
+                await client.placeBidOnAuction(sequence, new BN(amount), bidder);
+                bids.push({ bidder, amount: new BN(amount) });
+            }
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(auctionAccount.amount.toNumber() === 300);
+            assert.ok((auctionAccount.candleCheckpoints as any[]).length > 0);
+        });
+
+        it("outbid bidder attempts to claim a refund before settlement, and fails 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+
+            // any bid could still turn out to be the winning bid
+            expectThrowsAsync(async () => {
+                await client.claimBidRefund(
+                    auctionFactoryAccount.sequence,
+                    bids[0].bidder
+                );
+            }, "Bidder has no funds available to claim from this auction.");
+        });
+
+        it("attempt to request the close while bidding is open, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+
+            expectThrowsAsync(async () => {
+                await client.requestCandleAuctionClose(
+                    auctionFactoryAccount.sequence
+                );
+            }, "Auction is live and cannot be settled.");
+        });
+
+        it("attempt to settle before the close is requested, and fail 😈", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+
+            expectThrowsAsync(async () => {
+                await client.settleCurrentAuction(
+                    sequence,
+                    new PublicKey(auctionAccount.resource),
+                    auctionAccount.bidder
+                );
+            }, "Candle auction close must be requested before settling.");
+        });
+
+        it("attempt to request the close twice, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            await client.requestCandleAuctionClose(sequence);
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(auctionAccount.candleCloseSlot.gtn(0));
+
+            expectThrowsAsync(async () => {
+                await client.requestCandleAuctionClose(sequence);
+            }, "Candle auction close has already been requested.");
+        });
+
+        it("settle the auction at a random close time", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            let auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+
+            await client.settleCandleAuction(
+                sequence,
+                new PublicKey(auctionAccount.resource)
+            );
+
+            auctionAccount = await client.fetchAuctionWithSequence(sequence);
+            assert.ok(auctionAccount.settled === true);
+            assert.ok(
+                auctionAccount.finalizedEndTime.toNumber() >
+                    auctionAccount.candleStartTime.toNumber()
+            );
+            assert.ok(
+                auctionAccount.finalizedEndTime.toNumber() <=
+                    auctionAccount.endTime.toNumber()
+            );
+
+            // the candle may close before the first bid, leaving no winner
+            if (auctionAccount.amount.gtn(0)) {
+                const winningBid = bids.find(
+                    (bid) =>
+                        bid.bidder.publicKey.toString() ===
+                        auctionAccount.bidder.toString()
+                );
+                assert.ok(winningBid);
+                assert.ok(auctionAccount.amount.eq(winningBid.amount));
+            }
+        });
+
+        it("every other bidder claims their full bid after settlement", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+            const auctionAccount = await client.fetchAuction(auction);
+
+            const losingBids = bids.filter(
+                (bid) =>
+                    auctionAccount.amount.eqn(0) ||
+                    bid.bidder.publicKey.toString() !==
+                        auctionAccount.bidder.toString()
+            );
+            for (const bid of losingBids) {
+                const auctionBalanceBefore = await client.getBalance(auction);
+                await client.claimBidRefund(sequence, bid.bidder);
+                const auctionBalanceAfter = await client.getBalance(auction);
+                assert.ok(
+                    new BN(auctionBalanceBefore - auctionBalanceAfter).eq(
+                        bid.amount
+                    )
+                );
+            }
+        });
+    });
+}
@@ -0,0 +1,148 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test closing auctions
+    //
+    // once an auction is settled, its token account closed, every bid paid out or claimed, and
+    // every bid refund account closed, anyone can close it. a compact summary of the auction is
+    // left in its place, and the auction's rent goes to the treasury.
+    // ============================================================================
+    describe("close a settled auction and archive its summary", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 10;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+
+        let sequence: BN;
+        let mint: PublicKey;
+        let outbidBidder;
+        let winner;
+
+        it("initialize auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice
+            );
+            const auctionData = await client.createNextAuctionWithResource();
+            sequence = auctionData.sequence;
+            mint = auctionData.mint.publicKey;
+
+            outbidBidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            winner = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(sequence, new BN(100), outbidBidder);
+            await client.placeBidOnAuction(sequence, new BN(200), winner);
+        });
+
+        it("attempt to close the current auction, and fail 😈", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+            await client.settleCurrentAuction(sequence, mint);
+
+            expectThrowsAsync(async () => {
+                await client.closeSettledAuction(sequence);
+            }, "The auction factory's current auction cannot be closed.");
+        });
+
+        it("attempt to close an auction with an open token account, and fail 😈", async () => {
+            await client.initAuction(sequence.add(new BN(1)));
+
+            expectThrowsAsync(async () => {
+                await client.closeSettledAuction(sequence);
+            }, "Auction token account must be closed first.");
+        });
+
+        it("attempt to close an auction with unclaimed refunds, and fail 😈", async () => {
+            const pdaData = await client.fetchAuctionPdaData(sequence);
+            const [auctionTokenAccount, _auctionTokenAccountBump] =
+                await client.getAssociatedTokenAccountAddress(
+                    pdaData.addr,
+                    mint
+                );
+            await client.closeAuctionATA(
+                pdaData.addr,
+                pdaData.bump,
+                sequence,
+                auctionTokenAccount
+            );
+
+            expectThrowsAsync(async () => {
+                await client.closeSettledAuction(sequence);
+            }, "Auction still holds escrowed bids.");
+        });
+
+        it("attempt to close an auction with open bid refund accounts, and fail 😈", async () => {
+            // claiming the outbid bid also closes the outbid bidder's refund account
+            await client.claimBidRefund(sequence, outbidBidder);
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(auctionAccount.escrowedAmount.toNumber() === 0);
+            assert.ok(auctionAccount.openBidRefunds.toNumber() === 1);
+
+            expectThrowsAsync(async () => {
+                await client.closeSettledAuction(sequence);
+            }, "Auction still has open bid refund accounts.");
+        });
+
+        it("close the auction once every refund account is closed", async () => {
+            // anyone can close the winner's emptied refund account, its rent goes to the winner
+            const winnerBalanceBefore = await client.getBalance(
+                winner.publicKey
+            );
+            await client.closeEmptiedBidRefund(sequence, winner.publicKey);
+            assert.ok(
+                (await client.getBalance(winner.publicKey)) >
+                    winnerBalanceBefore
+            );
+
+            const auction = await client.getAuctionAddressWithSequence(
+                sequence
+            );
+            const treasuryBalanceBefore = await client.getBalance(
+                client.auctionFactory.treasury
+            );
+
+            const auctionSummary = await client.closeSettledAuction(sequence);
+
+            assert.ok(
+                (await client.provider.connection.getAccountInfo(auction)) ===
+                    null
+            );
+            assert.ok(
+                (await client.getBalance(client.auctionFactory.treasury)) >
+                    treasuryBalanceBefore
+            );
+
+            const auctionSummaryAccount = await client.fetchAuctionSummary(
+                auctionSummary
+            );
+            assert.ok(auctionSummaryAccount.sequence.eq(sequence));
+            assert.ok(
+                auctionSummaryAccount.winner.toString() ===
+                    winner.publicKey.toString()
+            );
+            assert.ok(auctionSummaryAccount.amount.toNumber() === 200);
+            assert.ok(auctionSummaryAccount.mint.toString() === mint.toString());
+        });
+    });
+}
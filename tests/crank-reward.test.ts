@@ -0,0 +1,112 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { AccountLayout } from "@solana/spl-token";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test crank rewards
+    //
+    // whoever settles an auction is paid a fixed reward out of the auction factory's lamports,
+    // plus a share of the winning bid in basis points. the rest goes to the treasury.
+    // ============================================================================
+    describe("reward the settler of an auction", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 10;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+        const fixedAmount = 10000;
+        const winningBidShare = 1000; // 10%
+        const winningBid = 0.05 * LAMPORTS_PER_SOL;
+
+        it("initialize auction factory with a crank reward & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                {
+                    crankReward: {
+                        fixedAmount: new BN(fixedAmount),
+                        winningBidShare: new BN(winningBidShare),
+                    },
+                }
+            );
+            await client.createNextAuctionWithResource();
+
+            // fixed rewards are paid out of the auction factory's lamports
+            await client.nodeWallet.fundWallet(
+                client.auctionFactory.config.address,
+                0.01 * LAMPORTS_PER_SOL
+            );
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(
+                auctionFactoryAccount.sequence,
+                new BN(winningBid),
+                bidder
+            );
+        });
+
+        it("settler receives the fixed reward and a share of the winning bid", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+
+            const settler = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            const settlerBalanceBefore = await client.getBalance(
+                settler.publicKey
+            );
+            const treasuryBalanceBefore = await client.getTreasuryBalance();
+            const auctionFactoryBalanceBefore =
+                await client.getAuctionFactoryBalance();
+
+            await client.settleAuction(
+                sequence,
+                new PublicKey(auctionAccount.resource),
+                settler
+            );
+
+            // the settler also pays for the winner's resource token account
+            const tokenAccountRent =
+                await client.provider.connection.getMinimumBalanceForRentExemption(
+                    AccountLayout.span
+                );
+            const reward = (winningBid * winningBidShare) / 10000;
+            assert.ok(
+                (await client.getBalance(settler.publicKey)) -
+                    settlerBalanceBefore ===
+                    fixedAmount + reward - tokenAccountRent
+            );
+            assert.ok(
+                (await client.getTreasuryBalance()) - treasuryBalanceBefore ===
+                    winningBid - reward
+            );
+            assert.ok(
+                auctionFactoryBalanceBefore -
+                    (await client.getAuctionFactoryBalance()) ===
+                    fixedAmount
+            );
+        });
+    });
+}
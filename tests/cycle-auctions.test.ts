@@ -149,18 +149,8 @@ if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
                 await waitForAuctionToEnd(client, 1, true);
 
                 // 3. settle auction
-                const [bidderTokenAccount, bidderTokenAccountBump] =
-                    await client.getAssociatedTokenAccountAddress(
-                        auctionAccount.bidder,
-                        mint.publicKey
-                    );
-
                 await client.settleCurrentAuction(
                     seq,
-                    {
-                        address: bidderTokenAccount,
-                        bump: bidderTokenAccountBump,
-                    },
                     mint.publicKey
                 );
 
@@ -0,0 +1,142 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test dutch auctions
+    //
+    // the price decays from the start price to the floor price over the auction's duration.
+    // the first buyer at the current price wins, and the auction settles in the same instruction.
+    // ============================================================================
+    describe("buy from a dutch auction", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 60;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+        const startPrice = new BN(100000);
+        const floorPrice = new BN(10000);
+
+        it("initialize dutch auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                {
+                    auctionType: { dutch: {} },
+                    dutchAuction: {
+                        startPrice,
+                        floorPrice,
+                        curve: { linear: {} },
+                        decayRate: new BN(0),
+                        decayInterval: new BN(0),
+                    },
+                }
+            );
+            const { auction } = await client.createNextAuctionWithResource();
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok("dutch" in auctionAccount.auctionType);
+            assert.ok(auctionAccount.dutchAuction.startPrice.eq(startPrice));
+        });
+
+        it("attempt to place a bid on a dutch auction, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.placeBidOnAuction(
+                    auctionFactoryAccount.sequence,
+                    startPrice,
+                    bidder
+                );
+            });
+        });
+
+        it("attempt to buy below the current price, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const buyer = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.buyFromAuction(
+                    auctionFactoryAccount.sequence,
+                    floorPrice.sub(new BN(1)),
+                    buyer
+                );
+            }, "Current price exceeds the max price the buyer is willing to pay.");
+        });
+
+        it("buy at the current price and settle the auction", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+            const treasuryBalanceBefore = await client.getTreasuryBalance();
+
+            const buyer = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.buyFromAuction(sequence, startPrice, buyer);
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.settled === true);
+            assert.ok(
+                auctionAccount.bidder.toString() === buyer.publicKey.toString()
+            );
+
+            // price has decayed somewhere between the start and floor prices
+            const price = auctionAccount.amount;
+            assert.ok(price.lte(startPrice) && price.gte(floorPrice));
+
+            const treasuryBalanceAfter = await client.getTreasuryBalance();
+            assert.ok(
+                new BN(treasuryBalanceAfter - treasuryBalanceBefore).eq(price)
+            );
+
+            // buyer holds the resource
+            const [buyerTokenAccount, _buyerTokenAccountBump] =
+                await client.getAssociatedTokenAccountAddress(
+                    buyer.publicKey,
+                    auctionAccount.resource
+                );
+            assert.ok(
+                (await client.getTokenAccountBalance(buyerTokenAccount)) === 1
+            );
+            assert.ok(
+                (await client.getAuctionTokenAccountBalance(
+                    auction,
+                    auctionAccount.resource
+                )) === 0
+            );
+        });
+
+        it("attempt to buy a settled auction, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const buyer = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.buyFromAuction(
+                    auctionFactoryAccount.sequence,
+                    startPrice,
+                    buyer
+                );
+            });
+        });
+    });
+}
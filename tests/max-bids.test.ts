@@ -0,0 +1,140 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { Network } from "./shared/types";
+import { getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test proxy (max) bids
+    //
+    // a max bid escrows its ceiling up front. the program counters other bids on the
+    // max bidder's behalf at the minimum increment, until the ceiling is reached.
+    // ============================================================================
+    describe("counter bids on behalf of a max bidder", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 30;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 100;
+        const minBidIncrement = 10;
+        const maxBidAmount = new BN(500);
+
+        let maxBidder;
+
+        it("initialize auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                { minBidIncrement: new BN(minBidIncrement) }
+            );
+            await client.createNextAuctionWithResource();
+        });
+
+        it("max bid opens at the reserve price and escrows the ceiling", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+            const auctionBalanceBefore = await client.getBalance(auction);
+
+            maxBidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeMaxBidOnAuction(sequence, maxBidAmount, maxBidder);
+
+            const auctionBalanceAfter = await client.getBalance(auction);
+            assert.ok(
+                new BN(auctionBalanceAfter - auctionBalanceBefore).eq(
+                    maxBidAmount
+                )
+            );
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.amount.toNumber() === minReservePrice);
+            assert.ok(
+                auctionAccount.bidder.toString() ===
+                    maxBidder.publicKey.toString()
+            );
+            assert.ok(
+                auctionAccount.proxyBidder.toString() ===
+                    maxBidder.publicKey.toString()
+            );
+
+            const bidRefund = await client.getBidRefund(
+                sequence,
+                maxBidder.publicKey
+            );
+            assert.ok(bidRefund.maxAmount.eq(maxBidAmount));
+        });
+
+        it("max bidder counters a lower bid at the minimum increment", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            const bidAmount = 200;
+            await client.placeBidOnAuction(sequence, new BN(bidAmount), bidder);
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(
+                auctionAccount.amount.toNumber() === bidAmount + minBidIncrement
+            );
+            assert.ok(
+                auctionAccount.bidder.toString() ===
+                    maxBidder.publicKey.toString()
+            );
+
+            // both the incoming bid and the automatic counter bid are recorded
+            const bids = auctionAccount.bids as any[];
+            assert.ok(
+                bids[bids.length - 2].bidder.toString() ===
+                    bidder.publicKey.toString()
+            );
+            assert.ok(
+                bids[bids.length - 1].bidder.toString() ===
+                    maxBidder.publicKey.toString()
+            );
+        });
+
+        it("bid above the ceiling takes the lead and frees the max bidder's escrow", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            const bidAmount = new BN(600);
+            await client.placeBidOnAuction(sequence, bidAmount, bidder);
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.amount.eq(bidAmount));
+            assert.ok(
+                auctionAccount.bidder.toString() === bidder.publicKey.toString()
+            );
+            assert.ok(!auctionAccount.proxyBidder);
+
+            const auctionBalanceBefore = await client.getBalance(auction);
+            await client.claimBidRefund(sequence, maxBidder);
+            const auctionBalanceAfter = await client.getBalance(auction);
+            assert.ok(
+                new BN(auctionBalanceBefore - auctionBalanceAfter).eq(
+                    maxBidAmount
+                )
+            );
+        });
+    });
+}
@@ -0,0 +1,183 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test multi-winner auctions
+    //
+    // the top num_winners bids win, and every winner pays their own bid. the top bidder
+    // receives the master edition, every other winner the print numbered by their rank.
+    // ============================================================================
+    describe("distribute editions to multiple winners", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 15;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+        const numWinners = 3;
+
+        const bids = [];
+
+        it("initialize multi-winner auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                { numWinners }
+            );
+            const { auction } = await client.createNextAuctionWithResource();
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.numWinners === numWinners);
+        });
+
+        it("rank the top bids as winning bids", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            for (const amount of [100, 200, 300, 400]) {
+                const bidder = await client.nodeWallet.createFundedWallet(
+                    0.1 * LAMPORTS_PER_SOL
+                );
+                await client.placeBidOnAuction(sequence, new BN(amount), bidder);
+                bids.push({ bidder, amount: new BN(amount) });
+            }
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            const winningBids = auctionAccount.winningBids as any[];
+            assert.ok(winningBids.length === numWinners);
+            assert.ok(winningBids[0].amount.toNumber() === 400);
+            assert.ok(winningBids[1].amount.toNumber() === 300);
+            assert.ok(winningBids[2].amount.toNumber() === 200);
+            assert.ok(
+                auctionAccount.bidder.toString() ===
+                    bids[3].bidder.publicKey.toString()
+            );
+        });
+
+        it("settle the auction and collect every winning bid", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            let auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+
+            const treasuryBalanceBefore = await client.getTreasuryBalance();
+            await client.settleCurrentAuction(
+                sequence,
+                new PublicKey(auctionAccount.resource)
+            );
+            const treasuryBalanceAfter = await client.getTreasuryBalance();
+            assert.ok(treasuryBalanceAfter - treasuryBalanceBefore === 900);
+
+            auctionAccount = await client.fetchAuctionWithSequence(sequence);
+            assert.ok(auctionAccount.settled === true);
+        });
+
+        it("attempt to claim the master edition before the prints, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+
+            expectThrowsAsync(async () => {
+                await client.claimMasterEditionForWinner(
+                    auctionFactoryAccount.sequence
+                );
+            }, "All print editions must be distributed before the master edition.");
+        });
+
+        it("distribute a print edition to every other winner", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            for (let winnerIndex = 1; winnerIndex < numWinners; winnerIndex++) {
+                const newMint = await client.claimPrintEditionForWinner(
+                    sequence,
+                    winnerIndex
+                );
+
+                // winners are ranked highest first, i.e. in reverse bid order
+                const winner = bids[bids.length - 1 - winnerIndex].bidder;
+                const [winnerTokenAccount, _winnerTokenAccountBump] =
+                    await client.getAssociatedTokenAccountAddress(
+                        winner.publicKey,
+                        newMint
+                    );
+                assert.ok(
+                    (await client.getTokenAccountBalance(winnerTokenAccount)) ===
+                        1
+                );
+            }
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(auctionAccount.distributedEditions.toNumber() === 0b110);
+        });
+
+        it("attempt to claim a print edition twice, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+
+            expectThrowsAsync(async () => {
+                await client.claimPrintEditionForWinner(
+                    auctionFactoryAccount.sequence,
+                    1
+                );
+            }, "Winner has already received their edition.");
+        });
+
+        it("send the master edition to the top bidder", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+
+            await client.claimMasterEditionForWinner(sequence);
+
+            const auctionAccount = await client.fetchAuction(auction);
+            const [topBidderTokenAccount, _topBidderTokenAccountBump] =
+                await client.getAssociatedTokenAccountAddress(
+                    bids[bids.length - 1].bidder.publicKey,
+                    auctionAccount.resource
+                );
+            assert.ok(
+                (await client.getTokenAccountBalance(topBidderTokenAccount)) ===
+                    1
+            );
+            assert.ok(
+                (await client.getAuctionTokenAccountBalance(
+                    auction,
+                    auctionAccount.resource
+                )) === 0
+            );
+        });
+
+        it("bidder outside the winning places claims their full bid", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+
+            const auctionBalanceBefore = await client.getBalance(auction);
+            await client.claimBidRefund(sequence, bids[0].bidder);
+            const auctionBalanceAfter = await client.getBalance(auction);
+            assert.ok(
+                new BN(auctionBalanceBefore - auctionBalanceAfter).eq(
+                    bids[0].amount
+                )
+            );
+        });
+    });
+}
@@ -0,0 +1,122 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test pending auction factory changes
+    //
+    // data & treasury changes are queued for the auction factory's change delay. anyone can
+    // execute them once the delay has passed, and they can be cancelled in the meantime.
+    // ============================================================================
+    describe("queue, cancel & execute auction factory changes", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 10;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+        const changeDelayInSeconds = 3600;
+
+        it("initialize auction factory with a change delay", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                { changeDelay: new BN(changeDelayInSeconds) }
+            );
+        });
+
+        it("attempt to execute a change that was never queued, and fail 😈", async () => {
+            expectThrowsAsync(async () => {
+                await client.executePendingAuctionFactoryChange({ data: {} });
+            }, "Auction factory has no pending change of this kind.");
+        });
+
+        it("attempt to execute a change before its delay has passed, and fail 😈", async () => {
+            let auctionFactoryAccount = await client.getAuctionFactory();
+            await client.modifyAuctionFactory(
+                auctionFactoryAccount.data.duration.toNumber(),
+                auctionFactoryAccount.data.timeBuffer.toNumber(),
+                minBidPercentageIncrease,
+                10
+            );
+
+            auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(auctionFactoryAccount.pendingData);
+
+            expectThrowsAsync(async () => {
+                await client.executePendingAuctionFactoryChange({ data: {} });
+            }, "Pending change cannot be executed until its delay has passed.");
+        });
+
+        it("non-authority attempts to cancel a pending change, and fails 😈", async () => {
+            const payer = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.cancelPendingAuctionFactoryChange(
+                    { data: {} },
+                    payer
+                );
+            }, "Account is not authorized to take such action.");
+        });
+
+        it("cancel a pending data change", async () => {
+            await client.cancelPendingAuctionFactoryChange({ data: {} });
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(!auctionFactoryAccount.pendingData);
+            assert.ok(
+                auctionFactoryAccount.data.minReservePrice.toNumber() ===
+                    minReservePrice
+            );
+        });
+
+        it("cancel a pending treasury change", async () => {
+            const treasuryBeforeUpdate = client.auctionFactory.treasury;
+            const updatedTreasury = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            await client.changeTreasury(updatedTreasury.publicKey);
+
+            let auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(
+                auctionFactoryAccount.pendingTreasury.treasury.toString() ===
+                    updatedTreasury.publicKey.toString()
+            );
+
+            await client.cancelPendingAuctionFactoryChange({ treasury: {} });
+
+            auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(!auctionFactoryAccount.pendingTreasury);
+            assert.ok(
+                auctionFactoryAccount.treasury.toString() ===
+                    treasuryBeforeUpdate.toString()
+            );
+            assert.ok(
+                client.auctionFactory.treasury.toString() ===
+                    treasuryBeforeUpdate.toString()
+            );
+        });
+
+        it("attempt to cancel a change that is no longer pending, and fail 😈", async () => {
+            expectThrowsAsync(async () => {
+                await client.cancelPendingAuctionFactoryChange({
+                    treasury: {},
+                });
+            }, "Auction factory has no pending change of this kind.");
+        });
+    });
+}
@@ -0,0 +1,114 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test raising the leading bid
+    //
+    // the leading bidder can raise their own bid, paying only the difference. the same
+    // increment rules apply as for any other bid.
+    // ============================================================================
+    describe("leading bidder raises their bid", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 30;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+        const minBidIncrement = 10;
+
+        let leadingBidder;
+
+        it("initialize auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                { minBidIncrement: new BN(minBidIncrement) }
+            );
+            await client.createNextAuctionWithResource();
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            leadingBidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(
+                auctionFactoryAccount.sequence,
+                new BN(100),
+                leadingBidder
+            );
+        });
+
+        it("non-leading bidder attempts to raise a bid, and fails 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.raiseBidOnAuction(
+                    auctionFactoryAccount.sequence,
+                    new BN(200),
+                    bidder
+                );
+            }, "Bidder is not the winning bidder.");
+        });
+
+        it("leading bidder attempts to raise by less than the minimum increment, and fails 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+
+            expectThrowsAsync(async () => {
+                await client.raiseBidOnAuction(
+                    auctionFactoryAccount.sequence,
+                    new BN(100 + minBidIncrement - 1),
+                    leadingBidder
+                );
+            }, "Bid must be a non-negative, non-zero amount. Bid must also beat previous bid by some percent.");
+        });
+
+        it("leading bidder raises their bid and pays only the difference", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+            let auctionAccount = await client.fetchAuction(auction);
+            const previousAmount = auctionAccount.amount;
+            const bidsBefore = (auctionAccount.bids as any[]).length;
+            const auctionBalanceBefore = await client.getBalance(auction);
+
+            const raisedAmount = new BN(150);
+            await client.raiseBidOnAuction(sequence, raisedAmount, leadingBidder);
+
+            const auctionBalanceAfter = await client.getBalance(auction);
+            assert.ok(
+                new BN(auctionBalanceAfter - auctionBalanceBefore).eq(
+                    raisedAmount.sub(previousAmount)
+                )
+            );
+
+            auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.amount.eq(raisedAmount));
+            assert.ok(
+                auctionAccount.bidder.toString() ===
+                    leadingBidder.publicKey.toString()
+            );
+            assert.ok((auctionAccount.bids as any[]).length === bidsBefore + 1);
+
+            // nothing is refundable while the raised bid leads
+            const bidRefund = await client.getBidRefund(
+                sequence,
+                leadingBidder.publicKey
+            );
+            assert.ok(bidRefund.amount.eq(raisedAmount));
+        });
+    });
+}
@@ -0,0 +1,136 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv, sleep } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test reclaiming stuck bids
+    //
+    // if nobody settles an auction within the settlement grace period, any bidder can void it
+    // and withdraw their full escrow, including the leading bidder.
+    // ============================================================================
+    describe("reclaim bids from an auction that was never settled", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 5;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+        const settlementGracePeriodInSeconds = 10;
+
+        const bids = [];
+
+        it("initialize auction factory with a settlement grace period & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                {
+                    settlementGracePeriod: new BN(
+                        settlementGracePeriodInSeconds
+                    ),
+                }
+            );
+            await client.createNextAuctionWithResource();
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            for (const amount of [100, 200]) {
+                const bidder = await client.nodeWallet.createFundedWallet(
+                    0.1 * LAMPORTS_PER_SOL
+                );
+                await client.placeBidOnAuction(
+                    auctionFactoryAccount.sequence,
+                    new BN(amount),
+                    bidder
+                );
+                bids.push({ bidder, amount: new BN(amount) });
+            }
+        });
+
+        it("leading bidder attempts to reclaim their bid during the grace period, and fails 😈", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+
+            expectThrowsAsync(async () => {
+                await client.reclaimStuckBidFromAuction(
+                    auctionFactoryAccount.sequence,
+                    bids[1].bidder
+                );
+            }, "Auction can still be settled.");
+        });
+
+        it("leading bidder voids the auction and reclaims their bid after the grace period", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+            let auctionAccount = await client.fetchAuction(auction);
+
+            // spin until the grace period is over
+            const reclaimableAt =
+                auctionAccount.endTime.toNumber() +
+                settlementGracePeriodInSeconds;
+            while (new Date().getTime() / 1000 < reclaimableAt) {
+                await sleep(3 * 1000);
+            }
+            await sleep(3 * 1000);
+
+            const auctionBalanceBefore = await client.getBalance(auction);
+            await client.reclaimStuckBidFromAuction(sequence, bids[1].bidder);
+            const auctionBalanceAfter = await client.getBalance(auction);
+            assert.ok(
+                new BN(auctionBalanceBefore - auctionBalanceAfter).eq(
+                    bids[1].amount
+                )
+            );
+
+            auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.voided === true);
+            assert.ok(auctionAccount.settled === false);
+        });
+
+        it("attempt to settle a voided auction, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+
+            expectThrowsAsync(async () => {
+                await client.settleCurrentAuction(
+                    sequence,
+                    new PublicKey(auctionAccount.resource),
+                    bids[1].bidder.publicKey
+                );
+            }, "Auction has been cancelled or voided.");
+        });
+
+        it("other bidders reclaim their bids from the voided auction", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+
+            const auctionBalanceBefore = await client.getBalance(auction);
+            await client.reclaimStuckBidFromAuction(sequence, bids[0].bidder);
+            const auctionBalanceAfter = await client.getBalance(auction);
+            assert.ok(
+                new BN(auctionBalanceBefore - auctionBalanceAfter).eq(
+                    bids[0].amount
+                )
+            );
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.escrowedAmount.toNumber() === 0);
+        });
+    });
+}
@@ -0,0 +1,145 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test revenue splits
+    //
+    // settled revenue is split across recipients by basis points instead of going to the
+    // treasury. like treasury changes, a new split is queued and executed after the delay.
+    // ============================================================================
+    describe("split auction revenue across recipients", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 10;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+
+        let firstRecipient;
+        let secondRecipient;
+
+        it("initialize auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice
+            );
+            await client.createNextAuctionWithResource();
+
+            firstRecipient = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            secondRecipient = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+        });
+
+        it("attempt to queue shares that do not total 10,000 basis points, and fail 😈", async () => {
+            expectThrowsAsync(async () => {
+                await client.changeRevenueSplit([
+                    {
+                        recipient: firstRecipient.publicKey,
+                        share: new BN(7000),
+                    },
+                    {
+                        recipient: secondRecipient.publicKey,
+                        share: new BN(2000),
+                    },
+                ]);
+            }, "Revenue shares must be non-zero and total 10,000 basis points.");
+        });
+
+        it("non-treasurer attempts to queue a revenue split, and fails 😈", async () => {
+            const payer = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.changeRevenueSplit(
+                    [{ recipient: payer.publicKey, share: new BN(10000) }],
+                    payer
+                );
+            }, "Account is not authorized to take such action.");
+        });
+
+        it("queue a revenue split and execute it", async () => {
+            await client.changeRevenueSplit([
+                { recipient: firstRecipient.publicKey, share: new BN(7000) },
+                { recipient: secondRecipient.publicKey, share: new BN(3000) },
+            ]);
+
+            // nothing changes until the pending split is executed
+            let auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok((auctionFactoryAccount.revenueSplit as any[]).length === 0);
+            assert.ok(auctionFactoryAccount.pendingRevenueSplit);
+
+            await client.executePendingAuctionFactoryChange({
+                revenueSplit: {},
+            });
+
+            auctionFactoryAccount = await client.getAuctionFactory();
+            const revenueSplit = auctionFactoryAccount.revenueSplit as any[];
+            assert.ok(revenueSplit.length === 2);
+            assert.ok(
+                revenueSplit[0].recipient.toString() ===
+                    firstRecipient.publicKey.toString()
+            );
+            assert.ok(revenueSplit[1].share.toNumber() === 3000);
+            assert.ok(!auctionFactoryAccount.pendingRevenueSplit);
+        });
+
+        it("settle the auction and split the winning bid", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(sequence, new BN(1000), bidder);
+
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            const treasuryBalanceBefore = await client.getTreasuryBalance();
+            const firstRecipientBalanceBefore = await client.getBalance(
+                firstRecipient.publicKey
+            );
+            const secondRecipientBalanceBefore = await client.getBalance(
+                secondRecipient.publicKey
+            );
+
+            await client.settleCurrentAuction(
+                sequence,
+                new PublicKey(auctionAccount.resource)
+            );
+
+            assert.ok(
+                (await client.getBalance(firstRecipient.publicKey)) -
+                    firstRecipientBalanceBefore ===
+                    700
+            );
+            assert.ok(
+                (await client.getBalance(secondRecipient.publicKey)) -
+                    secondRecipientBalanceBefore ===
+                    300
+            );
+            assert.ok(
+                (await client.getTreasuryBalance()) === treasuryBalanceBefore
+            );
+        });
+    });
+}
@@ -0,0 +1,121 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test auction factory roles
+    //
+    // the authority grants & revokes roles. each role holder can take that role's admin actions,
+    // e.g. a pauser can toggle the auction factory without holding the treasury keys.
+    // ============================================================================
+    describe("grant & revoke auction factory roles", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 10;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+
+        let pauser;
+        let treasurer;
+
+        it("initialize auction factory", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice
+            );
+
+            pauser = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            treasurer = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+        });
+
+        it("non-authority attempts to grant a role, and fails 😈", async () => {
+            expectThrowsAsync(async () => {
+                await client.grantAuctionFactoryRole(
+                    { pauser: {} },
+                    pauser.publicKey,
+                    pauser
+                );
+            }, "Account is not authorized to take such action.");
+        });
+
+        it("account without the role attempts to pause the auction factory, and fails 😈", async () => {
+            expectThrowsAsync(async () => {
+                await client.toggleAuctionFactoryStatus(pauser);
+            }, "Account is not authorized to take such action.");
+        });
+
+        it("grant roles and act with them", async () => {
+            await client.grantAuctionFactoryRole(
+                { pauser: {} },
+                pauser.publicKey
+            );
+            await client.grantAuctionFactoryRole(
+                { treasurer: {} },
+                treasurer.publicKey
+            );
+
+            let auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(
+                auctionFactoryAccount.roles.pauser.toString() ===
+                    pauser.publicKey.toString()
+            );
+            assert.ok(
+                auctionFactoryAccount.roles.treasurer.toString() ===
+                    treasurer.publicKey.toString()
+            );
+
+            await client.toggleAuctionFactoryStatus(pauser);
+            auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(!auctionFactoryAccount.isActive);
+
+            await client.toggleAuctionFactoryStatus(pauser);
+            auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(auctionFactoryAccount.isActive);
+
+            await client.changeRevenueSplit(
+                [{ recipient: treasurer.publicKey, share: new BN(10000) }],
+                treasurer
+            );
+            auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(auctionFactoryAccount.pendingRevenueSplit);
+        });
+
+        it("role holder attempts to act outside of their role, and fails 😈", async () => {
+            expectThrowsAsync(async () => {
+                await client.changeRevenueSplit(
+                    [{ recipient: pauser.publicKey, share: new BN(10000) }],
+                    pauser
+                );
+            }, "Account is not authorized to take such action.");
+        });
+
+        it("revoke a role", async () => {
+            await client.revokeAuctionFactoryRole({ pauser: {} });
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(!auctionFactoryAccount.roles.pauser);
+        });
+
+        it("former role holder attempts to pause the auction factory, and fails 😈", async () => {
+            expectThrowsAsync(async () => {
+                await client.toggleAuctionFactoryStatus(pauser);
+            }, "Account is not authorized to take such action.");
+        });
+    });
+}
@@ -0,0 +1,305 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+import { randomBytes } from "crypto";
+
+import { computeSealedBidCommitment } from "../sdk/src";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv, sleep } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test sealed bid auctions
+    //
+    // bidders commit a hash of their bid alongside a deposit that covers it. once the auction
+    // ends, bids are revealed until the reveal end time, and the highest revealed bid wins.
+    // deposits of bidders that never reveal are penalized to the treasury.
+    // ============================================================================
+    describe("commit and reveal sealed bids", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 20;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+        const revealDurationInSeconds = 15;
+        const nonRevealPenalty = 1000; // 10%
+
+        const winningBid = { amount: new BN(300), deposit: new BN(500) };
+        const losingBid = { amount: new BN(200), deposit: new BN(200) };
+        const unrevealedDeposit = new BN(400);
+
+        let winner;
+        let loser;
+        let nonRevealer;
+        const winnerSalt = Array.from(randomBytes(32));
+        const loserSalt = Array.from(randomBytes(32));
+
+        it("initialize sealed bid auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                {
+                    auctionType: { sealedBid: {} },
+                    sealedBid: {
+                        revealDuration: new BN(revealDurationInSeconds),
+                        nonRevealPenalty: new BN(nonRevealPenalty),
+                    },
+                }
+            );
+            const { auction } = await client.createNextAuctionWithResource();
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok("sealedBid" in auctionAccount.auctionType);
+            assert.ok(
+                auctionAccount.revealEndTime.toNumber() ===
+                    auctionAccount.endTime.toNumber() + revealDurationInSeconds
+            );
+        });
+
+        it("commit sealed bids without revealing their amounts", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+            const auctionBalanceBefore = await client.getBalance(auction);
+
+            winner = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            loser = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            nonRevealer = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            await client.commitSealedBidOnAuction(
+                sequence,
+                winningBid.deposit,
+                computeSealedBidCommitment(
+                    winningBid.amount,
+                    winnerSalt,
+                    winner.publicKey
+                ),
+                winner
+            );
+            await client.commitSealedBidOnAuction(
+                sequence,
+                losingBid.deposit,
+                computeSealedBidCommitment(
+                    losingBid.amount,
+                    loserSalt,
+                    loser.publicKey
+                ),
+                loser
+            );
+            await client.commitSealedBidOnAuction(
+                sequence,
+                unrevealedDeposit,
+                computeSealedBidCommitment(
+                    new BN(350),
+                    Array.from(randomBytes(32)),
+                    nonRevealer.publicKey
+                ),
+                nonRevealer
+            );
+
+            const auctionBalanceAfter = await client.getBalance(auction);
+            assert.ok(
+                new BN(auctionBalanceAfter - auctionBalanceBefore).eq(
+                    winningBid.deposit
+                        .add(losingBid.deposit)
+                        .add(unrevealedDeposit)
+                )
+            );
+
+            // no bid leads until reveals begin
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.amount.toNumber() === 0);
+
+            const bidRefund = await client.getBidRefund(
+                sequence,
+                winner.publicKey
+            );
+            assert.ok(bidRefund.amount.eq(winningBid.deposit));
+        });
+
+        it("attempt to commit a second sealed bid, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            expectThrowsAsync(async () => {
+                await client.commitSealedBidOnAuction(
+                    sequence,
+                    winningBid.deposit,
+                    computeSealedBidCommitment(
+                        winningBid.amount,
+                        winnerSalt,
+                        winner.publicKey
+                    ),
+                    winner
+                );
+            }, "Bidder has already committed a sealed bid to this auction.");
+        });
+
+        it("attempt to reveal a sealed bid before the auction ends, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            expectThrowsAsync(async () => {
+                await client.revealSealedBidOnAuction(
+                    sequence,
+                    winningBid.amount,
+                    winnerSalt,
+                    winner
+                );
+            }, "Auction is not in a state to perform such action.");
+        });
+
+        it("attempt to reveal a sealed bid with the wrong salt, and fail 😈", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            expectThrowsAsync(async () => {
+                await client.revealSealedBidOnAuction(
+                    sequence,
+                    winningBid.amount,
+                    loserSalt,
+                    winner
+                );
+            }, "Revealed bid does not match commitment.");
+        });
+
+        it("reveal sealed bids and the highest revealed bid leads", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            await client.revealSealedBidOnAuction(
+                sequence,
+                losingBid.amount,
+                loserSalt,
+                loser
+            );
+            await client.revealSealedBidOnAuction(
+                sequence,
+                winningBid.amount,
+                winnerSalt,
+                winner
+            );
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(auctionAccount.amount.eq(winningBid.amount));
+            assert.ok(
+                auctionAccount.bidder.toString() === winner.publicKey.toString()
+            );
+
+            const bidRefund = await client.getBidRefund(
+                sequence,
+                winner.publicKey
+            );
+            assert.ok(bidRefund.revealed === true);
+
+            expectThrowsAsync(async () => {
+                await client.revealSealedBidOnAuction(
+                    sequence,
+                    winningBid.amount,
+                    winnerSalt,
+                    winner
+                );
+            }, "Sealed bid has already been revealed.");
+        });
+
+        it("settle the auction once the reveal phase is over", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            let auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+
+            // spin until the reveal phase is over
+            while (
+                new Date().getTime() / 1000 <
+                auctionAccount.revealEndTime.toNumber()
+            ) {
+                await sleep(3 * 1000);
+            }
+            await sleep(3 * 1000);
+
+            const treasuryBalanceBefore = await client.getTreasuryBalance();
+            await client.settleCurrentAuction(
+                sequence,
+                new PublicKey(auctionAccount.resource)
+            );
+            const treasuryBalanceAfter = await client.getTreasuryBalance();
+            assert.ok(
+                new BN(treasuryBalanceAfter - treasuryBalanceBefore).eq(
+                    winningBid.amount
+                )
+            );
+
+            auctionAccount = await client.fetchAuctionWithSequence(sequence);
+            assert.ok(auctionAccount.settled === true);
+        });
+
+        it("winner and loser claim their remaining deposits", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+
+            let auctionBalanceBefore = await client.getBalance(auction);
+            await client.claimBidRefund(sequence, winner);
+            let auctionBalanceAfter = await client.getBalance(auction);
+            assert.ok(
+                new BN(auctionBalanceBefore - auctionBalanceAfter).eq(
+                    winningBid.deposit.sub(winningBid.amount)
+                )
+            );
+
+            auctionBalanceBefore = await client.getBalance(auction);
+            await client.claimBidRefund(sequence, loser);
+            auctionBalanceAfter = await client.getBalance(auction);
+            assert.ok(
+                new BN(auctionBalanceBefore - auctionBalanceAfter).eq(
+                    losingBid.deposit
+                )
+            );
+        });
+
+        it("bidder that never revealed forfeits the penalty to the treasury", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+            const penalty = unrevealedDeposit
+                .mul(new BN(nonRevealPenalty))
+                .div(new BN(10000));
+
+            const auctionBalanceBefore = await client.getBalance(auction);
+            const treasuryBalanceBefore = await client.getTreasuryBalance();
+            await client.claimBidRefund(sequence, nonRevealer);
+            const auctionBalanceAfter = await client.getBalance(auction);
+            const treasuryBalanceAfter = await client.getTreasuryBalance();
+
+            assert.ok(
+                new BN(auctionBalanceBefore - auctionBalanceAfter).eq(
+                    unrevealedDeposit
+                )
+            );
+            assert.ok(
+                new BN(treasuryBalanceAfter - treasuryBalanceBefore).eq(penalty)
+            );
+        });
+    });
+}
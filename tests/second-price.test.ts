@@ -0,0 +1,132 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test second price settlement
+    //
+    // the winner pays one increment above the second highest bid, or above the reserve price
+    // if there was only one bid, and withdraws the rest of their bid via claim_refund.
+    // ============================================================================
+    describe("settle auctions at the second price", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 10;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 50;
+        const minBidIncrement = 10;
+
+        const settleAndAssertPrice = async (
+            winner,
+            winningAmount: BN,
+            expectedPrice: BN
+        ) => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+            let auctionAccount = await client.fetchAuction(auction);
+
+            const treasuryBalanceBefore = await client.getTreasuryBalance();
+            await client.settleCurrentAuction(
+                sequence,
+                new PublicKey(auctionAccount.resource)
+            );
+            const treasuryBalanceAfter = await client.getTreasuryBalance();
+            assert.ok(
+                new BN(treasuryBalanceAfter - treasuryBalanceBefore).eq(
+                    expectedPrice
+                )
+            );
+
+            auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.settled === true);
+            assert.ok(auctionAccount.amount.eq(expectedPrice));
+
+            // the winner withdraws the difference between their bid and the price paid
+            const auctionBalanceBefore = await client.getBalance(auction);
+            await client.claimBidRefund(sequence, winner);
+            const auctionBalanceAfter = await client.getBalance(auction);
+            assert.ok(
+                new BN(auctionBalanceBefore - auctionBalanceAfter).eq(
+                    winningAmount.sub(expectedPrice)
+                )
+            );
+        };
+
+        it("initialize second price auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                {
+                    minBidIncrement: new BN(minBidIncrement),
+                    secondPriceSettlement: true,
+                }
+            );
+            const { auction } = await client.createNextAuctionWithResource();
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.secondPriceSettlement === true);
+        });
+
+        it("winner pays one increment above the second highest bid", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            const runnerUp = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            const winner = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            const runnerUpAmount = new BN(100);
+            const winningAmount = new BN(300);
+            await client.placeBidOnAuction(sequence, runnerUpAmount, runnerUp);
+            await client.placeBidOnAuction(sequence, winningAmount, winner);
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(auctionAccount.secondHighestAmount.eq(runnerUpAmount));
+
+            await settleAndAssertPrice(
+                winner,
+                winningAmount,
+                runnerUpAmount.add(new BN(minBidIncrement))
+            );
+        });
+
+        it("sole bidder pays one increment above the reserve price", async () => {
+            await client.createNextAuctionWithResource();
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            const winner = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            const winningAmount = new BN(200);
+            await client.placeBidOnAuction(sequence, winningAmount, winner);
+
+            await settleAndAssertPrice(
+                winner,
+                winningAmount,
+                new BN(minReservePrice + minBidIncrement)
+            );
+        });
+    });
+}
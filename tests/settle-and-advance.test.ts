@@ -0,0 +1,131 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test settle & advance
+    //
+    // a single instruction settles the current auction, creates the next auction, and supplies
+    // it with a freshly minted resource. if any step fails, the whole cycle is rolled back.
+    // ============================================================================
+    describe("settle the current auction and start the next one", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 10;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+
+        let bidder;
+
+        it("initialize auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice
+            );
+            await client.createNextAuctionWithResource();
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(
+                auctionFactoryAccount.sequence,
+                new BN(100),
+                bidder
+            );
+        });
+
+        it("attempt to settle & advance a live auction, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+
+            expectThrowsAsync(async () => {
+                await client.settleAndAdvanceCurrentAuction(
+                    auctionFactoryAccount.sequence
+                );
+            });
+        });
+
+        it("settle the current auction and supply the next auction in one instruction", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const currentSequence = auctionFactoryAccount.sequence;
+            const currentAuction = await client.getCurrentAuctionAddress();
+            const currentMint = new PublicKey(
+                (await client.fetchAuction(currentAuction)).resource
+            );
+            const treasuryBalanceBefore = await client.getTreasuryBalance();
+
+            const { nextSequence, nextMint } =
+                await client.settleAndAdvanceCurrentAuction(currentSequence);
+
+            // current auction is settled & the winner holds its resource
+            const currentAuctionAccount = await client.fetchAuction(
+                currentAuction
+            );
+            assert.ok(currentAuctionAccount.settled === true);
+            assert.ok(
+                (await client.getTreasuryBalance()) - treasuryBalanceBefore ===
+                    100
+            );
+
+            const [bidderTokenAccount, _bidderTokenAccountBump] =
+                await client.getAssociatedTokenAccountAddress(
+                    bidder.publicKey,
+                    currentMint
+                );
+            assert.ok(
+                (await client.getTokenAccountBalance(bidderTokenAccount)) === 1
+            );
+
+            // next auction is live with its resource
+            const updatedAuctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(updatedAuctionFactoryAccount.sequence.eq(nextSequence));
+
+            const nextAuction = await client.getAuctionAddressWithSequence(
+                nextSequence
+            );
+            const nextAuctionAccount = await client.fetchAuction(nextAuction);
+            assert.ok(
+                nextAuctionAccount.resource.toString() === nextMint.toString()
+            );
+            assert.ok(
+                (await client.getAuctionTokenAccountBalance(
+                    nextAuction,
+                    nextMint
+                )) === 1
+            );
+        });
+
+        it("bid on the next auction", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            const nextBidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(sequence, new BN(100), nextBidder);
+
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            assert.ok(
+                auctionAccount.bidder.toString() ===
+                    nextBidder.publicKey.toString()
+            );
+        });
+    });
+}
@@ -0,0 +1,116 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { AccountLayout } from "@solana/spl-token";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test settling on behalf of the winner
+    //
+    // anyone can settle an auction, even if the winner has no token account for the resource
+    // yet. the settler pays for the winner's token account, but cannot redirect the resource.
+    // ============================================================================
+    describe("third party settles for a winner without a token account", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 10;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+
+        let winner;
+
+        it("initialize auction factory & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice
+            );
+            await client.createNextAuctionWithResource();
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            winner = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(
+                auctionFactoryAccount.sequence,
+                new BN(100),
+                winner
+            );
+        });
+
+        it("third party attempts to settle the resource to themselves, and fails 😈", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            const settler = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.settleAuction(
+                    sequence,
+                    new PublicKey(auctionAccount.resource),
+                    settler,
+                    settler.publicKey
+                );
+            }, "Bidder is not the winning bidder.");
+        });
+
+        it("third party settles and pays for the winner's token account", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            const mint = new PublicKey(auctionAccount.resource);
+
+            const [winnerTokenAccount, _winnerTokenAccountBump] =
+                await client.getAssociatedTokenAccountAddress(
+                    winner.publicKey,
+                    mint
+                );
+            assert.ok(
+                (await client.provider.connection.getAccountInfo(
+                    winnerTokenAccount
+                )) === null
+            );
+
+            const settler = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            const settlerBalanceBefore = await client.getBalance(
+                settler.publicKey
+            );
+            await client.settleAuction(sequence, mint, settler);
+
+            assert.ok(
+                (await client.getTokenAccountBalance(winnerTokenAccount)) === 1
+            );
+
+            const tokenAccountRent =
+                await client.provider.connection.getMinimumBalanceForRentExemption(
+                    AccountLayout.span
+                );
+            assert.ok(
+                settlerBalanceBefore -
+                    (await client.getBalance(settler.publicKey)) ===
+                    tokenAccountRent
+            );
+        });
+    });
+}
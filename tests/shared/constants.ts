@@ -17,6 +17,7 @@ export const CONFIG_SEED_LEN = 5;
 //      - creating & settling a bunch of auctions
 //      - creating an auction and receiving many bids
 //      - create auction factory and max out config
+//      - auction factory feature suites, e.g. time buffer extensions
 // false = only run main auction lifecycle tests
 // ============================================================================
 // note: i recommend only running these tests that require this boolean on localnet.
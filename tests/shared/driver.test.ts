@@ -1,6 +1,7 @@
 import * as anchor from "@project-serum/anchor";
 import { BN } from "@project-serum/anchor";
 import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { Token, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 
 import {
     NodeWallet,
@@ -9,8 +10,13 @@ import {
     AUCTION_FACTORY_SEED_LEN,
     CONFIG_SEED_LEN,
     AuctionFactoryData,
-    TokenAccount,
+    AuctionFactoryChange,
+    AuctionFactoryRole,
+    RevenueShare,
+    getDefaultAuctionFactoryData,
 } from "../../sdk/src";
+import { generateConfigs } from "./helpers";
+import { sleep } from "./utils";
 
 // ============================================================================
 // base tester class
@@ -22,6 +28,8 @@ export class AuctionFactoryTestClient extends AuctionFactoryClient {
     funder: Keypair;
 
     auctionFactoryAuthority: Keypair;
+    // set for auction factories that take bids in SPL tokens
+    bidMint: Token;
 
     constructor() {
         // setup connection & local wallet
@@ -75,34 +83,70 @@ export class AuctionFactoryTestClient extends AuctionFactoryClient {
         timeBuffer: number,
         minBidPercentageIncrease: number,
         minReservePrice: number,
-        seed?: string
+        seed?: string,
+        overrides?: Partial<AuctionFactoryData>,
+        bidMint?: Token
     ) => {
         const _seed = seed ? seed : generateSeed(AUCTION_FACTORY_SEED_LEN);
         const [afAddress, afBump] = await this.findAuctionFactoryPda(_seed);
 
         const config = {
+            ...getDefaultAuctionFactoryData(),
             duration: new anchor.BN(duration),
             timeBuffer: new anchor.BN(timeBuffer),
             minBidPercentageIncrease: new anchor.BN(minBidPercentageIncrease),
             minReservePrice: new anchor.BN(minReservePrice),
+            ...overrides,
         } as AuctionFactoryData;
 
-        const treasury = await this.nodeWallet.createFundedWallet(
+        const treasuryOwner = await this.nodeWallet.createFundedWallet(
             0.1 * LAMPORTS_PER_SOL
         );
+        // a bid mint treasury is a token account of the bid mint
+        const treasury = bidMint
+            ? await bidMint.createAssociatedTokenAccount(
+                  treasuryOwner.publicKey
+              )
+            : treasuryOwner.publicKey;
 
         await this.initialize(
             afAddress,
             afBump,
             _seed,
             config,
-            treasury.publicKey,
-            this.auctionFactoryAuthority
+            treasury,
+            this.auctionFactoryAuthority,
+            bidMint ? bidMint.publicKey : undefined
+        );
+        this.bidMint = bidMint;
+    };
+
+    // config, active auction factory & a full config, shared by the feature test suites
+    setupAuctionFactory = async (
+        maxSupply: number,
+        duration: number,
+        timeBuffer: number,
+        minBidPercentageIncrease: number,
+        minReservePrice: number,
+        overrides?: Partial<AuctionFactoryData>,
+        bidMint?: Token
+    ) => {
+        await this.initConfig(maxSupply);
+        await this.initializeAuctionFactory(
+            duration,
+            timeBuffer,
+            minBidPercentageIncrease,
+            minReservePrice,
+            undefined,
+            overrides,
+            bidMint
         );
+        await this.toggleAuctionFactoryStatus();
+        await this.addDataToConfig(generateConfigs(maxSupply));
     };
 
-    toggleAuctionFactoryStatus = async () => {
-        await this.toggleStatus(this.auctionFactoryAuthority);
+    toggleAuctionFactoryStatus = async (payer?: Keypair) => {
+        await this.toggleStatus(payer ? payer : this.auctionFactoryAuthority);
     };
 
     modifyAuctionFactory = async (
@@ -112,7 +156,9 @@ export class AuctionFactoryTestClient extends AuctionFactoryClient {
         minReservePrice: number,
         payer?: Keypair
     ) => {
+        const auctionFactoryAccount = await this.getAuctionFactory();
         const config = {
+            ...(auctionFactoryAccount.data as AuctionFactoryData),
             duration: new anchor.BN(duration),
             timeBuffer: new anchor.BN(timeBuffer),
             minBidPercentageIncrease: new anchor.BN(minBidPercentageIncrease),
@@ -126,15 +172,87 @@ export class AuctionFactoryTestClient extends AuctionFactoryClient {
         await this.updateTreasury(treasury, this.auctionFactoryAuthority);
     };
 
-    changeAuthority = async (authority: Keypair) => {
-        await this.updateAuthority(
-            authority.publicKey,
-            this.auctionFactoryAuthority
+    changeRevenueSplit = async (
+        revenueSplit: RevenueShare[],
+        payer?: Keypair
+    ) => {
+        await this.updateRevenueSplit(
+            revenueSplit,
+            payer ? payer : this.auctionFactoryAuthority
+        );
+    };
+
+    executePendingAuctionFactoryChange = async (
+        change: AuctionFactoryChange
+    ) => {
+        const payer = await this.nodeWallet.createFundedWallet(
+            0.1 * LAMPORTS_PER_SOL
+        );
+
+        await this.executePendingChange(change, payer);
+    };
+
+    cancelPendingAuctionFactoryChange = async (
+        change: AuctionFactoryChange,
+        payer?: Keypair
+    ) => {
+        await this.cancelPendingChange(
+            change,
+            payer ? payer : this.auctionFactoryAuthority
+        );
+    };
+
+    grantAuctionFactoryRole = async (
+        role: AuctionFactoryRole,
+        holder: PublicKey,
+        payer?: Keypair
+    ) => {
+        await this.grantRole(
+            role,
+            holder,
+            payer ? payer : this.auctionFactoryAuthority
+        );
+    };
+
+    revokeAuctionFactoryRole = async (
+        role: AuctionFactoryRole,
+        payer?: Keypair
+    ) => {
+        await this.revokeRole(role, payer ? payer : this.auctionFactoryAuthority);
+    };
+
+    proposeNewAuthority = async (authority: PublicKey, payer?: Keypair) => {
+        await this.proposeAuthority(
+            authority,
+            payer ? payer : this.auctionFactoryAuthority
         );
+    };
+
+    cancelProposedAuthority = async (payer?: Keypair) => {
+        await this.cancelAuthorityTransfer(
+            payer ? payer : this.auctionFactoryAuthority
+        );
+    };
+
+    changeAuthority = async (authority: Keypair) => {
+        await this.proposeNewAuthority(authority.publicKey);
+        await this.acceptAuthority(authority);
 
         this.auctionFactoryAuthority = authority;
     };
 
+    cancelCurrentAuction = async (
+        sequence: BN,
+        burnResource: boolean = false,
+        payer?: Keypair
+    ) => {
+        await this.cancelAuction(
+            sequence,
+            burnResource,
+            payer ? payer : this.auctionFactoryAuthority
+        );
+    };
+
     dumpLamportsToTreasury = async () => {
         await this.transferLamports(
             this.auctionFactory.treasury,
@@ -162,24 +280,161 @@ export class AuctionFactoryTestClient extends AuctionFactoryClient {
         await this.supplyResource(sequence, mint.publicKey, payer);
     };
 
+    // create the next auction and supply it with a freshly minted resource
+    createNextAuctionWithResource = async () => {
+        const auctionFactoryAccount = await this.getAuctionFactory();
+        const sequence = auctionFactoryAccount.sequence.add(new BN(1));
+        const mint = Keypair.generate();
+
+        await this.initAuction(sequence);
+        await this.mintNftToAuction(sequence, mint);
+
+        return {
+            sequence,
+            auction: await this.getAuctionAddressWithSequence(sequence),
+            mint,
+        };
+    };
+
     placeBidOnAuction = async (
         sequence: BN,
         amount: BN,
+        bidder: Keypair,
+        recordReceipts: boolean = false
+    ) => {
+        await this.placeBid(sequence, amount, bidder, recordReceipts);
+    };
+
+    raiseBidOnAuction = async (sequence: BN, amount: BN, bidder: Keypair) => {
+        await this.raiseBid(sequence, amount, bidder);
+    };
+
+    placeMaxBidOnAuction = async (
+        sequence: BN,
+        maxAmount: BN,
+        bidder: Keypair
+    ) => {
+        await this.placeMaxBid(sequence, maxAmount, bidder);
+    };
+
+    commitSealedBidOnAuction = async (
+        sequence: BN,
+        deposit: BN,
+        commitment: number[],
+        bidder: Keypair
+    ) => {
+        await this.commitSealedBid(sequence, deposit, commitment, bidder);
+    };
+
+    revealSealedBidOnAuction = async (
+        sequence: BN,
+        amount: BN,
+        salt: number[],
         bidder: Keypair
     ) => {
-        await this.placeBid(sequence, amount, bidder);
+        await this.revealSealedBid(sequence, amount, salt, bidder);
+    };
+
+    buyFromAuction = async (sequence: BN, maxPrice: BN, buyer: Keypair) => {
+        await this.buy(sequence, maxPrice, buyer);
+    };
+
+    claimBidRefund = async (sequence: BN, bidder: Keypair) => {
+        await this.claimRefund(sequence, bidder);
+    };
+
+    reclaimStuckBidFromAuction = async (sequence: BN, bidder: Keypair) => {
+        await this.reclaimStuckBid(sequence, bidder);
     };
 
     settleCurrentAuction = async (
         sequence: BN,
-        bidderTokenAccount: TokenAccount,
-        mint: PublicKey
+        mint: PublicKey,
+        bidder?: PublicKey
     ) => {
         const payer = await this.nodeWallet.createFundedWallet(
             1 * LAMPORTS_PER_SOL
         );
 
-        await this.settleAuction(sequence, bidderTokenAccount, mint, payer);
+        await this.settleAuction(sequence, mint, payer, bidder);
+    };
+
+    settleAndAdvanceCurrentAuction = async (sequence: BN, bidder?: PublicKey) => {
+        const payer = await this.nodeWallet.createFundedWallet(
+            1 * LAMPORTS_PER_SOL
+        );
+
+        return await this.settleAndAdvance(sequence, payer, bidder);
+    };
+
+    requestCandleAuctionClose = async (sequence: BN) => {
+        const payer = await this.nodeWallet.createFundedWallet(
+            0.1 * LAMPORTS_PER_SOL
+        );
+
+        await this.requestCandleClose(sequence, payer);
+    };
+
+    // the winner of a candle auction is picked at settlement, from the hash of the requested close
+    // slot. any checkpointed leader could win, so try each of them until the settlement goes through.
+    settleCandleAuction = async (sequence: BN, mint: PublicKey) => {
+        let auctionAccount = await this.fetchAuctionWithSequence(sequence);
+        if (auctionAccount.candleCloseSlot.eqn(0)) {
+            await this.requestCandleAuctionClose(sequence);
+            auctionAccount = await this.fetchAuctionWithSequence(sequence);
+        }
+        while (
+            (await this.provider.connection.getSlot()) <=
+            auctionAccount.candleCloseSlot.toNumber()
+        ) {
+            await sleep(1000);
+        }
+
+        const candidates = (auctionAccount.candleCheckpoints as any[])
+            .filter((checkpoint) => checkpoint.amount.gtn(0))
+            .map((checkpoint) => checkpoint.bidder.toString());
+        if (auctionAccount.amount.gtn(0)) {
+            candidates.push(auctionAccount.bidder.toString());
+        }
+        // the candle may also close before the first bid
+        const auctionFactoryAccount = await this.getAuctionFactory();
+        candidates.push(
+            (auctionFactoryAccount.data.unsoldResourceRecipient
+                ? auctionFactoryAccount.data.unsoldResourceRecipient
+                : auctionFactoryAccount.treasury
+            ).toString()
+        );
+
+        for (const candidate of new Set(candidates)) {
+            try {
+                await this.settleCurrentAuction(
+                    sequence,
+                    mint,
+                    new PublicKey(candidate)
+                );
+                return;
+            } catch (_err) {
+                continue;
+            }
+        }
+
+        throw new Error("Failed to settle candle auction");
+    };
+
+    claimPrintEditionForWinner = async (sequence: BN, winnerIndex: number) => {
+        const payer = await this.nodeWallet.createFundedWallet(
+            1 * LAMPORTS_PER_SOL
+        );
+
+        return await this.claimPrintEdition(sequence, winnerIndex, payer);
+    };
+
+    claimMasterEditionForWinner = async (sequence: BN) => {
+        const payer = await this.nodeWallet.createFundedWallet(
+            1 * LAMPORTS_PER_SOL
+        );
+
+        await this.claimMasterEdition(sequence, payer);
     };
 
     closeAuctionATA = async (
@@ -228,10 +483,66 @@ export class AuctionFactoryTestClient extends AuctionFactoryClient {
         );
     };
 
+    closeEmptiedBidRefund = async (sequence: BN, bidder: PublicKey) => {
+        const payer = await this.nodeWallet.createFundedWallet(
+            0.1 * LAMPORTS_PER_SOL
+        );
+
+        await this.closeBidRefund(sequence, bidder, payer);
+    };
+
+    closeSettledAuction = async (sequence: BN) => {
+        const payer = await this.nodeWallet.createFundedWallet(
+            1 * LAMPORTS_PER_SOL
+        );
+
+        return await this.closeAuction(sequence, payer);
+    };
+
     // ============================================================================
     // generic helpers
     // ============================================================================
 
+    createBidMint = async (): Promise<Token> => {
+        return await Token.createMint(
+            this.connection,
+            this.funder,
+            this.funder.publicKey, // mint authority
+            null, // freeze authority
+            0, // decimals
+            TOKEN_PROGRAM_ID
+        );
+    };
+
+    // wallet with lamports for fees and an associated token account holding bid tokens
+    createFundedBidTokenWallet = async (amount: number): Promise<Keypair> => {
+        const wallet = await this.nodeWallet.createFundedWallet(
+            0.1 * LAMPORTS_PER_SOL
+        );
+        const tokenAccount = await this.bidMint.createAssociatedTokenAccount(
+            wallet.publicKey
+        );
+        await this.bidMint.mintTo(tokenAccount, this.funder, [], amount);
+
+        return wallet;
+    };
+
+    getBidTokenBalance = async (owner: PublicKey): Promise<number> => {
+        const [tokenAccount, _bump] =
+            await this.getAssociatedTokenAccountAddress(
+                owner,
+                this.bidMint.publicKey
+            );
+
+        return await this.getTokenAccountBalance(tokenAccount);
+    };
+
+    getTokenAccountBalance = async (tokenAccount: PublicKey): Promise<number> => {
+        const tokenAmount = await this.getTokenBalance(tokenAccount);
+
+        return +tokenAmount["value"]["amount"];
+    };
+
     addFundsToAuctionFactory = async (lamports: number): Promise<void> => {
         await this.nodeWallet.fundWallet(
             this.auctionFactory.config.address,
@@ -269,4 +580,24 @@ export class AuctionFactoryTestClient extends AuctionFactoryClient {
             this.auctionFactory.config.address
         );
     }
+
+    getBidReceipt = async (sequence: BN, index: BN) => {
+        const auction = await this.getAuctionAddressWithSequence(sequence);
+        const [bidReceipt, _bidReceiptBump] = await this.findBidReceiptPda(
+            auction,
+            index
+        );
+
+        return await this.fetchBidReceipt(bidReceipt);
+    }
+
+    getBidRefund = async (sequence: BN, bidder: PublicKey) => {
+        const auction = await this.getAuctionAddressWithSequence(sequence);
+        const [bidRefund, _bidRefundBump] = await this.findBidRefundPda(
+            auction,
+            bidder
+        );
+
+        return await this.fetchBidRefund(bidRefund);
+    }
 }
@@ -0,0 +1,174 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test SPL token denominated auctions
+    //
+    // bids are escrowed in the auction's associated token account for the factory's bid mint,
+    // refunds are claimed from it, and the winning bid is paid to the treasury token account, or
+    // to revenue recipients that are token accounts for the bid mint.
+    // ============================================================================
+    describe("bid on an auction with SPL tokens", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 10;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+        const initialBidTokenBalance = 1000;
+
+        let firstBidder;
+        let secondBidder;
+
+        it("initialize auction factory with a bid mint", async () => {
+            const bidMint = await client.createBidMint();
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                {},
+                bidMint
+            );
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(
+                auctionFactoryAccount.bidMint.toString() ===
+                    bidMint.publicKey.toString()
+            );
+
+            const treasury = await bidMint.getAccountInfo(
+                auctionFactoryAccount.treasury
+            );
+            assert.ok(treasury.mint.toString() === bidMint.publicKey.toString());
+        });
+
+        it("create an auction denominated in the bid mint", async () => {
+            const { auction } = await client.createNextAuctionWithResource();
+
+            const auctionAccount = await client.fetchAuction(auction);
+            assert.ok(
+                auctionAccount.tokenMint.toString() ===
+                    client.bidMint.publicKey.toString()
+            );
+        });
+
+        it("escrow SPL token bids in the auction token account", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+
+            firstBidder = await client.createFundedBidTokenWallet(
+                initialBidTokenBalance
+            );
+            secondBidder = await client.createFundedBidTokenWallet(
+                initialBidTokenBalance
+            );
+
+            await client.placeBidOnAuction(sequence, new BN(100), firstBidder);
+            assert.ok(
+                (await client.getBidTokenBalance(firstBidder.publicKey)) ===
+                    initialBidTokenBalance - 100
+            );
+            assert.ok((await client.getBidTokenBalance(auction)) === 100);
+
+            await client.placeBidOnAuction(sequence, new BN(200), secondBidder);
+            assert.ok(
+                (await client.getBidTokenBalance(secondBidder.publicKey)) ===
+                    initialBidTokenBalance - 200
+            );
+            assert.ok((await client.getBidTokenBalance(auction)) === 300);
+        });
+
+        it("outbid bidder claims their SPL token refund", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const auction = await client.getCurrentAuctionAddress();
+
+            await client.claimBidRefund(
+                auctionFactoryAccount.sequence,
+                firstBidder
+            );
+
+            assert.ok(
+                (await client.getBidTokenBalance(firstBidder.publicKey)) ===
+                    initialBidTokenBalance
+            );
+            assert.ok((await client.getBidTokenBalance(auction)) === 200);
+        });
+
+        it("settle auction and pay the treasury in SPL tokens", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auction = await client.getCurrentAuctionAddress();
+            let auctionAccount = await client.fetchAuction(auction);
+            const mint = new PublicKey(auctionAccount.resource);
+            const treasuryBalanceBefore = await client.getTokenAccountBalance(
+                client.auctionFactory.treasury
+            );
+
+            await client.settleCurrentAuction(auctionAccount.sequence, mint);
+
+            auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.settled === true);
+
+            const treasuryBalanceAfter = await client.getTokenAccountBalance(
+                client.auctionFactory.treasury
+            );
+            assert.ok(treasuryBalanceAfter - treasuryBalanceBefore === 200);
+            assert.ok((await client.getBidTokenBalance(auction)) === 0);
+
+            // winner holds the resource
+            const [bidderTokenAccount, _bidderTokenAccountBump] =
+                await client.getAssociatedTokenAccountAddress(
+                    secondBidder.publicKey,
+                    mint
+                );
+            assert.ok(
+                (await client.getTokenAccountBalance(bidderTokenAccount)) === 1
+            );
+        });
+
+        it("attempt to queue a revenue split to a wallet instead of a bid token account, and fail 😈", async () => {
+            const recipient = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+
+            expectThrowsAsync(async () => {
+                await client.changeRevenueSplit([
+                    { recipient: recipient.publicKey, share: new BN(10000) },
+                ]);
+            }, "Account does not have correct owner!");
+        });
+
+        it("queue a revenue split to a bid token account", async () => {
+            const recipient = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            const recipientTokenAccount =
+                await client.bidMint.createAssociatedTokenAccount(
+                    recipient.publicKey
+                );
+
+            await client.changeRevenueSplit([
+                { recipient: recipientTokenAccount, share: new BN(10000) },
+            ]);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(
+                auctionFactoryAccount.pendingRevenueSplit.revenueSplit[0].recipient.toString() ===
+                    recipientTokenAccount.toString()
+            );
+        });
+    });
+}
@@ -0,0 +1,91 @@
+import { BN } from "@project-serum/anchor";
+import { LAMPORTS_PER_SOL } from "@solana/web3.js";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { Network } from "./shared/types";
+import { getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test anti-sniping time buffer
+    //
+    // bids placed with less than time_buffer seconds left push the auction's end time out,
+    // up to max_time_extension seconds in total over the life of the auction.
+    // ============================================================================
+    describe("extend auction end time after late bids", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 5;
+        const timeBufferInSeconds = 20;
+        const maxTimeExtensionInSeconds = 10;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+
+        it("initialize auction factory with a capped time extension", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                { maxTimeExtension: new BN(maxTimeExtensionInSeconds) }
+            );
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            assert.ok(auctionFactoryAccount.isActive);
+            assert.ok(
+                auctionFactoryAccount.data.maxTimeExtension.toNumber() ===
+                    maxTimeExtensionInSeconds
+            );
+        });
+
+        it("bid inside the time buffer extends the end time up to the cap", async () => {
+            const { sequence, auction } =
+                await client.createNextAuctionWithResource();
+            let auctionAccount = await client.fetchAuction(auction);
+            const endTimeBeforeBid = auctionAccount.endTime.toNumber();
+            assert.ok(auctionAccount.timeExtension.toNumber() === 0);
+
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(sequence, new BN(100), bidder);
+
+            // time buffer exceeds the auction duration, so the extension is capped
+            auctionAccount = await client.fetchAuction(auction);
+            assert.ok(
+                auctionAccount.timeExtension.toNumber() ===
+                    maxTimeExtensionInSeconds
+            );
+            assert.ok(
+                auctionAccount.endTime.toNumber() ===
+                    endTimeBeforeBid + maxTimeExtensionInSeconds
+            );
+        });
+
+        it("bid after the cap is reached does not extend the end time", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            let auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+            const endTimeBeforeBid = auctionAccount.endTime.toNumber();
+
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(sequence, new BN(200), bidder);
+
+            auctionAccount = await client.fetchAuctionWithSequence(sequence);
+            assert.ok(auctionAccount.endTime.toNumber() === endTimeBeforeBid);
+            assert.ok(
+                auctionAccount.timeExtension.toNumber() ===
+                    maxTimeExtensionInSeconds
+            );
+        });
+    });
+}
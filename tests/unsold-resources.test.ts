@@ -0,0 +1,177 @@
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { BN } from "@project-serum/anchor";
+import * as assert from "assert";
+
+import { AuctionFactoryTestClient } from "./shared/driver.test";
+
+import { RUN_ALL_TESTS } from "./shared/constants";
+import { waitForAuctionToEnd } from "./shared/helpers";
+import { Network } from "./shared/types";
+import { expectThrowsAsync, getAnchorEnv } from "./shared/utils";
+
+if (getAnchorEnv() === Network.Localnet && RUN_ALL_TESTS) {
+    // ============================================================================
+    // test unsold resource policies
+    //
+    // the resource of an auction settled without any bids is burned by default. it can instead
+    // be transferred to the unsold resource recipient, or rolled over into the next auction.
+    // ============================================================================
+    describe("transfer unsold resources to the recipient", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 5;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+
+        const recipient = Keypair.generate();
+
+        it("initialize auction factory with the transfer policy & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                {
+                    unsoldResourcePolicy: { transfer: {} },
+                    unsoldResourceRecipient: recipient.publicKey,
+                }
+            );
+            await client.createNextAuctionWithResource();
+        });
+
+        it("attempt to settle the unsold resource to another account, and fail 😈", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auctionAccount = await client.fetchAuctionWithSequence(
+                sequence
+            );
+
+            expectThrowsAsync(async () => {
+                await client.settleCurrentAuction(
+                    sequence,
+                    new PublicKey(auctionAccount.resource),
+                    Keypair.generate().publicKey
+                );
+            }, "Unsold resource recipient mismatch.");
+        });
+
+        it("settle the auction and transfer the unsold resource to the recipient", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            const auction = await client.getCurrentAuctionAddress();
+            let auctionAccount = await client.fetchAuction(auction);
+            const mint = new PublicKey(auctionAccount.resource);
+
+            await client.settleCurrentAuction(sequence, mint);
+
+            auctionAccount = await client.fetchAuction(auction);
+            assert.ok(auctionAccount.settled === true);
+
+            const [recipientTokenAccount, _recipientTokenAccountBump] =
+                await client.getAssociatedTokenAccountAddress(
+                    recipient.publicKey,
+                    mint
+                );
+            assert.ok(
+                (await client.getTokenAccountBalance(recipientTokenAccount)) ===
+                    1
+            );
+            assert.ok(
+                (await client.getAuctionTokenAccountBalance(auction, mint)) ===
+                    0
+            );
+        });
+    });
+
+    describe("roll unsold resources over into the next auction", async () => {
+        const client = new AuctionFactoryTestClient();
+
+        const MAX_CONFIG_VEC_SIZE = 5;
+        const durationInSeconds = 5;
+        const timeBufferInSeconds = 0;
+        const minBidPercentageIncrease = 0;
+        const minReservePrice = 0;
+
+        let rolledOverMint: PublicKey;
+
+        it("initialize auction factory with the roll over policy & first auction", async () => {
+            await client.setupAuctionFactory(
+                MAX_CONFIG_VEC_SIZE,
+                durationInSeconds,
+                timeBufferInSeconds,
+                minBidPercentageIncrease,
+                minReservePrice,
+                { unsoldResourcePolicy: { rollOver: {} } }
+            );
+            const { mint } = await client.createNextAuctionWithResource();
+            rolledOverMint = mint.publicKey;
+        });
+
+        it("settle the auction and carry the unsold resource into the next auction", async () => {
+            await waitForAuctionToEnd(client, 3, true);
+
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+            await client.settleCurrentAuction(sequence, rolledOverMint);
+
+            const nextSequence = sequence.add(new BN(1));
+            await client.initAuction(nextSequence);
+
+            const nextAuction = await client.getAuctionAddressWithSequence(
+                nextSequence
+            );
+            const nextAuctionAccount = await client.fetchAuction(nextAuction);
+            assert.ok(nextAuctionAccount.resourceRolledOver === true);
+            assert.ok(
+                nextAuctionAccount.resource.toString() ===
+                    rolledOverMint.toString()
+            );
+
+            // the previous auction's token account now belongs to the next auction
+            const auctionTokenAccount = await client.getAuctionTokenAccount(
+                nextAuction
+            );
+            assert.ok(
+                (await client.getTokenAccountBalance(auctionTokenAccount)) === 1
+            );
+        });
+
+        it("attempt to supply another resource to the next auction, and fail 😈", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+
+            expectThrowsAsync(async () => {
+                await client.mintNftToAuction(
+                    auctionFactoryAccount.sequence,
+                    Keypair.generate()
+                );
+            }, "Auction resource was carried over from the previous auction.");
+        });
+
+        it("winner of the next auction receives the rolled over resource", async () => {
+            const auctionFactoryAccount = await client.getAuctionFactory();
+            const sequence = auctionFactoryAccount.sequence;
+
+            const bidder = await client.nodeWallet.createFundedWallet(
+                0.1 * LAMPORTS_PER_SOL
+            );
+            await client.placeBidOnAuction(sequence, new BN(100), bidder);
+
+            await waitForAuctionToEnd(client, 3, true);
+            await client.settleCurrentAuction(sequence, rolledOverMint);
+
+            const [bidderTokenAccount, _bidderTokenAccountBump] =
+                await client.getAssociatedTokenAccountAddress(
+                    bidder.publicKey,
+                    rolledOverMint
+                );
+            assert.ok(
+                (await client.getTokenAccountBalance(bidderTokenAccount)) === 1
+            );
+        });
+    });
+}